@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use digits::Digits;
+
+// The previous implementation, which built the vector by inserting every
+// digit at the front. Kept here as the baseline for comparison.
+fn insert_front(n: u64) -> Vec<u64> {
+    let mut rem = n;
+    let mut v = Vec::new();
+    while rem / 10 > 0 {
+        v.insert(0, rem % 10);
+        rem /= 10;
+    }
+    v.insert(0, rem);
+    v
+}
+
+fn from_int(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_int");
+    for n in [42u64, 1_000_000_007, u64::MAX] {
+        group.bench_with_input(BenchmarkId::new("insert_front", n), &n, |b, &n| {
+            b.iter(|| insert_front(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("Digits::from", n), &n, |b, &n| {
+            b.iter(|| Digits::from(black_box(n)))
+        });
+    }
+    group.finish();
+}
+
+fn from_u128(c: &mut Criterion) {
+    c.bench_function("Digits::from(u128::MAX)", |b| {
+        b.iter(|| Digits::from(black_box(u128::MAX)))
+    });
+}
+
+// Many small conversions in a row, where allocating the digits is a large
+// part of the work. Run them again with `--features smallvec` to compare
+// against inline storage.
+fn tight_loop(c: &mut Criterion) {
+    c.bench_function("digit sums of 0..10000", |b| {
+        b.iter(|| {
+            (0..black_box(10_000u64))
+                .map(|n| Digits::from(n).iter().sum::<u64>())
+                .sum::<u64>()
+        })
+    });
+    c.bench_function("round trip of 0..10000", |b| {
+        b.iter(|| {
+            (0..black_box(10_000u32))
+                .map(|n| Digits::from(n).to_int().unwrap())
+                .fold(0u32, u32::wrapping_add)
+        })
+    });
+}
+
+criterion_group!(benches, from_int, from_u128, tight_loop);
+criterion_main!(benches);
@@ -0,0 +1,491 @@
+//! Random digits, and shrinking them for property tests. Everything draws
+//! from a plain source of random `u64`s, so any generator plugs in, e.g.
+//! `|| rng.next_u64()` for a `rand` RNG, and the crate depends on none of
+//! them. The `proptest` and `quickcheck` features wrap the same generator
+//! and `shrink` in their strategy and `Arbitrary` traits.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+use num_traits::{Num, NumCast};
+
+use crate::{radix_constant, strip_leading_zeros, ConversionError, DigitVec, Digits, Sign};
+
+// A uniform value below `bound`, rejecting the top of the range that would
+// make a plain modulo favour small values.
+fn uniform(next: &mut impl FnMut() -> u64, bound: u64) -> u64 {
+    let zone = u64::MAX - u64::MAX % bound;
+    loop {
+        let x = next();
+        if x < zone {
+            return x % bound;
+        }
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// A uniformly random number with exactly `len` digits in `radix`, drawing
+    /// from `next`. The leading digit is only zero for a single digit.
+    pub fn from_random_source<F>(
+        len: usize,
+        radix: u32,
+        mut next: F,
+    ) -> Result<Self, ConversionError>
+    where
+        F: FnMut() -> u64,
+    {
+        let _: T = radix_constant(radix)?;
+        if len == 0 {
+            return Err(ConversionError::Empty);
+        }
+        let radix_value = radix as u64;
+        let values: Vec<T> = (0..len)
+            .map(|i| match i {
+                0 if len > 1 => 1 + uniform(&mut next, radix_value - 1),
+                _ => uniform(&mut next, radix_value),
+            })
+            .map(|d| T::from(d).unwrap())
+            .collect();
+        Ok(Self::new(values, radix))
+    }
+
+    /// A random decimal number with exactly `len` digits.
+    pub fn random<F>(len: usize, next: F) -> Result<Self, ConversionError>
+    where
+        F: FnMut() -> u64,
+    {
+        Self::from_random_source(len, 10, next)
+    }
+
+    /// A uniformly random number in `range`, written in the radix of its
+    /// start.
+    pub fn random_in_range<F>(
+        range: RangeInclusive<Digits<T>>,
+        mut next: F,
+    ) -> Result<Self, ConversionError>
+    where
+        F: FnMut() -> u64,
+    {
+        let (lo, hi) = range.into_inner();
+        if lo > hi {
+            return Err(ConversionError::EmptyRange);
+        }
+        // Draw below the width of the range by rejection, padding with
+        // leading zeros so every offset up to the width is equally likely.
+        let width = &hi - &lo;
+        let radix = width.radix as u64;
+        loop {
+            let values: DigitVec<T> = (0..width.values.len())
+                .map(|_| T::from(uniform(&mut next, radix)).unwrap())
+                .collect();
+            let offset = Digits::new(values, width.radix);
+            if offset <= width {
+                return Ok(&lo + &offset);
+            }
+        }
+    }
+
+    /// Shuffles the digits in place. The width is kept, so a zero may end up
+    /// in front.
+    pub fn shuffle_digits<F>(&mut self, mut next: F)
+    where
+        F: FnMut() -> u64,
+    {
+        for i in (1..self.values.len()).rev() {
+            let j = uniform(&mut next, i as u64 + 1) as usize;
+            self.values.swap(i, j);
+        }
+    }
+
+    /// Simpler numbers to try when a property fails, simplest first: zero,
+    /// the absolute value, the number without its first or last digit, and
+    /// the number with one digit zeroed. None of them equals `self`.
+    pub fn shrink(&self) -> Shrink<T> {
+        Shrink {
+            digits: self.clone(),
+            step: 0,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Shrink<T> {
+    digits: Digits<T>,
+    step: usize,
+}
+
+impl<T> Iterator for Shrink<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let digits = &self.digits;
+        let len = digits.values.len();
+        let with_values = |mut values: Vec<T>| {
+            strip_leading_zeros(&mut values);
+            Digits::new_signed(values, digits.radix, digits.sign == Sign::Negative)
+        };
+
+        while self.step < len + 4 {
+            self.step += 1;
+            let candidate = match self.step - 1 {
+                0 if digits.sign != Sign::Zero => Digits::new(vec![T::zero()], digits.radix),
+                1 if digits.sign == Sign::Negative => {
+                    Digits::new(digits.values.clone(), digits.radix)
+                }
+                2 if len > 1 => with_values(digits.values[1..].to_vec()),
+                3 if len > 1 => with_values(digits.values[..len - 1].to_vec()),
+                i @ 4.. if !digits.values[i - 4].is_zero() && digits.sign != Sign::Zero => {
+                    let mut values = digits.values.to_vec();
+                    values[i - 4] = T::zero();
+                    with_values(values)
+                }
+                _ => continue,
+            };
+            return Some(candidate);
+        }
+        None
+    }
+}
+
+impl<T> FusedIterator for Shrink<T> where T: Num + NumCast + PartialOrd + Copy {}
+
+// A random number from `from_random_source`, negative half the time when T
+// is signed.
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+fn signed_random<T, F>(len: usize, radix: u32, mut next: F) -> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+    F: FnMut() -> u64,
+{
+    let digits = Digits::<T>::from_random_source(len, radix, &mut next)
+        .expect("the length and radix were checked");
+    let negative = T::from(-1).is_some() && next().is_multiple_of(2);
+    Digits::new_signed(digits.values, radix, negative)
+}
+
+/// A proptest strategy for numbers of `len` digits in `radix`, shrinking
+/// like `Digits::shrink`.
+#[cfg(feature = "proptest")]
+#[derive(Clone, Debug)]
+pub struct DigitsStrategy<T> {
+    len: RangeInclusive<usize>,
+    radix: u32,
+    marker: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "proptest")]
+impl<T> DigitsStrategy<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn new(len: RangeInclusive<usize>, radix: u32) -> Result<Self, ConversionError> {
+        let _: T = radix_constant(radix)?;
+        if len.is_empty() || *len.start() == 0 {
+            return Err(ConversionError::Empty);
+        }
+        Ok(Self {
+            len,
+            radix,
+            marker: core::marker::PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T> proptest::strategy::Strategy for DigitsStrategy<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + core::fmt::Debug,
+{
+    type Tree = DigitsValueTree<T>;
+    type Value = Digits<T>;
+
+    fn new_tree(
+        &self,
+        runner: &mut proptest::test_runner::TestRunner,
+    ) -> proptest::strategy::NewTree<Self> {
+        use proptest::prelude::Rng;
+
+        let rng = runner.rng();
+        let (lo, hi) = (*self.len.start(), *self.len.end());
+        let len = lo + uniform(&mut || rng.next_u64(), (hi - lo) as u64 + 1) as usize;
+        let current = signed_random(len, self.radix, || rng.next_u64());
+        Ok(DigitsValueTree {
+            shrink: current.shrink(),
+            current,
+            previous: None,
+        })
+    }
+}
+
+/// The value tree of `DigitsStrategy`. Simplifying moves to the next
+/// candidate of `Digits::shrink`, complicating goes back to the number it
+/// came from.
+#[cfg(feature = "proptest")]
+#[derive(Clone)]
+pub struct DigitsValueTree<T> {
+    current: Digits<T>,
+    shrink: Shrink<T>,
+    previous: Option<(Digits<T>, Shrink<T>)>,
+}
+
+#[cfg(feature = "proptest")]
+impl<T> proptest::strategy::ValueTree for DigitsValueTree<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + core::fmt::Debug,
+{
+    type Value = Digits<T>;
+
+    fn current(&self) -> Digits<T> {
+        self.current.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        let Some(next) = self.shrink.next() else {
+            return false;
+        };
+        let shrink = core::mem::replace(&mut self.shrink, next.shrink());
+        let current = core::mem::replace(&mut self.current, next);
+        self.previous = Some((current, shrink));
+        true
+    }
+
+    fn complicate(&mut self) -> bool {
+        let Some((current, shrink)) = self.previous.take() else {
+            return false;
+        };
+        self.current = current;
+        self.shrink = shrink;
+        true
+    }
+}
+
+/// Decimal numbers of 1 to 20 digits.
+#[cfg(feature = "proptest")]
+impl<T> proptest::arbitrary::Arbitrary for Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + core::fmt::Debug,
+{
+    type Parameters = ();
+    type Strategy = DigitsStrategy<T>;
+
+    fn arbitrary_with(_: ()) -> DigitsStrategy<T> {
+        DigitsStrategy::new(1..=20, 10).expect("every integer type holds decimal digits")
+    }
+}
+
+/// Decimal numbers of up to the size of the generator in digits, shrinking
+/// like `Digits::shrink`.
+#[cfg(feature = "quickcheck")]
+impl<T> quickcheck::Arbitrary for Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + 'static,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let len = 1 + usize::arbitrary(g) % g.size().max(1);
+        signed_random(len, 10, || u64::arbitrary(g))
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(Digits::shrink(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A small xorshift generator, so the tests are repeatable.
+    fn xorshift(mut state: u64) -> impl FnMut() -> u64 {
+        move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        }
+    }
+
+    #[test]
+    fn from_random_source_works() {
+        let mut next = xorshift(42);
+        for len in 1..50 {
+            for radix in [2, 10, 16, 36] {
+                let digits = Digits::<u8>::from_random_source(len, radix, &mut next).unwrap();
+                assert_eq!(digits.len(), len);
+                assert!(digits.iter().all(|&d| (d as u32) < radix));
+                assert!(len == 1 || digits[0] != 0);
+            }
+        }
+    }
+
+    #[test]
+    fn from_random_source_covers_every_digit() {
+        let digits = Digits::<u8>::from_random_source(2_000, 10, xorshift(7)).unwrap();
+        assert!(digits.counts().iter().all(|&c| c > 100));
+    }
+
+    #[test]
+    fn from_random_source_round_trips() {
+        let mut next = xorshift(1);
+        for _ in 0..200 {
+            let digits = Digits::<u64>::from_random_source(19, 10, &mut next).unwrap();
+            let n = digits.to_int().unwrap();
+            assert!(Digits::from(n) == digits);
+        }
+    }
+
+    #[test]
+    fn from_random_source_throws_error_with_invalid_arguments() {
+        assert!(Digits::<u8>::from_random_source(0, 10, xorshift(1)).is_err());
+        assert!(Digits::<u8>::from_random_source(3, 37, xorshift(1)).is_err());
+    }
+
+    #[test]
+    fn shrink_works() {
+        let shrunk: Vec<i32> = Digits::try_from(-305)
+            .unwrap()
+            .shrink()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(shrunk, vec![0, 305, -5, -30, -5, -300]);
+    }
+
+    #[test]
+    fn shrink_never_yields_self() {
+        for n in 0u32..500 {
+            let digits = Digits::from(n);
+            assert!(digits.shrink().all(|d| d != digits && d < digits));
+        }
+        assert_eq!(Digits::from(0u32).shrink().count(), 0);
+    }
+
+    #[test]
+    fn random_works() {
+        let digits = Digits::<u8>::random(16, xorshift(13)).unwrap();
+        assert_eq!(digits.radix(), 10);
+        assert_eq!(digits.len(), 16);
+        assert_ne!(digits[0], 0);
+    }
+
+    #[test]
+    fn random_in_range_works() {
+        let mut next = xorshift(3);
+        let (lo, hi) = (Digits::from(100u32), Digits::from(199u32));
+        let mut seen = [false; 100];
+        for _ in 0..2_000 {
+            let n = Digits::random_in_range(lo.clone()..=hi.clone(), &mut next).unwrap();
+            let n = n.to_int().unwrap();
+            assert!((100..=199).contains(&n));
+            seen[n as usize - 100] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn random_in_range_works_with_signs_and_radix() {
+        let mut next = xorshift(5);
+        for _ in 0..500 {
+            let range = Digits::with_radix(-20i32, 16).unwrap()..=Digits::try_from(5).unwrap();
+            let n = Digits::random_in_range(range, &mut next).unwrap();
+            assert_eq!(n.radix(), 16);
+            assert!((-20..=5).contains(&n.to_int().unwrap()));
+        }
+        let single = Digits::random_in_range(Digits::from(7u32)..=Digits::from(7u32), next);
+        assert_eq!(single.unwrap().to_int(), Ok(7));
+    }
+
+    #[test]
+    fn random_in_range_works_beyond_integer_range() {
+        let hi = crate::factorial_digits(40);
+        let n = Digits::random_in_range(Digits::from(0u64)..=hi.clone(), xorshift(9)).unwrap();
+        assert!(n <= hi);
+    }
+
+    #[test]
+    fn random_in_range_throws_error_with_empty_range() {
+        assert!(
+            Digits::random_in_range(Digits::from(2u32)..=Digits::from(1u32), xorshift(1))
+                .map(|d| d.to_int())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn shuffle_digits_works() {
+        let mut next = xorshift(11);
+        let original = Digits::from(1_234_567_890u64);
+        let mut changed = false;
+        for _ in 0..20 {
+            let mut digits = original.clone();
+            digits.shuffle_digits(&mut next);
+            assert_eq!(digits.len(), original.len());
+            assert!(digits.is_anagram_of(&original));
+            changed |= digits.as_slice() != original.as_slice();
+        }
+        assert!(changed);
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn digits_strategy_works() {
+        use proptest::strategy::{Strategy, ValueTree};
+
+        let mut runner = proptest::test_runner::TestRunner::deterministic();
+        let strategy = DigitsStrategy::<i64>::new(3..=5, 16).unwrap();
+        let mut negative = false;
+        for _ in 0..200 {
+            let digits = strategy.new_tree(&mut runner).unwrap().current();
+            assert!((3..=5).contains(&digits.len()));
+            assert_eq!(digits.radix(), 16);
+            negative |= digits.sign() == Sign::Negative;
+        }
+        assert!(negative);
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn digits_strategy_shrinks() {
+        let mut runner = proptest::test_runner::TestRunner::deterministic();
+        let strategy = DigitsStrategy::<u32>::new(5..=5, 10).unwrap();
+        let result = runner.run(&strategy, |digits| {
+            proptest::prop_assert!(digits.to_int().unwrap() < 500);
+            Ok(())
+        });
+        let Err(proptest::test_runner::TestError::Fail(_, minimal)) = result else {
+            panic!("the property holds");
+        };
+        assert!(minimal.to_int().unwrap() >= 500);
+        assert!(minimal.iter().skip(1).all(|&d| d == 0));
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn digits_strategy_throws_error_with_invalid_arguments() {
+        assert!(DigitsStrategy::<u8>::new(0..=3, 10).is_err());
+        assert!(DigitsStrategy::<u8>::new(1..=3, 37).is_err());
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_arbitrary_works() {
+        let mut g = quickcheck::Gen::new(12);
+        let mut negative = false;
+        for _ in 0..200 {
+            let digits = <Digits<i64> as quickcheck::Arbitrary>::arbitrary(&mut g);
+            assert!((1..=12).contains(&digits.len()));
+            assert_eq!(digits.radix(), 10);
+            negative |= digits.sign() == Sign::Negative;
+        }
+        assert!(negative);
+
+        let digits = Digits::try_from(-305).unwrap();
+        assert!(quickcheck::Arbitrary::shrink(&digits).eq(digits.shrink()));
+    }
+}
@@ -0,0 +1,414 @@
+//! Schoolbook arithmetic directly on the digits, so results are not bounded
+//! by any integer type. The work is done on `u64` digits, which leaves room
+//! for the products of two digits in any radix, and the result is written in
+//! the radix of the left operand. A left operand with a fixed width also
+//! gives the result its width, dropping any digits that carry past the top.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use num_traits::{Num, NumCast};
+
+use crate::{long_add, long_mul, long_sub, radix_constant, strip_leading_zeros};
+use crate::{DigitVec, Digits, Sign};
+
+fn cmp_magnitude(a: &[u64], b: &[u64]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn from_magnitude(mut values: Vec<u64>, radix: u32, negative: bool) -> Self {
+        strip_leading_zeros(&mut values);
+        let digits = Self::new_signed(
+            values
+                .into_iter()
+                .map(|d| T::from(d).unwrap())
+                .collect::<DigitVec<T>>(),
+            radix,
+            negative,
+        );
+        if digits.sign == Sign::Negative && T::from(-1).is_none() {
+            panic!("attempt to make unsigned digits negative");
+        }
+        digits
+    }
+
+    fn signed_add(&self, other: &Self, negate_other: bool) -> Self {
+        let radix = self.radix as u64;
+        let a = self.magnitude_in(self.radix);
+        let b = other.magnitude_in(self.radix);
+        let a_negative = self.sign == Sign::Negative;
+        let b_negative = (other.sign == Sign::Negative) != negate_other;
+
+        // With equal signs the magnitudes add up, otherwise the smaller one is
+        // taken from the larger, which lends the result its sign.
+        let (values, negative) = if a_negative == b_negative {
+            (long_add(&a, &b, radix), a_negative)
+        } else if cmp_magnitude(&a, &b) != Ordering::Less {
+            (long_sub(&a, &b, radix), a_negative)
+        } else {
+            (long_sub(&b, &a, radix), b_negative)
+        };
+        self.result(values, negative)
+    }
+
+    // The result of an operation with `self` on the left, in its radix and,
+    // if it has one, its fixed width.
+    fn result(&self, values: Vec<u64>, negative: bool) -> Self {
+        let mut digits = Self::from_magnitude(values, self.radix, negative);
+        if self.fixed_width {
+            digits.wrap_to_width(self.values.len());
+        }
+        digits
+    }
+
+    fn add_digits(&self, other: &Self) -> Self {
+        self.signed_add(other, false)
+    }
+
+    // Panics for unsigned digit types if the difference would be negative,
+    // like subtraction on the unsigned integers themselves.
+    fn sub_digits(&self, other: &Self) -> Self {
+        self.signed_add(other, true)
+    }
+
+    fn mul_digits(&self, other: &Self) -> Self {
+        let a = self.magnitude_in(self.radix);
+        let b = other.magnitude_in(self.radix);
+        let negative = (self.sign == Sign::Negative) != (other.sign == Sign::Negative);
+        self.result(long_mul(&a, &b, self.radix as u64), negative)
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn radix_value(&self) -> T {
+        radix_constant(self.radix).expect("radix was checked on construction")
+    }
+
+    // Adds one to the magnitude, growing by a digit when it carries out.
+    fn increment_magnitude(&mut self) {
+        let radix = self.radix_value();
+        for d in self.values.iter_mut().rev() {
+            *d = *d + T::one();
+            if *d != radix {
+                return;
+            }
+            *d = T::zero();
+        }
+        self.values.insert(0, T::one());
+    }
+
+    // Takes one from a non-zero magnitude, dropping the leading digit if the
+    // borrow runs into it and leaves a zero, so 1000 becomes 999.
+    fn decrement_magnitude(&mut self) {
+        let radix = self.radix_value();
+        for i in (0..self.values.len()).rev() {
+            if !self.values[i].is_zero() {
+                self.values[i] = self.values[i] - T::one();
+                if i == 0 && self.values[0].is_zero() && self.values.len() > 1 {
+                    self.values.remove(0);
+                }
+                return;
+            }
+            self.values[i] = radix - T::one();
+        }
+    }
+
+    // Puts back the fixed width, if any, after the magnitude changed length.
+    fn keep_width(&mut self, len: usize) {
+        if self.fixed_width {
+            self.wrap_to_width(len);
+        } else {
+            self.update_sign();
+        }
+    }
+
+    /// Adds one in place, without converting to an integer. With a fixed
+    /// width the digits wrap around like an odometer, so `999` becomes `000`.
+    pub fn increment(&mut self) {
+        let len = self.values.len();
+        match self.sign {
+            Sign::Negative => self.decrement_magnitude(),
+            _ => self.increment_magnitude(),
+        }
+        self.keep_width(len);
+    }
+
+    /// Subtracts one in place, without converting to an integer. With a
+    /// fixed width the leading zeros stay, so `100` becomes `099`, and the
+    /// magnitude wraps around like in `increment`, so `-999` becomes `000`.
+    ///
+    /// # Panics
+    ///
+    /// If the digit type is unsigned and the number is zero.
+    pub fn decrement(&mut self) {
+        let len = self.values.len();
+        match self.sign {
+            Sign::Positive => self.decrement_magnitude(),
+            Sign::Negative => self.increment_magnitude(),
+            Sign::Zero => {
+                if T::from(-1).is_none() {
+                    panic!("attempt to make unsigned digits negative");
+                }
+                self.increment_magnitude();
+                self.sign = Sign::Negative;
+            }
+        }
+        self.keep_width(len);
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $f:path) => {
+        impl<T> $op<&Digits<T>> for &Digits<T>
+        where
+            T: Num + NumCast + Copy,
+        {
+            type Output = Digits<T>;
+
+            fn $method(self, other: &Digits<T>) -> Digits<T> {
+                $f(self, other)
+            }
+        }
+
+        impl<T> $op<Digits<T>> for &Digits<T>
+        where
+            T: Num + NumCast + Copy,
+        {
+            type Output = Digits<T>;
+
+            fn $method(self, other: Digits<T>) -> Digits<T> {
+                self.$method(&other)
+            }
+        }
+
+        impl<T> $op<&Digits<T>> for Digits<T>
+        where
+            T: Num + NumCast + Copy,
+        {
+            type Output = Digits<T>;
+
+            fn $method(self, other: &Digits<T>) -> Digits<T> {
+                (&self).$method(other)
+            }
+        }
+
+        impl<T> $op<Digits<T>> for Digits<T>
+        where
+            T: Num + NumCast + Copy,
+        {
+            type Output = Digits<T>;
+
+            fn $method(self, other: Digits<T>) -> Digits<T> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<T> $assign<&Digits<T>> for Digits<T>
+        where
+            T: Num + NumCast + Copy,
+        {
+            fn $assign_method(&mut self, other: &Digits<T>) {
+                *self = (&*self).$method(other);
+            }
+        }
+
+        impl<T> $assign<Digits<T>> for Digits<T>
+        where
+            T: Num + NumCast + Copy,
+        {
+            fn $assign_method(&mut self, other: Digits<T>) {
+                *self = (&*self).$method(&other);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, Digits::add_digits);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, Digits::sub_digits);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, Digits::mul_digits);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn add_works() {
+        let sum = Digits::from(999u32) + Digits::from(1u32);
+        assert_eq!(sum.to_int(), Ok(1_000));
+        let sum = &Digits::from(0u32) + &Digits::from(0u32);
+        assert_eq!(sum.sign(), Sign::Zero);
+        assert_eq!(sum.as_slice(), [0]);
+    }
+
+    #[test]
+    fn add_works_with_signs() {
+        for a in -30i32..30 {
+            for b in -30i32..30 {
+                let sum = Digits::try_from(a).unwrap() + Digits::try_from(b).unwrap();
+                assert_eq!(sum.to_int(), Ok(a + b));
+            }
+        }
+    }
+
+    #[test]
+    fn add_works_beyond_integer_range() {
+        let max = Digits::from(u64::MAX);
+        let sum = &max + &max;
+        assert_eq!(sum.to_string(), "36893488147419103230");
+        assert_eq!(sum.len(), 20);
+    }
+
+    #[test]
+    fn add_works_with_mixed_radixes() {
+        let sum = Digits::with_radix(0xffu32, 16).unwrap() + Digits::from(1u32);
+        assert_eq!(sum.radix(), 16);
+        assert_eq!(sum.to_int(), Ok(0x100));
+        let sum = Digits::with_radix(35u8, 36).unwrap() + Digits::with_radix(35u8, 36).unwrap();
+        assert_eq!(sum.to_string(), "1y");
+    }
+
+    #[test]
+    fn add_assign_works() {
+        let mut total = Digits::from(0u64);
+        for n in 1..=100u64 {
+            total += Digits::from(n);
+        }
+        assert_eq!(total.to_int(), Ok(5_050));
+    }
+
+    #[test]
+    fn sub_works() {
+        let difference = Digits::from(1_000u32) - Digits::from(1u32);
+        assert_eq!(difference.to_int(), Ok(999));
+        let difference = Digits::from(42u32) - Digits::from(42u32);
+        assert_eq!(difference.sign(), Sign::Zero);
+        assert_eq!(difference.as_slice(), [0]);
+    }
+
+    #[test]
+    fn sub_works_with_signs() {
+        for a in -30i32..30 {
+            for b in -30i32..30 {
+                let difference = Digits::try_from(a).unwrap() - Digits::try_from(b).unwrap();
+                assert_eq!(difference.to_int(), Ok(a - b));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to make unsigned digits negative")]
+    fn sub_panics_with_negative_unsigned_result() {
+        let _ = Digits::from(1u32) - Digits::from(2u32);
+    }
+
+    #[test]
+    fn mul_works() {
+        let product = Digits::from(12u32) * Digits::from(12u32);
+        assert_eq!(product.to_int(), Ok(144));
+        let product = Digits::try_from(-12).unwrap() * Digits::try_from(12).unwrap();
+        assert_eq!(product.to_int(), Ok(-144));
+        let product = Digits::try_from(-12).unwrap() * Digits::try_from(0).unwrap();
+        assert_eq!(product.sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn mul_works_beyond_integer_range() {
+        let max = Digits::from(u128::MAX);
+        let product = &max * &max;
+        assert_eq!(
+            product.to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+    }
+
+    #[test]
+    fn mul_assign_matches_factorial_digits() {
+        let mut factorial = Digits::from(1u64);
+        for n in 1..=50u64 {
+            factorial *= Digits::from(n);
+        }
+        assert!(factorial == crate::factorial_digits(50));
+        let digit_sum: u64 = factorial.iter().sum();
+        assert_eq!(digit_sum, 216);
+    }
+
+    #[test]
+    fn increment_works() {
+        let mut digits = Digits::from(998u32);
+        digits.increment();
+        assert_eq!(digits.as_slice(), [9, 9, 9]);
+        digits.increment();
+        assert_eq!(digits.as_slice(), [1, 0, 0, 0]);
+
+        let mut digits = Digits::with_radix(0xffu32, 16).unwrap();
+        digits.increment();
+        assert_eq!(digits.to_int(), Ok(0x100));
+
+        let mut digits = Digits::<u32>::parse_with_leading_zeros("0099").unwrap();
+        digits.increment();
+        assert_eq!(digits.as_slice(), [0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn increment_works_with_signs() {
+        for n in -1_100i32..1_100 {
+            let mut digits = Digits::try_from(n).unwrap();
+            digits.increment();
+            assert_eq!(digits.to_int(), Ok(n + 1));
+            assert!(digits == Digits::try_from(n + 1).unwrap());
+        }
+    }
+
+    #[test]
+    fn increment_works_beyond_integer_range() {
+        let mut digits = Digits::from(u128::MAX);
+        digits.increment();
+        assert_eq!(
+            digits.to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        digits.decrement();
+        assert_eq!(digits.to_int(), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn decrement_works() {
+        let mut digits = Digits::from(1_000u32);
+        digits.decrement();
+        assert_eq!(digits.as_slice(), [9, 9, 9]);
+
+        let mut digits = Digits::from(1u32);
+        digits.decrement();
+        assert_eq!(digits.sign(), Sign::Zero);
+        assert_eq!(digits.as_slice(), [0]);
+
+        let mut digits = Digits::<u32>::parse_with_leading_zeros("0100").unwrap();
+        digits.decrement();
+        assert_eq!(digits.as_slice(), [0, 0, 9, 9]);
+    }
+
+    #[test]
+    fn decrement_works_with_signs() {
+        for n in -1_100i32..1_100 {
+            let mut digits = Digits::try_from(n).unwrap();
+            digits.decrement();
+            assert_eq!(digits.to_int(), Ok(n - 1));
+            assert!(digits == Digits::try_from(n - 1).unwrap());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to make unsigned digits negative")]
+    fn decrement_panics_with_unsigned_zero() {
+        Digits::from(0u32).decrement();
+    }
+}
@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+
+use num_traits::{Num, NumCast};
+
+use crate::{strip_leading_zeros, ConversionError, Digits, Sign};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BcdOrder {
+    /// Most significant digit first, in the high nibble of the first byte.
+    BigEndian,
+    /// Least significant digit first, in the low nibble of the first byte.
+    LittleEndian,
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// Packs two decimal digits per byte. An odd number of digits is padded
+    /// with a zero nibble on the most significant end.
+    pub fn to_bcd(&self, order: BcdOrder) -> Result<Vec<u8>, ConversionError> {
+        if self.radix != 10 {
+            return Err(ConversionError::NotDecimal);
+        }
+        if self.sign == Sign::Negative {
+            return Err(ConversionError::NegativeInput);
+        }
+
+        // Pair the digits up from the least significant end, which gives the
+        // little-endian layout; big-endian is the same bytes reversed.
+        let mut digits = self.values.iter().rev().map(|d| d.to_u8().unwrap());
+        let mut bytes = Vec::with_capacity(self.values.len().div_ceil(2));
+        while let Some(low) = digits.next() {
+            let high = digits.next().unwrap_or(0);
+            bytes.push(high << 4 | low);
+        }
+        if order == BcdOrder::BigEndian {
+            bytes.reverse();
+        }
+        Ok(bytes)
+    }
+
+    pub fn from_bcd(bytes: &[u8], order: BcdOrder) -> Result<Self, ConversionError> {
+        if bytes.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+
+        let mut values = Vec::with_capacity(bytes.len() * 2);
+        let mut push = |nibble: u8| {
+            if nibble > 9 {
+                return Err(ConversionError::invalid_digit(values.len(), nibble));
+            }
+            values.push(T::from(nibble).unwrap());
+            Ok(())
+        };
+        match order {
+            BcdOrder::BigEndian => {
+                for &b in bytes {
+                    push(b >> 4)?;
+                    push(b & 0xf)?;
+                }
+            }
+            BcdOrder::LittleEndian => {
+                for &b in bytes.iter().rev() {
+                    push(b >> 4)?;
+                    push(b & 0xf)?;
+                }
+            }
+        }
+        strip_leading_zeros(&mut values);
+        Ok(Self::new(values, 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn to_bcd_works() {
+        let digits = Digits::from(1_234u32);
+        assert_eq!(digits.to_bcd(BcdOrder::BigEndian), Ok(vec![0x12, 0x34]));
+        assert_eq!(digits.to_bcd(BcdOrder::LittleEndian), Ok(vec![0x34, 0x12]));
+    }
+
+    #[test]
+    fn to_bcd_pads_odd_digit_count() {
+        let digits = Digits::from(12_345u32);
+        assert_eq!(
+            digits.to_bcd(BcdOrder::BigEndian),
+            Ok(vec![0x01, 0x23, 0x45])
+        );
+        assert_eq!(
+            digits.to_bcd(BcdOrder::LittleEndian),
+            Ok(vec![0x45, 0x23, 0x01])
+        );
+        assert_eq!(Digits::from(0u32).to_bcd(BcdOrder::BigEndian), Ok(vec![0]));
+    }
+
+    #[test]
+    fn to_bcd_throws_error_with_negative_number() {
+        assert_eq!(
+            Digits::try_from(-12).unwrap().to_bcd(BcdOrder::BigEndian),
+            Err(ConversionError::NegativeInput)
+        );
+    }
+
+    #[test]
+    fn to_bcd_throws_error_with_non_decimal_digits() {
+        assert_eq!(
+            Digits::with_radix(0x12u32, 16)
+                .unwrap()
+                .to_bcd(BcdOrder::BigEndian),
+            Err(ConversionError::NotDecimal)
+        );
+    }
+
+    #[test]
+    fn from_bcd_works() {
+        let digits = Digits::<u32>::from_bcd(&[0x01, 0x23, 0x45], BcdOrder::BigEndian).unwrap();
+        assert_eq!(digits.to_int(), Ok(12_345));
+        let digits = Digits::<u32>::from_bcd(&[0x45, 0x23, 0x01], BcdOrder::LittleEndian).unwrap();
+        assert_eq!(digits.to_int(), Ok(12_345));
+        let digits = Digits::<u32>::from_bcd(&[0x00, 0x00], BcdOrder::BigEndian).unwrap();
+        assert_eq!(digits.as_slice(), vec![0]);
+    }
+
+    #[test]
+    fn from_bcd_round_trips() {
+        for order in [BcdOrder::BigEndian, BcdOrder::LittleEndian] {
+            let factorial = crate::factorial_digits(50);
+            let bcd = factorial.to_bcd(order).unwrap();
+            assert_eq!(
+                Digits::<u64>::from_bcd(&bcd, order).unwrap().as_slice(),
+                factorial.as_slice()
+            );
+        }
+    }
+
+    #[test]
+    fn from_bcd_throws_error_with_invalid_nibble() {
+        for (bytes, position, value) in [(&[0x1a][..], 1, 10), (&[0xf1], 0, 15)] {
+            assert_eq!(
+                Digits::<u8>::from_bcd(bytes, BcdOrder::BigEndian).map(|d| d.to_int()),
+                Err(ConversionError::InvalidDigit { position, value })
+            );
+        }
+        assert!(Digits::<u8>::from_bcd(&[], BcdOrder::LittleEndian).is_err());
+    }
+}
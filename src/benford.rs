@@ -0,0 +1,168 @@
+//! Leading-digit statistics for checking data against Benford's law.
+//!
+//! Only decimal leading digits are counted, and zero has no leading digit, so
+//! it is skipped.
+
+use num_traits::{Num, NumCast};
+
+use crate::{digits_of, ConversionError, Digits};
+
+/// The Benford probability of each leading digit 1 to 9, i.e. log10(1 + 1/d).
+pub const EXPECTED: [f64; 9] = [
+    core::f64::consts::LOG10_2,
+    0.176_091_259_055_681_24,
+    0.124_938_736_608_299_92,
+    0.096_910_013_008_056_42,
+    0.079_181_246_047_624_82,
+    0.066_946_789_630_613_22,
+    0.057_991_946_977_686_73,
+    0.051_152_522_447_381_29,
+    0.045_757_490_560_675_14,
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Distribution {
+    counts: [usize; 9],
+}
+
+impl Distribution {
+    /// How many numbers had each leading digit, indexed from digit 1.
+    pub fn counts(&self) -> [usize; 9] {
+        self.counts
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// The observed share of each leading digit, all zero if nothing was
+    /// counted.
+    pub fn frequencies(&self) -> [f64; 9] {
+        let total = self.total().max(1) as f64;
+        self.counts.map(|count| count as f64 / total)
+    }
+
+    /// Pearson's chi-squared statistic against the Benford expectation, with
+    /// eight degrees of freedom. Values above 15.51 reject Benford's law at
+    /// the 5% level. Zero if nothing was counted.
+    pub fn chi_squared(&self) -> f64 {
+        if self.total() == 0 {
+            return 0.0;
+        }
+        let total = self.total() as f64;
+        self.counts
+            .iter()
+            .zip(EXPECTED)
+            .map(|(&observed, p)| {
+                let expected = p * total;
+                let diff = observed as f64 - expected;
+                diff * diff / expected
+            })
+            .sum()
+    }
+
+    fn record(&mut self, leading: usize) {
+        self.counts[leading - 1] += 1;
+    }
+}
+
+/// Counts the leading decimal digits of a sequence of integers.
+pub fn leading_digits<I, T>(numbers: I) -> Distribution
+where
+    I: IntoIterator<Item = T>,
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    let mut distribution = Distribution::default();
+    for n in numbers {
+        if !n.is_zero() {
+            let leading = digits_of(n).next().unwrap();
+            distribution.record(leading.to_usize().unwrap());
+        }
+    }
+    distribution
+}
+
+/// Counts the leading digits of a sequence of `Digits`, which must all be
+/// decimal.
+pub fn leading_digits_of<'a, I, T>(digits: I) -> Result<Distribution, ConversionError>
+where
+    I: IntoIterator<Item = &'a Digits<T>>,
+    T: Num + NumCast + Copy + 'a,
+{
+    let mut distribution = Distribution::default();
+    for d in digits {
+        if d.radix != 10 {
+            return Err(ConversionError::NotDecimal);
+        }
+        if let Some(leading) = d.values.iter().find(|d| !d.is_zero()) {
+            distribution.record(leading.to_usize().unwrap());
+        }
+    }
+    Ok(distribution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn expected_sums_to_one() {
+        assert!((EXPECTED.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn leading_digits_works() {
+        let distribution = leading_digits([1u32, 15, 199, 2, 30, 0, 999]);
+        assert_eq!(distribution.counts(), [3, 1, 1, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(distribution.total(), 6);
+        assert_eq!(distribution.frequencies()[0], 0.5);
+    }
+
+    #[test]
+    fn leading_digits_works_with_negative_numbers() {
+        let distribution = leading_digits([-12i32, 12, i32::MIN]);
+        assert_eq!(distribution.counts(), [2, 1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn leading_digits_of_works() {
+        let numbers: Vec<Digits<u32>> = [1u32, 15, 2].into_iter().map(Digits::from).collect();
+        let distribution = leading_digits_of(&numbers).unwrap();
+        assert_eq!(distribution, leading_digits([1u32, 15, 2]));
+
+        let padded = Digits::<u32>::parse_with_leading_zeros("0042").unwrap();
+        let distribution = leading_digits_of([&padded]).unwrap();
+        assert_eq!(distribution.counts()[3], 1);
+    }
+
+    #[test]
+    fn leading_digits_of_throws_error_with_non_decimal_digits() {
+        let hex = Digits::with_radix(0xfu32, 16).unwrap();
+        assert_eq!(leading_digits_of([&hex]), Err(ConversionError::NotDecimal));
+    }
+
+    #[test]
+    fn chi_squared_is_small_for_powers() {
+        let powers = (0..64)
+            .map(|k| 2u64.pow(k))
+            .chain((0..41).map(|k| 3u64.pow(k)));
+        let distribution = leading_digits(powers);
+        assert_eq!(distribution.counts(), [32, 20, 10, 11, 10, 7, 4, 7, 4]);
+        assert!(distribution.chi_squared() < 15.51);
+    }
+
+    #[test]
+    fn chi_squared_is_large_for_uniform_leading_digits() {
+        let distribution = leading_digits(100u32..1_000);
+        assert_eq!(distribution.counts(), [100; 9]);
+        assert!(distribution.chi_squared() > 100.0);
+    }
+
+    #[test]
+    fn chi_squared_is_zero_for_empty_distribution() {
+        let distribution = leading_digits(core::iter::empty::<u32>());
+        assert_eq!(distribution.chi_squared(), 0.0);
+        assert_eq!(leading_digits([0u32, 0]).chi_squared(), 0.0);
+    }
+}
@@ -0,0 +1,175 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use num_traits::{Num, NumCast, Zero};
+
+use crate::{ConversionError, DigitVec, Digits, Sign};
+
+// Below this many digits the conversions defer to num-bigint's own radix
+// routines; above it they split the number around powers of the radix.
+const CHUNK_DIGITS: usize = 256;
+
+impl<T> From<&BigUint> for Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn from(n: &BigUint) -> Self {
+        let powers = radix_powers(10, |_, power| power <= n);
+        let mut out = Vec::new();
+        to_radix_digits(n, 10, &powers, powers.len() - 1, None, &mut out);
+
+        let values: DigitVec<T> = out.into_iter().map(|d| T::from(d).unwrap()).collect();
+        Self::new(values, 10)
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    pub fn to_biguint(&self) -> Result<BigUint, ConversionError> {
+        if self.sign == Sign::Negative {
+            return Err(ConversionError::NegativeInput);
+        }
+
+        let digits: Vec<u8> = self.values.iter().map(|d| d.to_u8().unwrap()).collect();
+        let powers = radix_powers(self.radix, |width, _| width < digits.len());
+        Ok(from_radix_digits(&digits, self.radix, &powers))
+    }
+}
+
+// powers[i] == radix^chunk_width(i), extended for as long as `keep` holds
+// for the last power and its width in digits.
+fn radix_powers(radix: u32, keep: impl Fn(usize, &BigUint) -> bool) -> Vec<BigUint> {
+    let mut powers = vec![BigUint::from(radix).pow(CHUNK_DIGITS as u32)];
+    while keep(chunk_width(powers.len() - 1), powers.last().unwrap()) {
+        let last = powers.last().unwrap();
+        powers.push(last * last);
+    }
+    powers
+}
+
+fn chunk_width(level: usize) -> usize {
+    CHUNK_DIGITS << level
+}
+
+fn to_radix_digits(
+    n: &BigUint,
+    radix: u32,
+    powers: &[BigUint],
+    level: usize,
+    width: Option<usize>,
+    out: &mut Vec<u8>,
+) {
+    if level == 0 {
+        let digits = n.to_radix_be(radix);
+        if let Some(width) = width {
+            out.extend(core::iter::repeat_n(0, width - digits.len()));
+        }
+        out.extend(digits);
+        return;
+    }
+
+    let low_width = chunk_width(level - 1);
+    let (high, low) = (n / &powers[level - 1], n % &powers[level - 1]);
+    if width.is_none() && high.is_zero() {
+        to_radix_digits(&low, radix, powers, level - 1, None, out);
+    } else {
+        let high_width = width.map(|w| w - low_width);
+        to_radix_digits(&high, radix, powers, level - 1, high_width, out);
+        to_radix_digits(&low, radix, powers, level - 1, Some(low_width), out);
+    }
+}
+
+fn from_radix_digits(digits: &[u8], radix: u32, powers: &[BigUint]) -> BigUint {
+    if digits.len() <= CHUNK_DIGITS {
+        return BigUint::from_radix_be(digits, radix).unwrap();
+    }
+
+    let level = (0..powers.len())
+        .take_while(|&level| chunk_width(level) < digits.len())
+        .last()
+        .unwrap();
+    let (high, low) = digits.split_at(digits.len() - chunk_width(level));
+    from_radix_digits(high, radix, powers) * &powers[level] + from_radix_digits(low, radix, powers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factorial_digits;
+
+    #[test]
+    fn from_biguint_works() {
+        let digits = Digits::<u8>::from(&BigUint::from(42u32));
+        assert_eq!(digits.as_slice(), vec![4, 2]);
+        assert_eq!(digits.radix(), 10);
+        assert_eq!(digits.sign(), Sign::Positive);
+    }
+
+    #[test]
+    fn from_biguint_works_with_zero() {
+        let digits = Digits::<u8>::from(&BigUint::zero());
+        assert_eq!(digits.as_slice(), vec![0]);
+        assert_eq!(digits.sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn from_biguint_works_beyond_u128_range() {
+        let n = (1..=100u32).map(BigUint::from).product::<BigUint>();
+        assert_eq!(
+            Digits::<u64>::from(&n).as_slice(),
+            factorial_digits(100).as_slice()
+        );
+    }
+
+    #[test]
+    fn from_biguint_works_with_very_large_numbers() {
+        let n = BigUint::from(2u32).pow(100_000);
+        let digits = Digits::<u8>::from(&n);
+        assert_eq!(digits.len(), 30_103);
+        assert_eq!(digits[..5], [9, 9, 9, 0, 0]);
+        assert_eq!(digits[digits.len() - 5..], [0, 9, 3, 7, 6]);
+    }
+
+    #[test]
+    fn from_biguint_preserves_inner_zeros() {
+        let n = BigUint::from(10u32).pow(5_000) + 7u32;
+        let digits = Digits::<u8>::from(&n);
+        assert_eq!(digits.len(), 5_001);
+        assert_eq!(digits[0], 1);
+        assert!(digits[1..5_000].iter().all(|&d| d == 0));
+        assert_eq!(digits[5_000], 7);
+    }
+
+    #[test]
+    fn to_biguint_works() {
+        assert_eq!(Digits::from(42u32).to_biguint(), Ok(BigUint::from(42u32)));
+        assert_eq!(Digits::from(0u32).to_biguint(), Ok(BigUint::zero()));
+        assert_eq!(
+            Digits::with_radix(255u32, 16).unwrap().to_biguint(),
+            Ok(BigUint::from(255u32))
+        );
+    }
+
+    #[test]
+    fn to_biguint_throws_error_with_negative_number() {
+        assert!(Digits::try_from(-42).unwrap().to_biguint().is_err());
+    }
+
+    #[test]
+    fn biguint_round_trip_works() {
+        for exp in [1, 255, 256, 257, 1_000, 10_000] {
+            let n = BigUint::from(3u32).pow(exp) - 1u32;
+            assert_eq!(Digits::<u8>::from(&n).to_biguint(), Ok(n));
+        }
+    }
+
+    #[test]
+    fn biguint_round_trip_works_with_radix() {
+        let n = BigUint::from(7u32).pow(3_000);
+        let digits = Digits::from_radix(n.to_radix_be(2), 2).unwrap();
+        assert_eq!(digits.to_biguint(), Ok(n));
+    }
+}
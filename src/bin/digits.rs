@@ -0,0 +1,213 @@
+//! Command line access to the crate, e.g. `digits 12345 --sum` or
+//! `digits --luhn 4111111111111111`.
+
+use std::process::ExitCode;
+
+use digits::checksum::luhn;
+use digits::{ConversionError, Digits};
+
+const USAGE: &str = "\
+usage: digits <NUMBER> [--sum | --base <RADIX> | --reverse | --luhn]
+
+Without an option the decimal digits of NUMBER are printed one per line.
+
+options:
+    --sum             print the sum of the digits
+    --base <RADIX>    print NUMBER in another radix from 2 to 36
+    --reverse         print the digits in reverse order
+    --luhn            check NUMBER against the Luhn checksum
+    -h, --help        print this message";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Digits,
+    Sum,
+    Base(u32),
+    Reverse,
+    Luhn,
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+enum Output {
+    Text(String),
+    // The Luhn check exits non-zero for an invalid number, for use in shell
+    // conditions.
+    Check(bool),
+}
+
+fn parse_args<I>(args: I) -> Result<(Command, Option<String>), String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let mut command = None;
+    let mut number = None;
+    let set = |c: Command, command: &mut Option<Command>| {
+        if command.replace(c).is_some() {
+            return Err("only one option can be given".to_string());
+        }
+        Ok(())
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok((Command::Help, None)),
+            "--sum" => set(Command::Sum, &mut command)?,
+            "--reverse" => set(Command::Reverse, &mut command)?,
+            "--luhn" => set(Command::Luhn, &mut command)?,
+            "--base" => {
+                let radix = args
+                    .next()
+                    .ok_or("--base needs a radix")?
+                    .parse()
+                    .map_err(|_| "--base needs a radix from 2 to 36")?;
+                set(Command::Base(radix), &mut command)?;
+            }
+            s if s.starts_with("--") => return Err(format!("unknown option {s}")),
+            _ if number.is_some() => return Err(format!("unexpected argument {arg}")),
+            _ => number = Some(arg),
+        }
+    }
+    Ok((command.unwrap_or(Command::Digits), number))
+}
+
+fn run(command: Command, number: &str) -> Result<Output, ConversionError> {
+    let text = match command {
+        Command::Help => USAGE.to_string(),
+        Command::Digits => {
+            let digits: Digits<u8> = number.parse()?;
+            let lines: Vec<String> = digits.iter().map(|d| d.to_string()).collect();
+            lines.join("\n")
+        }
+        Command::Sum => number.parse::<Digits<u64>>()?.sum()?.to_string(),
+        // Converted digit by digit, so numbers of any length work. The
+        // digits are i8 rather than u8 so that negative numbers parse.
+        Command::Base(radix) => number
+            .parse::<Digits<i8>>()?
+            .convert_radix(radix)?
+            .to_string(),
+        Command::Reverse => number.parse::<Digits<u8>>()?.reversed().to_string(),
+        Command::Luhn => {
+            let digits = Digits::<u8>::parse_with_leading_zeros(number)?;
+            return Ok(Output::Check(luhn::is_valid(&digits)));
+        }
+    };
+    Ok(Output::Text(text))
+}
+
+fn main() -> ExitCode {
+    let (command, number) = match parse_args(std::env::args().skip(1)) {
+        Ok((Command::Help, _)) => (Command::Help, String::new()),
+        Ok((command, Some(number))) => (command, number),
+        Ok((_, None)) => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(command, &number) {
+        Ok(Output::Text(text)) => {
+            println!("{text}");
+            ExitCode::SUCCESS
+        }
+        Ok(Output::Check(valid)) => {
+            println!("{}", if valid { "valid" } else { "invalid" });
+            if valid {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    fn text(s: &str) -> Output {
+        Output::Text(s.to_string())
+    }
+
+    #[test]
+    fn parse_args_works() {
+        assert_eq!(
+            parse_args(args("12345 --sum")),
+            Ok((Command::Sum, Some("12345".to_string())))
+        );
+        assert_eq!(
+            parse_args(args("--base 16 255")),
+            Ok((Command::Base(16), Some("255".to_string())))
+        );
+        assert_eq!(
+            parse_args(args("--luhn 4111111111111111")),
+            Ok((Command::Luhn, Some("4111111111111111".to_string())))
+        );
+        assert_eq!(
+            parse_args(args("42")),
+            Ok((Command::Digits, Some("42".to_string())))
+        );
+        assert_eq!(parse_args(args("--help 42")), Ok((Command::Help, None)));
+    }
+
+    #[test]
+    fn parse_args_throws_error_with_invalid_arguments() {
+        assert!(parse_args(args("1 --sum --reverse")).is_err());
+        assert!(parse_args(args("1 2")).is_err());
+        assert!(parse_args(args("1 --base")).is_err());
+        assert!(parse_args(args("1 --base x")).is_err());
+        assert_eq!(
+            parse_args(args("1 --frobnicate")),
+            Err("unknown option --frobnicate".to_string())
+        );
+    }
+
+    #[test]
+    fn run_works() {
+        assert_eq!(run(Command::Digits, "123"), Ok(text("1\n2\n3")));
+        assert_eq!(run(Command::Sum, "12345"), Ok(text("15")));
+        assert_eq!(run(Command::Base(16), "255"), Ok(text("ff")));
+        assert_eq!(run(Command::Base(2), "-5"), Ok(text("-101")));
+        assert_eq!(run(Command::Reverse, "123"), Ok(text("321")));
+        assert_eq!(
+            run(Command::Luhn, "4111111111111111"),
+            Ok(Output::Check(true))
+        );
+        assert_eq!(
+            run(Command::Luhn, "4111111111111112"),
+            Ok(Output::Check(false))
+        );
+    }
+
+    #[test]
+    fn run_works_beyond_integer_range() {
+        let number = "9".repeat(100);
+        assert_eq!(run(Command::Sum, &number), Ok(text("900")));
+        let hex =
+            "1249ad2594c37ceb0b2784c4ce0bf38ace408e211a7caab24308a82e8f0fffffffffffffffffffffffff";
+        assert_eq!(run(Command::Base(16), &number), Ok(text(hex)));
+        assert_eq!(
+            run(Command::Base(16), &format!("-{number}")),
+            Ok(text(&format!("-{hex}")))
+        );
+    }
+
+    #[test]
+    fn run_throws_error_with_invalid_number() {
+        assert!(run(Command::Sum, "12a").is_err());
+        assert!(run(Command::Base(37), "12").is_err());
+    }
+}
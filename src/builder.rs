@@ -0,0 +1,233 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use num_traits::{Num, NumCast};
+
+use crate::{radix_constant, ConversionError, Digits, Sign};
+
+/// Builds `Digits` one digit at a time, from either end, checking the digits
+/// against the radix only once in `build`.
+#[derive(Clone, Debug)]
+pub struct DigitsBuilder<T> {
+    values: VecDeque<T>,
+    radix: u32,
+    negative: bool,
+    width: Option<usize>,
+}
+
+impl<T> Default for DigitsBuilder<T> {
+    fn default() -> Self {
+        Self {
+            values: VecDeque::new(),
+            radix: 10,
+            negative: false,
+            width: None,
+        }
+    }
+}
+
+impl<T> DigitsBuilder<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a digit below the others, for building most significant
+    /// first.
+    pub fn push(mut self, digit: T) -> Self {
+        self.values.push_back(digit);
+        self
+    }
+
+    /// Prepends a digit above the others, for building least significant
+    /// first.
+    pub fn push_front(mut self, digit: T) -> Self {
+        self.values.push_front(digit);
+        self
+    }
+
+    /// Appends digits below the others, most significant first.
+    pub fn extend<I>(mut self, digits: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.values.extend(digits);
+        self
+    }
+
+    /// Defaults to 10.
+    pub fn radix(mut self, radix: u32) -> Self {
+        self.radix = radix;
+        self
+    }
+
+    /// Makes the number negative, unless it turns out to be zero.
+    pub fn negative(mut self) -> Self {
+        self.negative = true;
+        self
+    }
+
+    /// Pads with leading zeros to exactly `width` digits, in fixed-width
+    /// mode like `with_width`.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn build(&self) -> Result<Digits<T>, ConversionError> {
+        let radix_value = radix_constant::<T>(self.radix)?;
+        if self.values.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        if let Some(position) = self
+            .values
+            .iter()
+            .position(|&d| d < T::zero() || d >= radix_value)
+        {
+            return Err(ConversionError::invalid_digit(
+                position,
+                self.values[position],
+            ));
+        }
+        let values: Vec<T> = match self.width {
+            Some(width) if self.values.len() > width => {
+                return Err(ConversionError::InvalidLength {
+                    expected: width,
+                    found: self.values.len(),
+                })
+            }
+            Some(width) => core::iter::repeat_n(T::zero(), width - self.values.len())
+                .chain(self.values.iter().copied())
+                .collect(),
+            None => self.values.iter().copied().collect(),
+        };
+
+        let digits = Digits::new_signed(values, self.radix, self.negative);
+        if digits.sign == Sign::Negative && T::from(-1).is_none() {
+            return Err(ConversionError::NegativeInput);
+        }
+        Ok(match self.width {
+            Some(_) => digits.into_fixed_width(),
+            None => digits,
+        })
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn builder() -> DigitsBuilder<T> {
+        DigitsBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn builder_works() {
+        let digits = Digits::builder().push(4u32).push(2).build().unwrap();
+        assert_eq!(digits.to_int(), Ok(42));
+        let digits = Digits::builder().extend([1u32, 2, 3]).build().unwrap();
+        assert_eq!(digits.to_int(), Ok(123));
+        let zero = Digits::builder().push(0u32).build().unwrap();
+        assert_eq!(zero.sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn builder_works_least_significant_first() {
+        let mut builder = Digits::builder();
+        let mut n = 9_075u32;
+        loop {
+            builder = builder.push_front(n % 10);
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        assert_eq!(builder.build().unwrap().to_int(), Ok(9_075));
+    }
+
+    #[test]
+    fn builder_works_with_radix_and_sign() {
+        let digits = Digits::builder()
+            .extend([15i32, 15])
+            .radix(16)
+            .negative()
+            .build()
+            .unwrap();
+        assert_eq!(digits.radix(), 16);
+        assert_eq!(digits.to_int(), Ok(-255));
+        // Zero stays unsigned.
+        let zero = Digits::builder().push(0i32).negative().build().unwrap();
+        assert_eq!(zero.sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn builder_works_with_width() {
+        let digits = Digits::builder().push(7u8).width(3).build().unwrap();
+        assert_eq!(digits.to_string(), "007");
+        assert_eq!(digits.fixed_width(), Some(3));
+        assert!(digits == Digits::with_width(7, 3).unwrap());
+    }
+
+    #[test]
+    fn builder_throws_error_with_invalid_input() {
+        assert_eq!(
+            Digits::<u8>::builder().build().map(|d| d.to_int()),
+            Err(ConversionError::Empty)
+        );
+        assert_eq!(
+            Digits::builder()
+                .extend([1u8, 10])
+                .build()
+                .map(|d| d.to_int()),
+            Err(ConversionError::InvalidDigit {
+                position: 1,
+                value: 10
+            })
+        );
+        assert_eq!(
+            Digits::builder()
+                .push(1u8)
+                .radix(1)
+                .build()
+                .map(|d| d.to_int()),
+            Err(ConversionError::InvalidRadix { radix: 1 })
+        );
+        assert_eq!(
+            Digits::builder()
+                .extend([1u8, 2])
+                .width(1)
+                .build()
+                .map(|d| d.to_int()),
+            Err(ConversionError::InvalidLength {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Digits::builder()
+                .push(1u8)
+                .negative()
+                .build()
+                .map(|d| d.to_int()),
+            Err(ConversionError::NegativeInput)
+        );
+        assert!(Digits::builder().push(-1i8).build().is_err());
+    }
+
+    #[test]
+    fn builder_can_be_reused() {
+        let builder = Digits::builder().push(1u64).push(2);
+        let first = builder.build().unwrap();
+        let second = builder.push(3).build().unwrap();
+        assert_eq!(first.to_int(), Ok(12));
+        assert_eq!(second.to_int(), Ok(123));
+    }
+}
@@ -0,0 +1,821 @@
+//! Check digit algorithms that work directly on a digit sequence.
+//!
+//! The sequences are read most significant digit first, so digits parsed with
+//! `Digits::parse_with_leading_zeros` keep their leading zeros.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use num_traits::NumCast;
+
+use crate::{ConversionError, Digits};
+
+/// A sequence of decimal digits, most significant first, that the check digit
+/// algorithms can run over. Implemented for `Digits` and plain digit slices.
+pub trait DecimalDigits {
+    type Digit: NumCast + Copy;
+
+    /// The digits as `u32`s, or `None` if any of them is not decimal.
+    fn decimal_digits(&self) -> Option<Vec<u32>>;
+}
+
+impl<T> DecimalDigits for Digits<T>
+where
+    T: NumCast + Copy,
+{
+    type Digit = T;
+
+    fn decimal_digits(&self) -> Option<Vec<u32>> {
+        if self.radix != 10 {
+            return None;
+        }
+        self.values.decimal_digits()
+    }
+}
+
+impl<T> DecimalDigits for [T]
+where
+    T: NumCast + Copy,
+{
+    type Digit = T;
+
+    fn decimal_digits(&self) -> Option<Vec<u32>> {
+        self.iter()
+            .map(|d| d.to_u32().filter(|&d| d < 10))
+            .collect()
+    }
+}
+
+fn decimal_digits<D>(digits: &D) -> Result<Vec<u32>, ConversionError>
+where
+    D: DecimalDigits + ?Sized,
+{
+    digits.decimal_digits().ok_or(ConversionError::NotDecimal)
+}
+
+fn check_value<D>(check: u32) -> D::Digit
+where
+    D: DecimalDigits + ?Sized,
+{
+    <D::Digit as NumCast>::from(check).unwrap()
+}
+
+pub mod luhn {
+    use super::*;
+
+    fn sum(digits: &[u32], double_first: bool) -> u32 {
+        digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, &d)| {
+                if (i % 2 == 0) == double_first {
+                    let doubled = 2 * d;
+                    if doubled > 9 {
+                        doubled - 9
+                    } else {
+                        doubled
+                    }
+                } else {
+                    d
+                }
+            })
+            .fold(0, |acc, d| (acc + d) % 10)
+    }
+
+    /// Checks a sequence whose last digit is the Luhn check digit.
+    pub fn is_valid<D>(digits: &D) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        decimal_digits(digits).is_ok_and(|d| sum(&d, false) == 0)
+    }
+
+    /// Computes the digit to append to make the sequence Luhn valid.
+    pub fn check_digit<D>(digits: &D) -> Result<D::Digit, ConversionError>
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        let sum = sum(&decimal_digits(digits)?, true);
+        Ok(check_value::<D>((10 - sum) % 10))
+    }
+}
+
+pub mod verhoeff {
+    use super::*;
+
+    const MULTIPLY: [[u8; 10]; 10] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+        [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+        [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+        [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+        [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+        [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+        [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+        [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+        [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    ];
+
+    const PERMUTE: [[u8; 10]; 8] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+        [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+        [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+        [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+        [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+        [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+        [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+    ];
+
+    const INVERSE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+    // `offset` is 0 when the check digit is already in place and 1 when it
+    // is still to be appended.
+    fn checksum(digits: &[u32], offset: usize) -> u8 {
+        digits.iter().rev().enumerate().fold(0, |c, (i, &d)| {
+            MULTIPLY[c as usize][PERMUTE[(i + offset) % 8][d as usize] as usize]
+        })
+    }
+
+    /// Checks a sequence whose last digit is the Verhoeff check digit.
+    pub fn is_valid<D>(digits: &D) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        decimal_digits(digits).is_ok_and(|d| checksum(&d, 0) == 0)
+    }
+
+    /// Computes the digit to append to make the sequence Verhoeff valid.
+    pub fn check_digit<D>(digits: &D) -> Result<D::Digit, ConversionError>
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        let c = checksum(&decimal_digits(digits)?, 1);
+        Ok(check_value::<D>(INVERSE[c as usize] as u32))
+    }
+}
+
+pub mod damm {
+    use super::*;
+
+    const QUASIGROUP: [[u8; 10]; 10] = [
+        [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+        [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+        [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+        [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+        [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+        [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+        [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+        [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+        [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+        [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+    ];
+
+    fn interim(digits: &[u32]) -> u8 {
+        digits
+            .iter()
+            .fold(0, |i, &d| QUASIGROUP[i as usize][d as usize])
+    }
+
+    /// Checks a sequence whose last digit is the Damm check digit.
+    pub fn is_valid<D>(digits: &D) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        decimal_digits(digits).is_ok_and(|d| interim(&d) == 0)
+    }
+
+    /// Computes the digit to append to make the sequence Damm valid.
+    pub fn check_digit<D>(digits: &D) -> Result<D::Digit, ConversionError>
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        let i = interim(&decimal_digits(digits)?);
+        Ok(check_value::<D>(i as u32))
+    }
+}
+
+pub mod isbn10 {
+    use super::*;
+
+    /// An ISBN-10 check "digit", which is a value mod 11 and so may be `X`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum CheckDigit {
+        Digit(u8),
+        X,
+    }
+
+    impl CheckDigit {
+        pub fn value(self) -> u32 {
+            match self {
+                CheckDigit::Digit(d) => d as u32,
+                CheckDigit::X => 10,
+            }
+        }
+
+        pub fn from_char(c: char) -> Option<CheckDigit> {
+            match c {
+                'X' | 'x' => Some(CheckDigit::X),
+                _ => c.to_digit(10).map(|d| CheckDigit::Digit(d as u8)),
+            }
+        }
+    }
+
+    impl fmt::Display for CheckDigit {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                CheckDigit::Digit(d) => write!(f, "{}", d),
+                CheckDigit::X => write!(f, "X"),
+            }
+        }
+    }
+
+    fn weighted_sum(payload: &[u32]) -> u32 {
+        payload.iter().zip((2..=10).rev()).map(|(d, w)| d * w).sum()
+    }
+
+    /// Checks a full ten digit ISBN whose check digit is numeric. Use
+    /// `is_valid_with` or `is_valid_str` when it may be `X`.
+    pub fn is_valid<D>(digits: &D) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        match digits.decimal_digits() {
+            Some(d) if d.len() == 10 => is_valid_with(&d[..9], CheckDigit::Digit(d[9] as u8)),
+            _ => false,
+        }
+    }
+
+    /// Checks nine payload digits against a separate check digit.
+    pub fn is_valid_with<D>(payload: &D, check: CheckDigit) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        match payload.decimal_digits() {
+            Some(d) if d.len() == 9 => (weighted_sum(&d) + check.value()).is_multiple_of(11),
+            _ => false,
+        }
+    }
+
+    /// Checks an ISBN written as text, e.g. `"0-8044-2957-X"`. Hyphens and
+    /// spaces are ignored and only the last character may be `X`.
+    pub fn is_valid_str(s: &str) -> bool {
+        let chars: Vec<char> = s.chars().filter(|&c| c != '-' && c != ' ').collect();
+        let Some((&last, payload)) = chars.split_last() else {
+            return false;
+        };
+        let payload: Option<Vec<u32>> = payload.iter().map(|c| c.to_digit(10)).collect();
+        match (payload, CheckDigit::from_char(last)) {
+            (Some(payload), Some(check)) => is_valid_with(&payload[..], check),
+            _ => false,
+        }
+    }
+
+    /// Computes the check digit for nine payload digits.
+    pub fn check_digit<D>(payload: &D) -> Result<CheckDigit, ConversionError>
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        let d = decimal_digits(payload)?;
+        if d.len() != 9 {
+            return Err(ConversionError::InvalidLength {
+                expected: 9,
+                found: d.len(),
+            });
+        }
+        match (11 - weighted_sum(&d) % 11) % 11 {
+            10 => Ok(CheckDigit::X),
+            check => Ok(CheckDigit::Digit(check as u8)),
+        }
+    }
+}
+
+pub mod isbn13 {
+    use super::*;
+
+    fn weighted_sum(digits: &[u32]) -> u32 {
+        digits
+            .iter()
+            .zip([1, 3].iter().cycle())
+            .map(|(d, w)| d * w)
+            .sum()
+    }
+
+    /// Checks a full thirteen digit ISBN.
+    pub fn is_valid<D>(digits: &D) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        match digits.decimal_digits() {
+            Some(d) if d.len() == 13 => weighted_sum(&d).is_multiple_of(10),
+            _ => false,
+        }
+    }
+
+    /// Computes the check digit for twelve payload digits.
+    pub fn check_digit<D>(payload: &D) -> Result<D::Digit, ConversionError>
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        let d = decimal_digits(payload)?;
+        if d.len() != 12 {
+            return Err(ConversionError::InvalidLength {
+                expected: 12,
+                found: d.len(),
+            });
+        }
+        Ok(check_value::<D>((10 - weighted_sum(&d) % 10) % 10))
+    }
+}
+
+pub mod ean {
+    use super::*;
+
+    /// The full code lengths of EAN-8, UPC-A and EAN-13.
+    const LENGTHS: [usize; 3] = [8, 12, 13];
+
+    // The digits are weighted 3, 1, 3, ... from the right of the payload, so
+    // all three formats share the sum.
+    fn weighted_sum(payload: &[u32]) -> u32 {
+        payload
+            .iter()
+            .rev()
+            .zip([3, 1].iter().cycle())
+            .map(|(d, w)| d * w)
+            .sum()
+    }
+
+    /// Checks a full EAN-8, UPC-A or EAN-13 code, telling them apart by
+    /// length. UPC-A codes often start with zero, so parse them with
+    /// `Digits::parse_with_leading_zeros`.
+    pub fn is_valid<D>(digits: &D) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        match digits.decimal_digits() {
+            Some(d) if LENGTHS.contains(&d.len()) => {
+                let (&check, payload) = d.split_last().unwrap();
+                (weighted_sum(payload) + check).is_multiple_of(10)
+            }
+            _ => false,
+        }
+    }
+
+    /// Computes the check digit for a 7, 11 or 12 digit payload. A payload
+    /// of another length is reported against the nearest of these.
+    pub fn check_digit<D>(payload: &D) -> Result<D::Digit, ConversionError>
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        let d = decimal_digits(payload)?;
+        if !LENGTHS.contains(&(d.len() + 1)) {
+            let expected = LENGTHS
+                .iter()
+                .map(|len| len - 1)
+                .min_by_key(|len| len.abs_diff(d.len()))
+                .unwrap();
+            return Err(ConversionError::InvalidLength {
+                expected,
+                found: d.len(),
+            });
+        }
+        Ok(check_value::<D>((10 - weighted_sum(&d) % 10) % 10))
+    }
+}
+
+pub mod iban {
+    use super::*;
+
+    // The remainder mod 97, taken one digit at a time so any length works.
+    fn mod97(digits: &[u32]) -> u32 {
+        digits.iter().fold(0, |rem, d| (rem * 10 + d) % 97)
+    }
+
+    /// Replaces each letter with its two digit value, `A` = 10 up to
+    /// `Z` = 35, and keeps the digits, ignoring spaces. `"GB82"` becomes
+    /// `[1, 6, 1, 1, 8, 2]`.
+    pub fn substitute(s: &str) -> Result<Vec<u8>, ConversionError> {
+        let mut digits = Vec::with_capacity(2 * s.len());
+        for (position, c) in s.chars().enumerate().filter(|&(_, c)| c != ' ') {
+            match c.to_digit(36) {
+                Some(d) if d < 10 => digits.push(d as u8),
+                Some(d) => digits.extend([d as u8 / 10, d as u8 % 10]),
+                None => return Err(ConversionError::InvalidChar { position, value: c }),
+            }
+        }
+        Ok(digits)
+    }
+
+    /// Checks a substituted IBAN, with the country code and check digits
+    /// already moved to the end.
+    pub fn is_valid<D>(digits: &D) -> bool
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        decimal_digits(digits).is_ok_and(|d| mod97(&d) == 1)
+    }
+
+    /// Checks an IBAN as written, e.g. `"GB82 WEST 1234 5698 7654 32"`.
+    pub fn is_valid_str(s: &str) -> bool {
+        let s: String = s.chars().filter(|&c| c != ' ').collect();
+        if s.len() < 5 || !s.is_char_boundary(4) {
+            return false;
+        }
+        let (front, rest) = s.split_at(4);
+        let (country, check) = front.split_at(2);
+        if !country.chars().all(|c| c.is_ascii_alphabetic())
+            || !check.chars().all(|c| c.is_ascii_digit())
+        {
+            return false;
+        }
+        substitute(&(String::from(rest) + front)).is_ok_and(|d| is_valid(&d[..]))
+    }
+
+    /// Computes the two check digits for a substituted IBAN without them,
+    /// i.e. the account number followed by the country code.
+    pub fn check_digits<D>(digits: &D) -> Result<[D::Digit; 2], ConversionError>
+    where
+        D: DecimalDigits + ?Sized,
+    {
+        let mut d = decimal_digits(digits)?;
+        d.extend([0, 0]);
+        let check = 98 - mod97(&d);
+        Ok([check_value::<D>(check / 10), check_value::<D>(check % 10)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use core::str::FromStr;
+
+    #[test]
+    fn luhn_is_valid_works() {
+        assert!(luhn::is_valid(&Digits::from(79_927_398_713u64)));
+        assert!(luhn::is_valid(&Digits::from(0u32)));
+        assert!(!luhn::is_valid(&Digits::from(79_927_398_710u64)));
+        assert!(!luhn::is_valid(&Digits::from(79_927_398_731u64)));
+    }
+
+    #[test]
+    fn luhn_is_valid_works_with_card_and_imei_numbers() {
+        let visa = Digits::<u8>::from_str("4111111111111111").unwrap();
+        assert!(luhn::is_valid(&visa));
+        let imei = Digits::<u8>::from_str("490154203237518").unwrap();
+        assert!(luhn::is_valid(&imei));
+    }
+
+    #[test]
+    fn luhn_is_valid_works_with_leading_zeros() {
+        let digits = Digits::<u8>::parse_with_leading_zeros("0079927398713").unwrap();
+        assert!(luhn::is_valid(&digits));
+    }
+
+    #[test]
+    fn luhn_is_valid_works_beyond_integer_range() {
+        let digits = Digits::<u8>::from_str(&"1".repeat(200)).unwrap();
+        assert!(luhn::is_valid(&digits));
+        let digits = Digits::<u8>::from_str(&"1".repeat(199)).unwrap();
+        assert!(!luhn::is_valid(&digits));
+    }
+
+    #[test]
+    fn luhn_is_valid_rejects_non_decimal_digits() {
+        let digits = Digits::with_radix(0x3cu32, 16).unwrap();
+        assert!(!luhn::is_valid(&digits));
+    }
+
+    #[test]
+    fn luhn_check_digit_works() {
+        assert_eq!(luhn::check_digit(&Digits::from(7_992_739_871u64)), Ok(3));
+        assert_eq!(luhn::check_digit(&Digits::from(0u32)), Ok(0));
+
+        let payload = Digits::<u8>::from_str("49015420323751").unwrap();
+        assert_eq!(luhn::check_digit(&payload), Ok(8));
+    }
+
+    #[test]
+    fn luhn_check_digit_agrees_with_is_valid() {
+        for n in 1u64..1_000 {
+            let check = luhn::check_digit(&Digits::from(n)).unwrap();
+            assert!(luhn::is_valid(&Digits::from(n * 10 + check)));
+        }
+    }
+
+    #[test]
+    fn luhn_check_digit_throws_error_with_non_decimal_digits() {
+        let digits = Digits::with_radix(0x3cu32, 16).unwrap();
+        assert_eq!(luhn::check_digit(&digits), Err(ConversionError::NotDecimal));
+    }
+
+    #[test]
+    fn luhn_works_with_slices() {
+        let digits: &[u8] = &[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3];
+        assert!(luhn::is_valid(digits));
+        assert_eq!(luhn::check_digit(&digits[..10]), Ok(3));
+        assert!(!luhn::is_valid(&[1u8, 10][..]));
+    }
+
+    #[test]
+    fn verhoeff_is_valid_works() {
+        assert!(verhoeff::is_valid(&Digits::from(2_363u32)));
+        assert!(verhoeff::is_valid(&Digits::from(758_722u32)));
+        assert!(!verhoeff::is_valid(&Digits::from(2_364u32)));
+        assert!(!verhoeff::is_valid(&Digits::from(758_772u32)));
+    }
+
+    #[test]
+    fn verhoeff_is_valid_catches_transpositions_luhn_misses() {
+        // Luhn cannot tell 09 from 90.
+        assert!(luhn::is_valid(
+            &Digits::<u8>::parse_with_leading_zeros("091").unwrap()
+        ));
+        assert!(luhn::is_valid(&Digits::<u8>::from_str("901").unwrap()));
+
+        let check = verhoeff::check_digit(&[0u8, 9][..]).unwrap();
+        assert!(verhoeff::is_valid(&[0, 9, check][..]));
+        assert!(!verhoeff::is_valid(&[9, 0, check][..]));
+    }
+
+    #[test]
+    fn verhoeff_check_digit_works() {
+        assert_eq!(verhoeff::check_digit(&Digits::from(236u32)), Ok(3));
+        assert_eq!(verhoeff::check_digit(&Digits::from(75_872u32)), Ok(2));
+        assert_eq!(verhoeff::check_digit(&[1u8, 2, 3, 4, 5][..]), Ok(1));
+    }
+
+    #[test]
+    fn verhoeff_check_digit_agrees_with_is_valid() {
+        for n in 1u64..1_000 {
+            let check = verhoeff::check_digit(&Digits::from(n)).unwrap();
+            assert!(verhoeff::is_valid(&Digits::from(n * 10 + check)));
+        }
+    }
+
+    #[test]
+    fn verhoeff_check_digit_throws_error_with_non_decimal_digits() {
+        assert_eq!(
+            verhoeff::check_digit(&[1u8, 12][..]),
+            Err(ConversionError::NotDecimal)
+        );
+    }
+
+    #[test]
+    fn damm_is_valid_works() {
+        assert!(damm::is_valid(&Digits::from(5_724u32)));
+        assert!(!damm::is_valid(&Digits::from(5_274u32)));
+        assert!(!damm::is_valid(&Digits::from(5_723u32)));
+    }
+
+    #[test]
+    fn damm_check_digit_works() {
+        assert_eq!(damm::check_digit(&Digits::from(572u32)), Ok(4));
+        assert_eq!(damm::check_digit(&[5u8, 7, 2][..]), Ok(4));
+    }
+
+    #[test]
+    fn damm_catches_every_adjacent_transposition() {
+        for n in 10u32..100 {
+            let (a, b) = (n / 10, n % 10);
+            if a == b {
+                continue;
+            }
+            let check = damm::check_digit(&[a, b][..]).unwrap();
+            assert!(damm::is_valid(&[a, b, check][..]));
+            assert!(!damm::is_valid(&[b, a, check][..]));
+        }
+    }
+
+    #[test]
+    fn damm_check_digit_throws_error_with_non_decimal_digits() {
+        let digits = Digits::with_radix(0x3cu32, 16).unwrap();
+        assert!(!damm::is_valid(&digits));
+        assert_eq!(damm::check_digit(&digits), Err(ConversionError::NotDecimal));
+    }
+
+    #[test]
+    fn isbn10_is_valid_works() {
+        let isbn = Digits::<u8>::parse_with_leading_zeros("0306406152").unwrap();
+        assert!(isbn10::is_valid(&isbn));
+        assert!(isbn10::is_valid(&Digits::from(1_566_199_093u64)));
+        assert!(!isbn10::is_valid(&Digits::from(1_566_199_094u64)));
+        assert!(!isbn10::is_valid(&Digits::from(306_406_152u64)));
+    }
+
+    #[test]
+    fn isbn10_is_valid_with_works() {
+        let payload: &[u8] = &[0, 8, 0, 4, 4, 2, 9, 5, 7];
+        assert!(isbn10::is_valid_with(payload, isbn10::CheckDigit::X));
+        assert!(!isbn10::is_valid_with(
+            payload,
+            isbn10::CheckDigit::Digit(0)
+        ));
+        assert!(!isbn10::is_valid_with(&payload[1..], isbn10::CheckDigit::X));
+    }
+
+    #[test]
+    fn isbn10_is_valid_str_works() {
+        assert!(isbn10::is_valid_str("0-8044-2957-X"));
+        assert!(isbn10::is_valid_str("080442957x"));
+        assert!(isbn10::is_valid_str("3-16-148410-X"));
+        assert!(isbn10::is_valid_str("0 306 40615 2"));
+        assert!(!isbn10::is_valid_str("0-306-40615-X"));
+        assert!(!isbn10::is_valid_str("X-306-40615-2"));
+        assert!(!isbn10::is_valid_str(""));
+    }
+
+    #[test]
+    fn isbn10_check_digit_works() {
+        let payload = Digits::<u8>::parse_with_leading_zeros("030640615").unwrap();
+        assert_eq!(
+            isbn10::check_digit(&payload),
+            Ok(isbn10::CheckDigit::Digit(2))
+        );
+        let payload: &[u8] = &[0, 8, 0, 4, 4, 2, 9, 5, 7];
+        assert_eq!(isbn10::check_digit(payload), Ok(isbn10::CheckDigit::X));
+        assert_eq!(isbn10::CheckDigit::X.to_string(), "X");
+        assert_eq!(isbn10::CheckDigit::Digit(2).to_string(), "2");
+    }
+
+    #[test]
+    fn isbn10_check_digit_throws_error_with_wrong_length() {
+        assert_eq!(
+            isbn10::check_digit(&Digits::from(3_064_061u32)),
+            Err(ConversionError::InvalidLength {
+                expected: 9,
+                found: 7
+            })
+        );
+    }
+
+    #[test]
+    fn isbn13_is_valid_works() {
+        assert!(isbn13::is_valid(&Digits::from(9_780_306_406_157u64)));
+        assert!(isbn13::is_valid(&Digits::from(9_783_161_484_100u64)));
+        assert!(!isbn13::is_valid(&Digits::from(9_780_306_406_158u64)));
+        assert!(!isbn13::is_valid(&Digits::from(978_030_640_615u64)));
+    }
+
+    #[test]
+    fn isbn13_check_digit_works() {
+        assert_eq!(
+            isbn13::check_digit(&Digits::from(978_030_640_615u64)),
+            Ok(7)
+        );
+        assert_eq!(
+            isbn13::check_digit(&Digits::from(978_316_148_410u64)),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn isbn13_check_digit_throws_error_with_wrong_length() {
+        assert_eq!(
+            isbn13::check_digit(&Digits::from(97_803_064_061u64)),
+            Err(ConversionError::InvalidLength {
+                expected: 12,
+                found: 11
+            })
+        );
+    }
+
+    #[test]
+    fn iban_substitute_works() {
+        assert_eq!(iban::substitute("GB82"), Ok(vec![1, 6, 1, 1, 8, 2]));
+        assert_eq!(iban::substitute("a 1z"), Ok(vec![1, 0, 1, 3, 5]));
+        assert_eq!(
+            iban::substitute("GB-82"),
+            Err(ConversionError::InvalidChar {
+                position: 2,
+                value: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn iban_is_valid_works() {
+        let digits = iban::substitute("WEST12345698765432GB82").unwrap();
+        assert!(iban::is_valid(&digits[..]));
+        let digits = iban::substitute("WEST12345698765432GB83").unwrap();
+        assert!(!iban::is_valid(&digits[..]));
+        assert!(!iban::is_valid(&Digits::with_radix(255u32, 16).unwrap()));
+    }
+
+    #[test]
+    fn iban_is_valid_str_works() {
+        assert!(iban::is_valid_str("GB82 WEST 1234 5698 7654 32"));
+        assert!(iban::is_valid_str("DE89370400440532013000"));
+        assert!(iban::is_valid_str("gb82west12345698765432"));
+        assert!(!iban::is_valid_str("GB82 WEST 1234 5698 7654 33"));
+        assert!(!iban::is_valid_str("GB28 WEST 1234 5698 7654 32"));
+        assert!(!iban::is_valid_str("1282 WEST 1234 5698 7654 32"));
+        assert!(!iban::is_valid_str("GB82"));
+        // These pass the mod-97 check once the letters are substituted.
+        assert!(!iban::is_valid_str("GBAK WEST 1234 5698 7654 32"));
+        assert!(!iban::is_valid_str("GB8B WEST 1234 5698 7654 32"));
+    }
+
+    #[test]
+    fn iban_is_valid_works_beyond_integer_range() {
+        // Maltese IBANs have 31 characters, 45 digits once substituted.
+        let digits = iban::substitute("MALT011000012345MTLCAST001SMT84").unwrap();
+        assert_eq!(digits.len(), 45);
+        assert!(iban::is_valid(&digits[..]));
+        assert!(iban::is_valid_str("MT84 MALT 0110 0001 2345 MTLC AST0 01S"));
+    }
+
+    #[test]
+    fn iban_check_digits_works() {
+        let digits = iban::substitute("WEST12345698765432GB").unwrap();
+        assert_eq!(iban::check_digits(&digits[..]), Ok([8, 2]));
+        let digits = iban::substitute("370400440532013000DE").unwrap();
+        let digits = Digits::<u8>::from_radix(digits, 10).unwrap();
+        assert_eq!(iban::check_digits(&digits), Ok([8, 9]));
+    }
+
+    #[test]
+    fn iban_check_digits_agrees_with_is_valid() {
+        for account in ["1", "0000", "123456789012345678901234567890"] {
+            let mut digits = iban::substitute(&(String::from(account) + "NL")).unwrap();
+            let check = iban::check_digits(&digits[..]).unwrap();
+            digits.extend(check);
+            assert!(iban::is_valid(&digits[..]));
+        }
+    }
+
+    #[test]
+    fn iban_check_digits_throws_error_with_non_decimal_digits() {
+        let digits = Digits::with_radix(255u32, 16).unwrap();
+        assert_eq!(
+            iban::check_digits(&digits),
+            Err(ConversionError::NotDecimal)
+        );
+    }
+
+    #[test]
+    fn ean_is_valid_works() {
+        assert!(ean::is_valid(&Digits::from(4_006_381_333_931u64)));
+        assert!(ean::is_valid(&Digits::from(5_901_234_123_457u64)));
+        assert!(!ean::is_valid(&Digits::from(4_006_381_333_932u64)));
+        assert!(ean::is_valid(&Digits::from(73_513_537u32)));
+        assert!(ean::is_valid(&Digits::from(96_385_074u32)));
+        assert!(!ean::is_valid(&Digits::from(96_385_075u32)));
+    }
+
+    #[test]
+    fn ean_is_valid_works_with_upc_a() {
+        let upc = Digits::<u8>::parse_with_leading_zeros("036000291452").unwrap();
+        assert!(ean::is_valid(&upc));
+        // The leading zero matters, without it the length is wrong.
+        assert!(!ean::is_valid(&Digits::from(36_000_291_452u64)));
+        // A UPC-A code is the EAN-13 code with a zero in front.
+        let ean13 = Digits::<u8>::parse_with_leading_zeros("0036000291452").unwrap();
+        assert!(ean::is_valid(&ean13));
+    }
+
+    #[test]
+    fn ean_is_valid_rejects_wrong_length() {
+        assert!(!ean::is_valid(&Digits::from(0u32)));
+        assert!(!ean::is_valid(&Digits::from(1_234_567_890u64)));
+        assert!(!ean::is_valid(&Digits::with_radix(255u32, 16).unwrap()));
+    }
+
+    #[test]
+    fn ean_check_digit_works() {
+        assert_eq!(ean::check_digit(&Digits::from(400_638_133_393u64)), Ok(1));
+        assert_eq!(ean::check_digit(&Digits::from(7_351_353u32)), Ok(7));
+        let payload = Digits::<u8>::parse_with_leading_zeros("03600029145").unwrap();
+        assert_eq!(ean::check_digit(&payload), Ok(2));
+        let payload: &[u8] = &[9, 7, 8, 0, 3, 0, 6, 4, 0, 6, 1, 5];
+        assert_eq!(ean::check_digit(payload), isbn13::check_digit(payload));
+    }
+
+    #[test]
+    fn ean_check_digit_throws_error_with_wrong_length() {
+        assert_eq!(
+            ean::check_digit(&Digits::from(12_345u32)),
+            Err(ConversionError::InvalidLength {
+                expected: 7,
+                found: 5
+            })
+        );
+        assert_eq!(
+            ean::check_digit(&Digits::from(1_234_567_890u64)),
+            Err(ConversionError::InvalidLength {
+                expected: 11,
+                found: 10
+            })
+        );
+        assert_eq!(
+            ean::check_digit(&Digits::from(12_345_678_901_234u64)),
+            Err(ConversionError::InvalidLength {
+                expected: 12,
+                found: 14
+            })
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use num_traits::{Num, NumCast};
+
+use crate::{strip_leading_zeros, to_mixed_radix, Digits, Sign};
+
+// A Mersenne prime, so the hash can reduce the value without caring which
+// radix it is written in.
+const HASH_MODULUS: u128 = (1 << 61) - 1;
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    // The digits of the absolute value in `radix`, without leading zeros.
+    pub(crate) fn magnitude_in(&self, radix: u32) -> Vec<u64> {
+        let mut values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        if radix != self.radix {
+            values = to_mixed_radix(&values, self.radix as u64, |_| radix as u64);
+        }
+        strip_leading_zeros(&mut values);
+        values
+    }
+}
+
+fn sign_rank(sign: Sign) -> i8 {
+    match sign {
+        Sign::Negative => -1,
+        Sign::Zero => 0,
+        Sign::Positive => 1,
+    }
+}
+
+/// Digits compare by the number they represent, so leading zeros and the
+/// radix make no difference: `042` equals `42`, and `ff` in hexadecimal
+/// equals `255` in decimal. Digits with a fixed width also compare by their
+/// width, so a fixed `007` differs from `7` and from a fixed `07`.
+impl<T> PartialEq for Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Digits<T> where T: Num + NumCast + Copy {}
+
+impl<T> PartialOrd for Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Equal numbers are ordered by width, with the variable width first.
+        self.cmp_value(other)
+            .then_with(|| self.fixed_width().cmp(&other.fixed_width()))
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn cmp_value(&self, other: &Self) -> Ordering {
+        let by_sign = sign_rank(self.sign).cmp(&sign_rank(other.sign));
+        if by_sign != Ordering::Equal || self.sign == Sign::Zero {
+            return by_sign;
+        }
+
+        // Without leading zeros the longer magnitude is the larger one.
+        let (a, b) = (
+            self.magnitude_in(self.radix),
+            other.magnitude_in(self.radix),
+        );
+        let by_magnitude = a.len().cmp(&b.len()).then_with(|| a.cmp(&b));
+        match self.sign {
+            Sign::Negative => by_magnitude.reverse(),
+            _ => by_magnitude,
+        }
+    }
+}
+
+/// Hashes the sign and the value modulo a prime, which is the same in every
+/// radix and so agrees with `Eq`.
+impl<T> Hash for Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let radix = self.radix as u128;
+        let residue = self.values.iter().fold(0u128, |acc, d| {
+            (acc * radix + d.to_u128().unwrap()) % HASH_MODULUS
+        });
+        sign_rank(self.sign).hash(state);
+        (residue as u64).hash(state);
+        self.fixed_width().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn eq_works() {
+        assert!(Digits::from(42u32) == Digits::from(42u32));
+        assert!(Digits::from(42u32) != Digits::from(24u32));
+        assert!(Digits::try_from(-42).unwrap() != Digits::try_from(42).unwrap());
+    }
+
+    #[test]
+    fn eq_ignores_leading_zeros_and_radix() {
+        let padded = Digits::<u32>::parse_with_leading_zeros("0042").unwrap();
+        assert!(padded == Digits::from(42u32));
+        assert!(Digits::with_radix(255u32, 16).unwrap() == Digits::from(255u32));
+        assert!(Digits::with_radix(255u32, 2).unwrap() == Digits::with_radix(255u32, 16).unwrap());
+        let zero = Digits::<u32>::parse_with_leading_zeros("000").unwrap();
+        assert!(zero == Digits::from(0u32));
+    }
+
+    #[test]
+    fn cmp_works() {
+        let mut numbers: Vec<Digits<i32>> = [5, -12, 0, 100, -3, 99, -100]
+            .into_iter()
+            .map(|n| Digits::try_from(n).unwrap())
+            .collect();
+        numbers.sort();
+        let sorted: Vec<i32> = numbers.iter().map(|d| d.to_int().unwrap()).collect();
+        assert_eq!(sorted, [-100, -12, -3, 0, 5, 99, 100]);
+    }
+
+    #[test]
+    fn cmp_works_with_radix() {
+        let hex = Digits::with_radix(0x100u32, 16).unwrap();
+        assert!(hex > Digits::from(255u32));
+        assert!(hex < Digits::from(257u32));
+        assert!(Digits::with_radix(-8i32, 2).unwrap() < Digits::try_from(-7).unwrap());
+        let padded = Digits::<u32>::parse_with_leading_zeros("0009").unwrap();
+        assert!(padded < Digits::from(10u32));
+    }
+
+    #[test]
+    fn cmp_works_beyond_integer_range() {
+        assert!(crate::factorial_digits(30) < crate::factorial_digits(31));
+        assert!(crate::fibonacci_digits(200) > crate::factorial_digits(30));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_agrees_with_eq() {
+        let padded = Digits::<u32>::parse_with_leading_zeros("0255").unwrap();
+        let hex = Digits::with_radix(255u32, 16).unwrap();
+        assert_eq!(hash_of(&padded), hash_of(&Digits::from(255u32)));
+        assert_eq!(hash_of(&hex), hash_of(&Digits::from(255u32)));
+        assert_ne!(
+            hash_of(&Digits::try_from(-255).unwrap()),
+            hash_of(&Digits::try_from(255).unwrap())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn digits_work_as_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut counts = HashMap::new();
+        for n in [1u32, 2, 1, 3, 1] {
+            *counts.entry(Digits::from(n)).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&Digits::with_radix(1u32, 2).unwrap()], 3);
+
+        let map: BTreeMap<Digits<u32>, &str> = [(10u32, "ten"), (2, "two"), (7, "seven")]
+            .into_iter()
+            .map(|(n, s)| (Digits::from(n), s))
+            .collect();
+        let names: Vec<&str> = map.values().copied().collect();
+        assert_eq!(names, ["two", "seven", "ten"]);
+    }
+}
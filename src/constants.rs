@@ -0,0 +1,146 @@
+//! Unbounded spigot generators for the decimal digits of π and e.
+//!
+//! Both iterators never end and keep all of their state as exact integers,
+//! so each digit comes out correct however far they are taken.
+
+use core::iter::FusedIterator;
+
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// The decimal digits of π, starting 3, 1, 4, 1, 5.
+pub fn pi_digits() -> PiDigits {
+    PiDigits {
+        q: BigInt::one(),
+        r: BigInt::zero(),
+        t: BigInt::one(),
+        k: 1,
+        n: BigInt::from(3),
+        l: 3,
+    }
+}
+
+/// The decimal digits of e, starting 2, 7, 1, 8, 2.
+pub fn e_digits() -> EDigits {
+    EDigits {
+        q: BigInt::one(),
+        r: BigInt::zero(),
+        t: BigInt::one(),
+        k: 0,
+    }
+}
+
+/// Gibbons' streaming spigot, which composes the linear fractional
+/// transformations of a series for π and emits a digit once it is certain.
+#[derive(Clone, Debug)]
+pub struct PiDigits {
+    q: BigInt,
+    r: BigInt,
+    t: BigInt,
+    k: u64,
+    n: BigInt,
+    l: u64,
+}
+
+impl Iterator for PiDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if &self.q * 4 + &self.r - &self.t < &self.n * &self.t {
+                let digit = self.n.to_u8().unwrap();
+                let n = (&self.q * 3 + &self.r) * 10 / &self.t - &self.n * 10;
+                self.r = (&self.r - &self.n * &self.t) * 10;
+                self.q *= 10;
+                self.n = n;
+                return Some(digit);
+            }
+
+            let (k, l) = (self.k, self.l);
+            self.n = (&self.q * (7 * k + 2) + &self.r * l) / (&self.t * l);
+            self.r = (&self.q * 2 + &self.r) * l;
+            self.q *= k;
+            self.t *= l;
+            self.k += 1;
+            self.l += 2;
+        }
+    }
+}
+
+impl FusedIterator for PiDigits {}
+
+/// Composes x -> 1 + x / k for k = 1, 2, ... as x -> (q x + r) / t. The tail
+/// of the series always lies in [1, 2], so a digit is emitted once both ends
+/// of that interval agree on it.
+#[derive(Clone, Debug)]
+pub struct EDigits {
+    q: BigInt,
+    r: BigInt,
+    t: BigInt,
+    k: u64,
+}
+
+impl Iterator for EDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.k > 0 {
+                let low = (&self.q + &self.r) / &self.t;
+                let high = (&self.q * 2 + &self.r) / &self.t;
+                if low == high {
+                    self.r = (&self.r - &low * &self.t) * 10;
+                    self.q *= 10;
+                    return Some(low.to_u8().unwrap());
+                }
+            }
+
+            self.k += 1;
+            self.r = (&self.q + &self.r) * self.k;
+            self.t *= self.k;
+        }
+    }
+}
+
+impl FusedIterator for EDigits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn digits_of(s: &str) -> Vec<u8> {
+        s.bytes().map(|b| b - b'0').collect()
+    }
+
+    #[test]
+    fn pi_digits_works() {
+        let digits: Vec<u8> = pi_digits().take(50).collect();
+        assert_eq!(
+            digits,
+            digits_of("31415926535897932384626433832795028841971693993751")
+        );
+    }
+
+    #[test]
+    fn pi_digits_works_far_out() {
+        // The Feynman point: six nines starting at the 762nd decimal.
+        let digits: Vec<u8> = pi_digits().skip(762).take(6).collect();
+        assert_eq!(digits, [9; 6]);
+    }
+
+    #[test]
+    fn e_digits_works() {
+        let digits: Vec<u8> = e_digits().take(50).collect();
+        assert_eq!(
+            digits,
+            digits_of("27182818284590452353602874713526624977572470936999")
+        );
+    }
+
+    #[test]
+    fn e_digits_works_far_out() {
+        let digits: Vec<u8> = e_digits().skip(990).take(10).collect();
+        assert_eq!(digits, digits_of("8957035035"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Decimal digits of a `u64` in `const` context, for lookup tables built at
+//! compile time where the heap allocating `Digits` cannot be used.
+//!
+//! ```
+//! use digits::{digit_count, DigitArray};
+//!
+//! const SUMS: [u8; 100] = {
+//!     let mut sums = [0; 100];
+//!     let mut n = 0;
+//!     while n < 100 {
+//!         let digits = DigitArray::new(n as u64);
+//!         let mut i = 0;
+//!         while i < digits.len() {
+//!             sums[n] += digits.as_slice()[i];
+//!             i += 1;
+//!         }
+//!         n += 1;
+//!     }
+//!     sums
+//! };
+//! assert_eq!(SUMS[99], 18);
+//! assert_eq!(digit_count(u64::MAX), 20);
+//! ```
+
+/// The number of decimal digits in `n`, 1 for zero.
+pub const fn digit_count(n: u64) -> usize {
+    match n.checked_ilog10() {
+        Some(log) => log as usize + 1,
+        None => 1,
+    }
+}
+
+/// Digit `i` of `n` counting from the most significant, like indexing
+/// `Digits`, or `None` past the last digit.
+pub const fn nth_digit(n: u64, i: usize) -> Option<u8> {
+    let count = digit_count(n);
+    if i >= count {
+        return None;
+    }
+    Some((n / 10u64.pow((count - 1 - i) as u32) % 10) as u8)
+}
+
+/// The decimal digits of a `u64`, most significant first, in a fixed array
+/// with room for the longest one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitArray {
+    digits: [u8; 20],
+    len: u8,
+}
+
+impl DigitArray {
+    pub const fn new(mut n: u64) -> Self {
+        let len = digit_count(n);
+        let mut digits = [0; 20];
+        let mut i = len;
+        while i > 0 {
+            i -= 1;
+            digits[i] = (n % 10) as u8;
+            n /= 10;
+        }
+        DigitArray {
+            digits,
+            len: len as u8,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Always false, as zero has a digit too.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The digits, without the unused end of the array.
+    pub const fn as_slice(&self) -> &[u8] {
+        self.digits.split_at(self.len as usize).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Digits;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn digit_count_works() {
+        assert_eq!(digit_count(0), 1);
+        assert_eq!(digit_count(9), 1);
+        assert_eq!(digit_count(10), 2);
+        assert_eq!(digit_count(u64::MAX), 20);
+        for n in (0u64..1_000_000).step_by(37) {
+            assert_eq!(digit_count(n), Digits::from(n).len());
+        }
+    }
+
+    #[test]
+    fn nth_digit_works() {
+        assert_eq!(nth_digit(1234, 0), Some(1));
+        assert_eq!(nth_digit(1234, 3), Some(4));
+        assert_eq!(nth_digit(1234, 4), None);
+        assert_eq!(nth_digit(0, 0), Some(0));
+        assert_eq!(nth_digit(u64::MAX, 19), Some(5));
+    }
+
+    #[test]
+    fn digit_array_works() {
+        for n in [0, 7, 1234, 10_000, u64::MAX] {
+            let array = DigitArray::new(n);
+            assert_eq!(array.len(), digit_count(n));
+            let expected: Vec<u8> = Digits::from(n).iter().map(|&d| d as u8).collect();
+            assert_eq!(array.as_slice(), expected);
+        }
+    }
+
+    #[test]
+    fn digit_array_works_in_const_context() {
+        const SQUARES: [DigitArray; 4] = {
+            let mut squares = [DigitArray::new(0); 4];
+            let mut i = 0;
+            while i < 4 {
+                squares[i] = DigitArray::new(((i + 10) * (i + 10)) as u64);
+                i += 1;
+            }
+            squares
+        };
+        assert_eq!(SQUARES[0].as_slice(), [1, 0, 0]);
+        assert_eq!(SQUARES[3].as_slice(), [1, 6, 9]);
+        const FIRST: Option<u8> = nth_digit(987, 0);
+        assert_eq!(FIRST, Some(9));
+    }
+}
@@ -0,0 +1,237 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use num_traits::{Num, NumCast};
+
+use crate::{ConversionError, DigitVec, Digits};
+
+/// A single decimal digit, guaranteed to be in `0..=9`.
+///
+/// Arithmetic on digits never silently leaves that range: the carrying and
+/// borrowing methods hand back the carry, and the wrapping ones drop it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digit(u8);
+
+impl Digit {
+    pub const ZERO: Digit = Digit(0);
+    pub const NINE: Digit = Digit(9);
+
+    pub const fn new(value: u8) -> Option<Digit> {
+        if value <= 9 {
+            Some(Digit(value))
+        } else {
+            None
+        }
+    }
+
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+
+    /// The sum with an incoming carry, and whether it carries out.
+    pub fn carrying_add(self, other: Digit, carry: bool) -> (Digit, bool) {
+        let sum = self.0 + other.0 + carry as u8;
+        (Digit(sum % 10), sum >= 10)
+    }
+
+    /// The difference with an incoming borrow, and whether it borrows out.
+    pub fn borrowing_sub(self, other: Digit, borrow: bool) -> (Digit, bool) {
+        let subtrahend = other.0 + borrow as u8;
+        if self.0 >= subtrahend {
+            (Digit(self.0 - subtrahend), false)
+        } else {
+            (Digit(self.0 + 10 - subtrahend), true)
+        }
+    }
+
+    /// The product plus an incoming carry, as the low digit and the carry.
+    pub fn carrying_mul(self, other: Digit, carry: Digit) -> (Digit, Digit) {
+        let product = self.0 * other.0 + carry.0;
+        (Digit(product % 10), Digit(product / 10))
+    }
+
+    pub fn wrapping_add(self, other: Digit) -> Digit {
+        self.carrying_add(other, false).0
+    }
+
+    pub fn wrapping_sub(self, other: Digit) -> Digit {
+        self.borrowing_sub(other, false).0
+    }
+
+    pub fn wrapping_mul(self, other: Digit) -> Digit {
+        self.carrying_mul(other, Digit::ZERO).0
+    }
+}
+
+impl TryFrom<u8> for Digit {
+    type Error = ConversionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Digit::new(value).ok_or(ConversionError::InvalidDigit {
+            position: 0,
+            value: value as i128,
+        })
+    }
+}
+
+impl TryFrom<char> for Digit {
+    type Error = ConversionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_digit(10) {
+            Some(d) => Ok(Digit(d as u8)),
+            None => Err(ConversionError::InvalidChar {
+                position: 0,
+                value: c,
+            }),
+        }
+    }
+}
+
+impl From<Digit> for u8 {
+    fn from(digit: Digit) -> Self {
+        digit.0
+    }
+}
+
+impl From<Digit> for char {
+    fn from(digit: Digit) -> Self {
+        char::from(b'0' + digit.0)
+    }
+}
+
+impl fmt::Display for Digit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    pub fn from_decimal_digits(digits: &[Digit]) -> Result<Self, ConversionError> {
+        if digits.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        let values: DigitVec<T> = digits.iter().map(|d| T::from(d.0).unwrap()).collect();
+        Ok(Self::new(values, 10))
+    }
+
+    pub fn to_decimal_digits(&self) -> Result<Vec<Digit>, ConversionError> {
+        if self.radix != 10 {
+            return Err(ConversionError::NotDecimal);
+        }
+        Ok(self
+            .values
+            .iter()
+            .map(|d| Digit(d.to_u8().unwrap()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn try_from_works() {
+        assert_eq!(Digit::try_from(7u8).map(Digit::value), Ok(7));
+        assert_eq!(Digit::try_from('7'), Ok(Digit::new(7).unwrap()));
+        assert_eq!(char::from(Digit::NINE), '9');
+        assert_eq!(Digit::NINE.to_string(), "9");
+    }
+
+    #[test]
+    fn try_from_throws_error_with_invalid_digit() {
+        assert_eq!(
+            Digit::try_from(10u8),
+            Err(ConversionError::InvalidDigit {
+                position: 0,
+                value: 10
+            })
+        );
+        assert_eq!(
+            Digit::try_from('a'),
+            Err(ConversionError::InvalidChar {
+                position: 0,
+                value: 'a'
+            })
+        );
+        assert_eq!(Digit::new(255), None);
+    }
+
+    #[test]
+    fn carrying_add_works() {
+        let (seven, eight) = (Digit::new(7).unwrap(), Digit::new(8).unwrap());
+        assert_eq!(
+            seven.carrying_add(eight, false),
+            (Digit::new(5).unwrap(), true)
+        );
+        assert_eq!(
+            Digit::NINE.carrying_add(Digit::ZERO, true),
+            (Digit::ZERO, true)
+        );
+        assert_eq!(
+            Digit::NINE.carrying_add(Digit::NINE, true),
+            (Digit::NINE, true)
+        );
+        assert_eq!(seven.wrapping_add(eight), Digit::new(5).unwrap());
+    }
+
+    #[test]
+    fn borrowing_sub_works() {
+        let (three, eight) = (Digit::new(3).unwrap(), Digit::new(8).unwrap());
+        assert_eq!(
+            three.borrowing_sub(eight, false),
+            (Digit::new(5).unwrap(), true)
+        );
+        assert_eq!(
+            eight.borrowing_sub(three, true),
+            (Digit::new(4).unwrap(), false)
+        );
+        assert_eq!(
+            Digit::ZERO.borrowing_sub(Digit::NINE, true),
+            (Digit::ZERO, true)
+        );
+        assert_eq!(three.wrapping_sub(eight), Digit::new(5).unwrap());
+    }
+
+    #[test]
+    fn carrying_mul_works() {
+        let nine = Digit::NINE;
+        assert_eq!(
+            nine.carrying_mul(nine, nine),
+            (Digit::ZERO, Digit::new(9).unwrap())
+        );
+        let (six, seven) = (Digit::new(6).unwrap(), Digit::new(7).unwrap());
+        assert_eq!(
+            six.carrying_mul(seven, Digit::ZERO),
+            (Digit::new(2).unwrap(), Digit::new(4).unwrap())
+        );
+        assert_eq!(six.wrapping_mul(seven), Digit::new(2).unwrap());
+    }
+
+    #[test]
+    fn decimal_digits_round_trip() {
+        let digits = Digits::from(1_234u32);
+        let decimal = digits.to_decimal_digits().unwrap();
+        assert_eq!(
+            decimal.iter().map(|d| d.value()).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        let digits = Digits::<u32>::from_decimal_digits(&decimal).unwrap();
+        assert_eq!(digits.to_int(), Ok(1_234));
+        assert!(Digits::<u32>::from_decimal_digits(&[]).is_err());
+    }
+
+    #[test]
+    fn to_decimal_digits_throws_error_with_non_decimal_digits() {
+        assert_eq!(
+            Digits::with_radix(5u32, 2).unwrap().to_decimal_digits(),
+            Err(ConversionError::NotDecimal)
+        );
+    }
+}
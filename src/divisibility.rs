@@ -0,0 +1,233 @@
+//! Divisibility tests read off the digits, so they work for numbers of any
+//! length.
+//!
+//! In radix r a divisor d that divides r - 1 can be tested on the digit sum,
+//! one that divides r + 1 on the alternating digit sum, and one that divides
+//! a power r^k on the last k digits. Any other divisor is tested by folding
+//! the digits modulo d.
+
+use num_traits::{Num, NumCast};
+
+use crate::{Digits, Sign};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// The digit sum has the same remainder, like 3 and 9 in decimal.
+    DigitSum,
+    /// The alternating digit sum from the least significant digit has the
+    /// same remainder, like 11 in decimal.
+    AlternatingSum,
+    /// The number formed by the last digits has the same remainder, like 4
+    /// with the last two digits in decimal.
+    LastDigits(usize),
+    /// No shortcut, the digits are folded modulo the divisor.
+    Folding,
+}
+
+/// The rule `check` uses for `divisor` in `radix`.
+pub fn rule(radix: u32, divisor: u64) -> Rule {
+    let (radix, divisor) = (radix as u128, divisor as u128);
+    if divisor > 1 && radix % divisor == 1 {
+        return Rule::DigitSum;
+    }
+    if divisor > 1 && (radix + 1) % divisor == 0 {
+        return Rule::AlternatingSum;
+    }
+    // Only divisors made of the prime factors of the radix divide a power of
+    // it, and then within as many digits as the divisor has bits.
+    let mut power = radix % divisor.max(1);
+    for k in 1..=u64::BITS as usize {
+        if power == 0 {
+            return Rule::LastDigits(k);
+        }
+        power = power * radix % divisor;
+    }
+    Rule::Folding
+}
+
+// The remainder of the digits in `radix` modulo `m`. Moduli so large that
+// the remainder times the radix overflows take the slow path of adding it up
+// modulo `m` one radix at a time.
+fn fold_mod(values: impl Iterator<Item = u128>, radix: u128, m: u128) -> u128 {
+    let add_mod = |a: u128, b: u128| if a >= m - b { a - (m - b) } else { a + b };
+    values.fold(0, |rem, d| match rem.checked_mul(radix) {
+        Some(shifted) => add_mod(shifted % m, d % m),
+        None => add_mod((0..radix).fold(0, |acc, _| add_mod(acc, rem)), d % m),
+    })
+}
+
+/// Whether `divisor` divides the number. Zero only divides zero.
+pub fn check<T>(digits: &Digits<T>, divisor: u64) -> bool
+where
+    T: NumCast + Copy,
+{
+    let values = || digits.values.iter().map(|d| d.to_u128().unwrap());
+    let (radix, m) = (digits.radix as u128, divisor as u128);
+    let remainder = match divisor {
+        0 => return values().all(|d| d == 0),
+        1 => return true,
+        _ => match rule(digits.radix, divisor) {
+            Rule::DigitSum => values().fold(0, |sum, d| (sum + d) % m),
+            // Adding m - d subtracts d without leaving the unsigned range.
+            Rule::AlternatingSum => values()
+                .rev()
+                .enumerate()
+                .fold(0, |sum, (i, d)| match i % 2 {
+                    0 => (sum + d) % m,
+                    _ => (sum + m - d % m) % m,
+                }),
+            Rule::LastDigits(k) => {
+                let skip = digits.values.len().saturating_sub(k);
+                fold_mod(values().skip(skip), radix, m)
+            }
+            Rule::Folding => fold_mod(values(), radix, m),
+        },
+    };
+    remainder == 0
+}
+
+impl<T> Digits<T>
+where
+    T: NumCast + Copy,
+{
+    pub fn divisible_by_3(&self) -> bool {
+        check(self, 3)
+    }
+
+    pub fn divisible_by_9(&self) -> bool {
+        check(self, 9)
+    }
+
+    pub fn divisible_by_11(&self) -> bool {
+        check(self, 11)
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    /// The remainder modulo `m`, without converting the digits to an integer.
+    /// Like `%` on integers, it takes the sign of the number.
+    ///
+    /// # Panics
+    ///
+    /// If `m` is zero.
+    pub fn mod_by(&self, m: T) -> T {
+        let modulus = m
+            .to_u128()
+            .or_else(|| m.to_i128().map(i128::unsigned_abs))
+            .unwrap();
+        if modulus == 0 {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+        let values = self.values.iter().map(|d| d.to_u128().unwrap());
+        let rem = T::from(fold_mod(values, self.radix as u128, modulus)).unwrap();
+        match self.sign {
+            Sign::Negative => T::zero() - rem,
+            _ => rem,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_works() {
+        assert_eq!(rule(10, 3), Rule::DigitSum);
+        assert_eq!(rule(10, 9), Rule::DigitSum);
+        assert_eq!(rule(10, 11), Rule::AlternatingSum);
+        assert_eq!(rule(10, 2), Rule::LastDigits(1));
+        assert_eq!(rule(10, 4), Rule::LastDigits(2));
+        assert_eq!(rule(10, 1_000), Rule::LastDigits(3));
+        assert_eq!(rule(10, 7), Rule::Folding);
+        assert_eq!(rule(16, 5), Rule::DigitSum);
+        assert_eq!(rule(16, 17), Rule::AlternatingSum);
+        assert_eq!(rule(2, 3), Rule::AlternatingSum);
+        assert_eq!(rule(2, 1 << 40), Rule::LastDigits(40));
+    }
+
+    #[test]
+    fn check_works() {
+        for radix in [2, 3, 10, 16, 36] {
+            for n in 0u64..300 {
+                let digits = Digits::with_radix(n, radix).unwrap();
+                for divisor in 1..40 {
+                    assert_eq!(check(&digits, divisor), n % divisor == 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn check_works_with_zero_divisor() {
+        assert!(check(&Digits::from(0u32), 0));
+        assert!(!check(&Digits::from(5u32), 0));
+    }
+
+    #[test]
+    fn check_works_with_negative_numbers() {
+        let digits = Digits::try_from(-1_001).unwrap();
+        assert!(check(&digits, 7));
+        assert!(check(&digits, 11));
+        assert!(!check(&digits, 3));
+    }
+
+    #[test]
+    fn check_works_beyond_integer_range() {
+        // 50! has every prime below 50 as a factor, but not 53.
+        let factorial = crate::factorial_digits(50);
+        for divisor in [2, 3, 7, 11, 47, 1 << 40, 1_000_000_000] {
+            assert!(check(&factorial, divisor));
+        }
+        assert!(!check(&factorial, 53));
+        assert!(!check(&factorial, 1 << 50));
+    }
+
+    #[test]
+    fn mod_by_works() {
+        for n in 0u64..200 {
+            for m in 1..30 {
+                assert_eq!(Digits::with_radix(n, 7).unwrap().mod_by(m), n % m);
+            }
+        }
+        assert_eq!(Digits::from(u128::MAX).mod_by(u128::MAX - 1), 1);
+        assert_eq!(Digits::from(u128::MAX - 1).mod_by(u128::MAX), u128::MAX - 1);
+    }
+
+    #[test]
+    fn mod_by_works_with_signs() {
+        for n in -50i32..50 {
+            for m in [-7, -1, 3, 10] {
+                assert_eq!(Digits::try_from(n).unwrap().mod_by(m), n % m);
+            }
+        }
+        assert_eq!(Digits::try_from(i128::MIN).unwrap().mod_by(i128::MIN), 0);
+    }
+
+    #[test]
+    fn mod_by_works_beyond_integer_range() {
+        // 10^30 = (10^15)^2 and 10^15 = 1 mod 999999999999999.
+        let digits: Digits<u64> = alloc::format!("1{}", "0".repeat(30)).parse().unwrap();
+        assert_eq!(digits.mod_by(999_999_999_999_999), 1);
+        assert_eq!(crate::factorial_digits(100).mod_by(101), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor of zero")]
+    fn mod_by_panics_with_zero_modulus() {
+        Digits::from(12u32).mod_by(0);
+    }
+
+    #[test]
+    fn divisible_by_works() {
+        let digits = Digits::from(123_456_789u64);
+        assert!(digits.divisible_by_3());
+        assert!(digits.divisible_by_9());
+        assert!(!digits.divisible_by_11());
+        assert!(Digits::from(918_082u32).divisible_by_11());
+        assert!(!Digits::from(100u32).divisible_by_3());
+    }
+}
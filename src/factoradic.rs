@@ -0,0 +1,181 @@
+use alloc::vec::Vec;
+
+use num_traits::{Num, NumCast};
+
+use crate::{from_mixed_radix, to_mixed_radix, ConversionError, DigitVec, Digits, Sign};
+
+// In the factorial number system the digit at position i from the right has
+// base i + 1, so the last digit is always zero.
+fn factorial_base(i: usize) -> u64 {
+    i as u64 + 1
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// The factoradic digits of the number, most significant first, e.g.
+    /// 463 is `[3, 4, 1, 0, 1, 0]` since 463 = 3·5! + 4·4! + 1·3! + 0·2! + 1·1!.
+    pub fn to_factoradic(&self) -> Result<Vec<T>, ConversionError> {
+        if self.sign == Sign::Negative {
+            return Err(ConversionError::NegativeInput);
+        }
+        let values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        to_mixed_radix(&values, self.radix as u64, factorial_base)
+            .into_iter()
+            .map(|d| {
+                T::from(d).ok_or_else(|| ConversionError::overflow(u64::BITS - d.leading_zeros()))
+            })
+            .collect()
+    }
+
+    /// Builds the decimal digits of a number given in factoradic, most
+    /// significant first.
+    pub fn from_factoradic(values: &[T]) -> Result<Self, ConversionError> {
+        if values.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        let mut factoradic = Vec::with_capacity(values.len());
+        for (i, d) in values.iter().rev().enumerate() {
+            match d.to_u64() {
+                Some(d) if d < factorial_base(i) => factoradic.push(d),
+                _ => {
+                    let position = values.len() - 1 - i;
+                    return Err(ConversionError::invalid_digit(position, *d));
+                }
+            }
+        }
+        factoradic.reverse();
+
+        let values: DigitVec<T> = from_mixed_radix(&factoradic, 10, factorial_base)
+            .into_iter()
+            .map(|d| T::from(d).unwrap())
+            .collect();
+        Ok(Self::new(values, 10))
+    }
+
+    /// The permutation of `items` at this zero-based index in lexicographic
+    /// order, read off the factoradic digits.
+    pub fn kth_permutation<U: Clone>(&self, items: &[U]) -> Result<Vec<U>, ConversionError> {
+        let code = self.to_factoradic()?;
+        if code.len() > items.len().max(1) {
+            return Err(ConversionError::OutOfRange);
+        }
+
+        let mut pool = items.to_vec();
+        let padding = items.len().saturating_sub(code.len());
+        let picks =
+            core::iter::repeat_n(0, padding).chain(code.iter().map(|d| d.to_usize().unwrap()));
+        Ok(picks.take(items.len()).map(|i| pool.remove(i)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn to_factoradic_works() {
+        assert_eq!(
+            Digits::from(463u32).to_factoradic(),
+            Ok(vec![3, 4, 1, 0, 1, 0])
+        );
+        assert_eq!(Digits::from(0u32).to_factoradic(), Ok(vec![0]));
+        assert_eq!(Digits::from(1u32).to_factoradic(), Ok(vec![1, 0]));
+        assert_eq!(Digits::from(5u32).to_factoradic(), Ok(vec![2, 1, 0]));
+        assert_eq!(Digits::from(6u32).to_factoradic(), Ok(vec![1, 0, 0, 0]));
+    }
+
+    #[test]
+    fn to_factoradic_works_with_radix() {
+        let digits = Digits::with_radix(463u32, 16).unwrap();
+        assert_eq!(digits.to_factoradic(), Ok(vec![3, 4, 1, 0, 1, 0]));
+    }
+
+    #[test]
+    fn to_factoradic_works_beyond_integer_range() {
+        // 30! - 1 has every factoradic digit at its maximum.
+        let factorial = crate::factorial_digits(30);
+        let values = crate::long_sub(factorial.as_slice(), &[1], 10);
+        let digits = Digits::from_radix(values, 10).unwrap();
+        let expected: Vec<u64> = (0..30).rev().collect();
+        assert_eq!(digits.to_factoradic(), Ok(expected));
+    }
+
+    #[test]
+    fn to_factoradic_throws_error_with_negative_number() {
+        assert_eq!(
+            Digits::try_from(-1).unwrap().to_factoradic(),
+            Err(ConversionError::NegativeInput)
+        );
+    }
+
+    #[test]
+    fn from_factoradic_works() {
+        let digits = Digits::from_factoradic(&[3u32, 4, 1, 0, 1, 0]).unwrap();
+        assert_eq!(digits.to_int(), Ok(463));
+        assert_eq!(Digits::from_factoradic(&[0u32]).unwrap().to_int(), Ok(0));
+        assert_eq!(
+            Digits::from_factoradic(&[0u32, 1, 0, 0]).unwrap().to_int(),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn from_factoradic_round_trips() {
+        for n in 0u32..2_000 {
+            let factoradic = Digits::from(n).to_factoradic().unwrap();
+            assert_eq!(
+                Digits::from_factoradic(&factoradic).unwrap().to_int(),
+                Ok(n)
+            );
+        }
+    }
+
+    #[test]
+    fn from_factoradic_throws_error_with_digit_out_of_range() {
+        for (values, position, value) in
+            [(&[1u32][..], 0, 1), (&[3, 0, 0], 0, 3), (&[2, 2, 0], 1, 2)]
+        {
+            assert_eq!(
+                Digits::from_factoradic(values).map(|d| d.to_int()),
+                Err(ConversionError::InvalidDigit { position, value })
+            );
+        }
+    }
+
+    #[test]
+    fn kth_permutation_works() {
+        let items = ['a', 'b', 'c'];
+        let permutations: Vec<Vec<char>> = (0u32..6)
+            .map(|k| Digits::from(k).kth_permutation(&items).unwrap())
+            .collect();
+        assert_eq!(
+            permutations,
+            vec![
+                vec!['a', 'b', 'c'],
+                vec!['a', 'c', 'b'],
+                vec!['b', 'a', 'c'],
+                vec!['b', 'c', 'a'],
+                vec!['c', 'a', 'b'],
+                vec!['c', 'b', 'a'],
+            ]
+        );
+    }
+
+    #[test]
+    fn kth_permutation_finds_millionth_permutation() {
+        let items: Vec<u8> = (0..10).collect();
+        let permutation = Digits::from(999_999u32).kth_permutation(&items).unwrap();
+        assert_eq!(permutation, vec![2, 7, 8, 3, 9, 1, 5, 4, 6, 0]);
+    }
+
+    #[test]
+    fn kth_permutation_throws_error_with_index_out_of_range() {
+        assert_eq!(
+            Digits::from(6u32).kth_permutation(&[1, 2, 3]),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+}
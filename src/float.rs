@@ -0,0 +1,240 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{long_add, long_mul, strip_leading_zeros, ConversionError, Digits, LazyDigits};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Drop the digits past the precision.
+    Truncate,
+    /// Round ties away from zero.
+    HalfUp,
+    /// Round ties to the nearest even digit.
+    HalfEven,
+}
+
+/// The decimal digits of a float, split into an integer part and at most
+/// `precision` fractional digits, without trailing zeros.
+#[derive(Clone)]
+pub struct FloatDigits {
+    integer: Digits<u8>,
+    fraction: Vec<u8>,
+    negative: bool,
+}
+
+impl Digits<u8> {
+    /// Expands `x` exactly, then rounds it to `precision` fractional digits.
+    pub fn from_float(
+        x: f64,
+        precision: usize,
+        rounding: Rounding,
+    ) -> Result<FloatDigits, ConversionError> {
+        if !x.is_finite() {
+            return Err(ConversionError::NonFinite);
+        }
+
+        let (mut integer, exact_fraction) = exact_decimal(x);
+        let (kept, rest) = exact_fraction.split_at(precision.min(exact_fraction.len()));
+        let mut fraction = kept.to_vec();
+
+        let last_is_odd = match fraction.last().or(integer.last()) {
+            Some(d) => d % 2 == 1,
+            None => false,
+        };
+        let round_up = match (rounding, rest.split_first()) {
+            (Rounding::Truncate, _) | (_, None) => false,
+            (Rounding::HalfUp, Some((&first, _))) => first >= 5,
+            (Rounding::HalfEven, Some((&first, tail))) => {
+                first > 5 || (first == 5 && (tail.iter().any(|&d| d != 0) || last_is_odd))
+            }
+        };
+        if round_up {
+            let carry = increment(&mut fraction);
+            if carry {
+                integer = long_add(&integer, &[1], 10);
+            }
+        }
+
+        while fraction.last() == Some(&0) {
+            fraction.pop();
+        }
+        let negative = x.is_sign_negative() && (integer != [0] || !fraction.is_empty());
+        Ok(FloatDigits {
+            integer: Digits::new_signed(integer, 10, negative),
+            fraction,
+            negative,
+        })
+    }
+}
+
+impl FloatDigits {
+    pub fn integer(&self) -> &Digits<u8> {
+        &self.integer
+    }
+
+    pub fn fraction(&self) -> &[u8] {
+        &self.fraction
+    }
+
+    /// The sign of the whole value, which the integer part alone loses for
+    /// numbers like -0.5.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The nearest `f64` to the digits.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap()
+    }
+}
+
+impl fmt::Display for FloatDigits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = String::with_capacity(self.integer.values.len() + self.fraction.len() + 1);
+        s.extend(self.integer.values.iter().map(|&d| char::from(b'0' + d)));
+        if !self.fraction.is_empty() {
+            s.push('.');
+            s.extend(self.fraction.iter().map(|&d| char::from(b'0' + d)));
+        }
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+// Every finite f64 is m * 2^e. For negative e that equals m * 5^-e / 10^-e,
+// so the decimal expansion is exact and has at most -e fractional digits.
+fn exact_decimal(x: f64) -> (Vec<u8>, Vec<u8>) {
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let mantissa = bits & ((1 << 52) - 1);
+    let (m, e) = if biased == 0 {
+        (mantissa, -1074)
+    } else {
+        (mantissa | (1 << 52), biased - 1075)
+    };
+
+    let mut n: Vec<u8> = LazyDigits::new(m, 10).map(|d| d as u8).collect();
+    let factor = if e >= 0 { 2 } else { 5 };
+    for _ in 0..e.unsigned_abs() {
+        n = long_mul(&n, &[factor], 10);
+    }
+    if e >= 0 {
+        return (n, Vec::new());
+    }
+
+    let scale = e.unsigned_abs() as usize;
+    if n.len() <= scale {
+        let mut fraction = vec![0; scale - n.len()];
+        fraction.extend(n);
+        return (vec![0], fraction);
+    }
+    let fraction = n.split_off(n.len() - scale);
+    strip_leading_zeros(&mut n);
+    (n, fraction)
+}
+
+// Adds one in the last place and reports whether it carried out of the front.
+fn increment(values: &mut [u8]) -> bool {
+    for d in values.iter_mut().rev() {
+        if *d == 9 {
+            *d = 0;
+        } else {
+            *d += 1;
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_float_works() {
+        let digits = Digits::from_float(3.25, 10, Rounding::Truncate).unwrap();
+        assert_eq!(digits.integer().as_slice(), [3]);
+        assert_eq!(digits.fraction(), [2, 5]);
+        assert!(!digits.is_negative());
+        assert_eq!(digits.to_string(), "3.25");
+    }
+
+    #[test]
+    fn from_float_works_with_negative_numbers() {
+        let digits = Digits::from_float(-12.5, 3, Rounding::Truncate).unwrap();
+        assert_eq!(digits.integer().to_string(), "-12");
+        assert_eq!(digits.to_string(), "-12.5");
+
+        let digits = Digits::from_float(-0.5, 3, Rounding::Truncate).unwrap();
+        assert_eq!(digits.integer().to_string(), "0");
+        assert!(digits.is_negative());
+        assert_eq!(digits.to_string(), "-0.5");
+
+        let digits = Digits::from_float(-0.0001, 2, Rounding::HalfUp).unwrap();
+        assert!(!digits.is_negative());
+        assert_eq!(digits.to_string(), "0");
+    }
+
+    #[test]
+    fn from_float_expands_exactly() {
+        // 0.1 is not representable, and its exact expansion shows it.
+        let digits = Digits::from_float(0.1, 30, Rounding::Truncate).unwrap();
+        assert_eq!(digits.to_string(), "0.100000000000000005551115123125");
+
+        let digits = Digits::from_float(1e20, 0, Rounding::Truncate).unwrap();
+        assert_eq!(digits.to_string(), "100000000000000000000");
+
+        let digits = Digits::from_float(f64::MIN_POSITIVE, 2_000, Rounding::Truncate).unwrap();
+        assert_eq!(
+            digits.fraction().iter().take_while(|&&d| d == 0).count(),
+            307
+        );
+    }
+
+    #[test]
+    fn from_float_rounds() {
+        let round = |x, precision, rounding| {
+            Digits::from_float(x, precision, rounding)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(round(2.675, 2, Rounding::Truncate), "2.67");
+        // 2.675 is stored as 2.67499999..., so it rounds down either way.
+        assert_eq!(round(2.675, 2, Rounding::HalfUp), "2.67");
+        assert_eq!(round(0.125, 2, Rounding::HalfUp), "0.13");
+        assert_eq!(round(0.125, 2, Rounding::HalfEven), "0.12");
+        assert_eq!(round(0.375, 2, Rounding::HalfEven), "0.38");
+        assert_eq!(round(2.5, 0, Rounding::HalfEven), "2");
+        assert_eq!(round(3.5, 0, Rounding::HalfEven), "4");
+        assert_eq!(round(-2.5, 0, Rounding::HalfUp), "-3");
+    }
+
+    #[test]
+    fn from_float_carries_into_integer_part() {
+        let digits = Digits::from_float(9.996, 2, Rounding::HalfUp).unwrap();
+        assert_eq!(digits.to_string(), "10");
+        assert_eq!(digits.integer().to_string(), "10");
+        assert!(digits.fraction().is_empty());
+    }
+
+    #[test]
+    fn from_float_throws_error_with_non_finite_input() {
+        for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Digits::from_float(x, 2, Rounding::Truncate).map(|d| d.to_string()),
+                Err(ConversionError::NonFinite)
+            );
+        }
+    }
+
+    #[test]
+    fn to_f64_works() {
+        for x in [0.0, 1.5, -3.125, 0.1, 1e-300, 123_456.789, f64::MAX] {
+            let digits = Digits::from_float(x, 2_000, Rounding::Truncate).unwrap();
+            assert_eq!(digits.to_f64(), x);
+        }
+        let digits = Digits::from_float(1.0 / 3.0, 4, Rounding::HalfUp).unwrap();
+        assert_eq!(digits.to_f64(), 0.3333);
+    }
+}
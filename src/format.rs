@@ -0,0 +1,227 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use num_traits::NumCast;
+
+use crate::{Digits, Sign};
+
+#[derive(Clone, Copy)]
+pub struct DigitsFormat<'a, T> {
+    digits: &'a Digits<T>,
+    width: usize,
+    pad_zero: bool,
+    separator: Option<char>,
+    group: Option<usize>,
+}
+
+impl<'a, T> DigitsFormat<'a, T>
+where
+    T: NumCast + Copy,
+{
+    pub(crate) fn new(digits: &'a Digits<T>) -> Self {
+        Self {
+            digits,
+            width: 0,
+            pad_zero: false,
+            separator: None,
+            group: None,
+        }
+    }
+
+    /// Minimum width. With `pad_zero` this counts the sign and the digits,
+    /// like `{:05}` does, and the digits are padded with leading zeros before
+    /// grouping; otherwise the rendered output is padded with spaces on the
+    /// left.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn pad_zero(mut self) -> Self {
+        self.pad_zero = true;
+        self
+    }
+
+    /// Separates groups of 3 digits, or of 4 with `pad_zero`, as padded
+    /// numbers are usually codes rather than amounts. `group` sets another
+    /// size.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// Number of digits between separators, counted from the right.
+    pub fn group(mut self, group: usize) -> Self {
+        self.group = Some(group.max(1));
+        self
+    }
+}
+
+impl<T> fmt::Display for DigitsFormat<'_, T>
+where
+    T: NumCast + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let negative = self.digits.sign == Sign::Negative;
+        let mut digits: Vec<char> = self.digits.chars().collect();
+        let width = self.width.saturating_sub(negative as usize);
+        if self.pad_zero && digits.len() < width {
+            let padding = width - digits.len();
+            digits.splice(0..0, core::iter::repeat_n('0', padding));
+        }
+
+        let group = self.group.unwrap_or(if self.pad_zero { 4 } else { 3 });
+        let mut out = String::new();
+        if negative {
+            out.push('-');
+        }
+        for (i, &c) in digits.iter().enumerate() {
+            let remaining = digits.len() - i;
+            if let Some(separator) = self.separator {
+                if i > 0 && remaining.is_multiple_of(group) {
+                    out.push(separator);
+                }
+            }
+            out.push(c);
+        }
+
+        let len = out.chars().count();
+        if !self.pad_zero && len < self.width {
+            out.insert_str(0, &" ".repeat(self.width - len));
+        }
+        f.pad(&out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn format_works() {
+        assert_eq!(Digits::from(42u32).format().to_string(), "42");
+        assert_eq!(Digits::try_from(-42).unwrap().format().to_string(), "-42");
+    }
+
+    #[test]
+    fn format_works_with_width() {
+        let digits = Digits::from(42u32);
+        assert_eq!(digits.format().width(5).to_string(), "   42");
+        assert_eq!(digits.format().width(1).to_string(), "42");
+        assert_eq!(
+            Digits::try_from(-42).unwrap().format().width(5).to_string(),
+            "  -42"
+        );
+    }
+
+    #[test]
+    fn format_works_with_pad_zero() {
+        let digits = Digits::from(42u32);
+        assert_eq!(digits.format().width(5).pad_zero().to_string(), "00042");
+        assert_eq!(
+            Digits::try_from(-42)
+                .unwrap()
+                .format()
+                .width(5)
+                .pad_zero()
+                .to_string(),
+            "-0042"
+        );
+    }
+
+    #[test]
+    fn format_pad_zero_matches_std() {
+        for n in [-12_345i32, -42, -1, 0, 7, 42, 12_345] {
+            let digits = Digits::try_from(n).unwrap();
+            for width in 0..8 {
+                assert_eq!(
+                    digits.format().width(width).pad_zero().to_string(),
+                    alloc::format!("{:0width$}", n, width = width)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn format_works_with_separator() {
+        let digits = Digits::from(1_234_567u32);
+        assert_eq!(digits.format().separator(',').to_string(), "1,234,567");
+        assert_eq!(
+            Digits::from(123u32).format().separator(',').to_string(),
+            "123"
+        );
+        assert_eq!(
+            Digits::try_from(-1_234)
+                .unwrap()
+                .format()
+                .separator(',')
+                .to_string(),
+            "-1,234"
+        );
+    }
+
+    #[test]
+    fn format_works_with_group() {
+        let digits = Digits::with_radix(0xdead_beef_u32, 16).unwrap();
+        assert_eq!(
+            digits.format().separator('_').group(4).to_string(),
+            "dead_beef"
+        );
+    }
+
+    #[test]
+    fn format_works_with_all_options() {
+        let digits = Digits::from(42u32);
+        assert_eq!(
+            digits
+                .format()
+                .width(8)
+                .pad_zero()
+                .separator('_')
+                .group(4)
+                .to_string(),
+            "0000_0042"
+        );
+        // Zero padded digits are grouped by 4 unless told otherwise.
+        assert_eq!(
+            digits
+                .format()
+                .width(8)
+                .pad_zero()
+                .separator('_')
+                .to_string(),
+            "0000_0042"
+        );
+        assert_eq!(
+            digits
+                .format()
+                .width(8)
+                .pad_zero()
+                .separator('_')
+                .group(3)
+                .to_string(),
+            "00_000_042"
+        );
+        assert_eq!(
+            digits.format().width(8).separator('_').group(2).to_string(),
+            "      42"
+        );
+    }
+
+    #[test]
+    fn format_honors_formatter_flags() {
+        let digits = Digits::from(42u32);
+        assert_eq!(alloc::format!("{:>8}|", digits.format()), "      42|");
+        assert_eq!(alloc::format!("{:<6}|", digits.format()), "42    |");
+        assert_eq!(
+            alloc::format!("{:>8}", digits.format().separator(',')),
+            alloc::format!("{:>8}", digits)
+        );
+        assert_eq!(
+            alloc::format!("{:>7}", Digits::from(1_234u32).format().separator(',')),
+            "  1,234"
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! Numbers of a fixed length whose digits meet a set of constraints, built
+//! digit by digit so that prefixes no completion could satisfy are dropped
+//! early instead of filtering every number of that length.
+//!
+//! ```
+//! use digits::generate::{DigitSum, Generator, Increasing};
+//!
+//! // The three digit numbers with strictly increasing digits summing to 12.
+//! let numbers: Vec<u32> = Generator::new(3)
+//!     .unwrap()
+//!     .with(Increasing)
+//!     .with(DigitSum(12))
+//!     .map(|d| d.to_int().unwrap())
+//!     .collect();
+//! assert_eq!(numbers[..3], [129, 138, 147]);
+//! ```
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use num_traits::{Num, NumCast};
+
+use crate::{radix_constant, ConversionError, DigitVec, Digits};
+
+/// A condition on the digits of a number with `len` digits in `radix`.
+pub trait Constraint {
+    /// Whether `prefix` can still be completed to `len` digits that meet the
+    /// condition. Once the prefix is complete this is the condition itself.
+    fn admits(&self, prefix: &[u8], len: usize, radix: u32) -> bool;
+}
+
+impl<F> Constraint for F
+where
+    F: Fn(&[u8], usize, u32) -> bool,
+{
+    fn admits(&self, prefix: &[u8], len: usize, radix: u32) -> bool {
+        self(prefix, len, radix)
+    }
+}
+
+/// Each digit is greater than the one before it.
+#[derive(Clone, Copy, Debug)]
+pub struct Increasing;
+
+impl Constraint for Increasing {
+    fn admits(&self, prefix: &[u8], len: usize, radix: u32) -> bool {
+        let Some(&last) = prefix.last() else {
+            return true;
+        };
+        // The remaining digits each need a larger value than the last.
+        prefix.windows(2).all(|w| w[0] < w[1])
+            && len - prefix.len() < (radix - last as u32) as usize
+    }
+}
+
+/// Each digit is less than the one before it.
+#[derive(Clone, Copy, Debug)]
+pub struct Decreasing;
+
+impl Constraint for Decreasing {
+    fn admits(&self, prefix: &[u8], len: usize, _: u32) -> bool {
+        let Some(&last) = prefix.last() else {
+            return true;
+        };
+        prefix.windows(2).all(|w| w[0] > w[1]) && len - prefix.len() <= last as usize
+    }
+}
+
+/// The digits add up to exactly this sum.
+#[derive(Clone, Copy, Debug)]
+pub struct DigitSum(pub u32);
+
+impl Constraint for DigitSum {
+    fn admits(&self, prefix: &[u8], len: usize, radix: u32) -> bool {
+        let sum: u32 = prefix.iter().map(|&d| d as u32).sum();
+        let most = (len - prefix.len()) as u32 * (radix - 1);
+        sum <= self.0 && self.0 - sum <= most
+    }
+}
+
+/// The digits contain this run of digits somewhere.
+#[derive(Clone, Debug)]
+pub struct Contains(pub Vec<u8>);
+
+impl Constraint for Contains {
+    fn admits(&self, prefix: &[u8], len: usize, _: u32) -> bool {
+        let pattern = &self.0;
+        if pattern.is_empty() || prefix.windows(pattern.len()).any(|w| w == &pattern[..]) {
+            return true;
+        }
+        // The longest end of the prefix that starts the pattern is how much
+        // of it is already in place.
+        let started = (1..pattern.len().min(prefix.len() + 1))
+            .rev()
+            .find(|&k| prefix.ends_with(&pattern[..k]))
+            .unwrap_or(0);
+        pattern.len() - started <= len - prefix.len()
+    }
+}
+
+/// Only these digits are used.
+#[derive(Clone, Debug)]
+pub struct AllowedDigits(pub Vec<u8>);
+
+impl Constraint for AllowedDigits {
+    fn admits(&self, prefix: &[u8], _: usize, _: u32) -> bool {
+        prefix.last().is_none_or(|d| self.0.contains(d))
+    }
+}
+
+/// Yields the numbers with exactly `len` digits that meet every constraint,
+/// in ascending order. Only a single digit number may start with zero.
+pub struct Generator<T> {
+    len: usize,
+    radix: u32,
+    constraints: Vec<Box<dyn Constraint>>,
+    prefix: Vec<u8>,
+    started: bool,
+    _digit: PhantomData<T>,
+}
+
+impl<T> Generator<T>
+where
+    T: Num + NumCast + Copy,
+{
+    pub fn new(len: usize) -> Result<Self, ConversionError> {
+        if len == 0 {
+            return Err(ConversionError::Empty);
+        }
+        Ok(Generator {
+            len,
+            radix: 10,
+            constraints: Vec::new(),
+            prefix: Vec::with_capacity(len),
+            started: false,
+            _digit: PhantomData,
+        })
+    }
+
+    pub fn radix(mut self, radix: u32) -> Result<Self, ConversionError> {
+        radix_constant::<T>(radix)?;
+        self.radix = radix;
+        Ok(self)
+    }
+
+    pub fn with<C>(mut self, constraint: C) -> Self
+    where
+        C: Constraint + 'static,
+    {
+        self.constraints.push(Box::new(constraint));
+        self
+    }
+
+    fn admits(&self) -> bool {
+        self.constraints
+            .iter()
+            .all(|c| c.admits(&self.prefix, self.len, self.radix))
+    }
+
+    fn first_digit(&self) -> u8 {
+        (self.prefix.is_empty() && self.len > 1) as u8
+    }
+}
+
+impl<T> Iterator for Generator<T>
+where
+    T: Num + NumCast + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Resume after the last number, or start at the smallest first digit.
+        let mut d = if self.started {
+            self.prefix.pop()? + 1
+        } else {
+            self.started = true;
+            self.first_digit()
+        };
+        loop {
+            if d as u32 >= self.radix {
+                d = self.prefix.pop()? + 1;
+                continue;
+            }
+            self.prefix.push(d);
+            if !self.admits() {
+                self.prefix.pop();
+                d += 1;
+            } else if self.prefix.len() == self.len {
+                let values: DigitVec<T> =
+                    self.prefix.iter().map(|&d| T::from(d).unwrap()).collect();
+                return Some(Digits::new(values, self.radix));
+            } else {
+                d = self.first_digit();
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for Generator<T> where T: Num + NumCast + Copy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn numbers(generator: Generator<u32>) -> Vec<u32> {
+        generator.map(|d| d.to_int().unwrap()).collect()
+    }
+
+    fn brute(len: u32, f: impl Fn(&Digits<u32>) -> bool) -> Vec<u32> {
+        let low = if len == 1 { 0 } else { 10u32.pow(len - 1) };
+        (low..10u32.pow(len))
+            .filter(|&n| f(&Digits::from(n)))
+            .collect()
+    }
+
+    #[test]
+    fn generator_works() {
+        assert_eq!(
+            numbers(Generator::new(1).unwrap()),
+            (0..10).collect::<Vec<_>>()
+        );
+        assert_eq!(numbers(Generator::new(3).unwrap()).len(), 900);
+        assert_eq!(
+            numbers(Generator::new(3).unwrap().radix(2).unwrap()),
+            vec![4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn generator_throws_error_with_invalid_arguments() {
+        assert!(Generator::<u32>::new(0).is_err());
+        assert!(Generator::<u32>::new(2).unwrap().radix(37).is_err());
+    }
+
+    #[test]
+    fn increasing_works() {
+        let expected = brute(4, |d| d.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(
+            numbers(Generator::new(4).unwrap().with(Increasing)),
+            expected
+        );
+        // Nine digits can only be 123456789, and ten cannot increase at all.
+        assert_eq!(
+            numbers(Generator::new(9).unwrap().with(Increasing)),
+            vec![123_456_789]
+        );
+        assert_eq!(
+            Generator::<u64>::new(10).unwrap().with(Increasing).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn decreasing_works() {
+        let expected = brute(4, |d| d.windows(2).all(|w| w[0] > w[1]));
+        assert_eq!(
+            numbers(Generator::new(4).unwrap().with(Decreasing)),
+            expected
+        );
+        let count = Generator::<u64>::new(10).unwrap().with(Decreasing).count();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn digit_sum_works() {
+        let expected = brute(5, |d| d.sum() == Ok(20));
+        assert_eq!(
+            numbers(Generator::new(5).unwrap().with(DigitSum(20))),
+            expected
+        );
+        assert_eq!(
+            numbers(Generator::new(3).unwrap().with(DigitSum(1))),
+            vec![100]
+        );
+        assert_eq!(
+            Generator::<u32>::new(3).unwrap().with(DigitSum(28)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn contains_works() {
+        let expected = brute(5, |d| d.windows(2).any(|w| w == [4, 2]));
+        let generator = Generator::new(5).unwrap().with(Contains(vec![4, 2]));
+        assert_eq!(numbers(generator), expected);
+        let generator = Generator::new(3).unwrap().with(Contains(vec![1, 1, 1]));
+        assert_eq!(numbers(generator), vec![111]);
+    }
+
+    #[test]
+    fn allowed_digits_works() {
+        let generator = Generator::new(2)
+            .unwrap()
+            .with(AllowedDigits(vec![0, 3, 7]));
+        assert_eq!(numbers(generator), vec![30, 33, 37, 70, 73, 77]);
+    }
+
+    #[test]
+    fn constraints_compose() {
+        let generator = Generator::new(4)
+            .unwrap()
+            .with(Increasing)
+            .with(DigitSum(20))
+            .with(Contains(vec![5]));
+        let expected = brute(4, |d| {
+            d.windows(2).all(|w| w[0] < w[1]) && d.sum() == Ok(20) && d.contains(&5)
+        });
+        assert_eq!(numbers(generator), expected);
+    }
+
+    #[test]
+    fn closures_are_constraints() {
+        // Palindromes, checked once the number is complete.
+        let palindrome = |prefix: &[u8], len: usize, _: u32| {
+            prefix.len() < len || prefix.iter().eq(prefix.iter().rev())
+        };
+        let generator = Generator::new(3)
+            .unwrap()
+            .with(palindrome)
+            .with(DigitSum(2));
+        assert_eq!(numbers(generator), vec![101]);
+    }
+
+    #[test]
+    fn generator_works_beyond_integer_range() {
+        // The 30 digit numbers of ones and twos with digit sum 31 have a
+        // single two.
+        let generator = Generator::<u8>::new(30)
+            .unwrap()
+            .with(AllowedDigits(vec![1, 2]))
+            .with(DigitSum(31));
+        let found: Vec<Digits<u8>> = generator.collect();
+        assert_eq!(found.len(), 30);
+        assert!(found.windows(2).all(|w| w[0] < w[1]));
+    }
+}
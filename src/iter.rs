@@ -0,0 +1,807 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use num_traits::{CheckedMul, Num, NumCast};
+
+use crate::{overflow_error, radix_constant, ConversionError, DigitVec, Digits, Sign};
+
+#[derive(Clone, Debug)]
+pub struct DigitsIter<T> {
+    inner: <DigitVec<T> as IntoIterator>::IntoIter,
+}
+
+impl<T> Iterator for DigitsIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for DigitsIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for DigitsIter<T> {}
+
+impl<T> FusedIterator for DigitsIter<T> {}
+
+impl<T> IntoIterator for Digits<T> {
+    type Item = T;
+    type IntoIter = DigitsIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DigitsIter {
+            inner: self.values.into_iter(),
+        }
+    }
+}
+
+impl<T> Digits<T> {
+    /// Borrows the digits, most significant first, so they can be walked
+    /// any number of times without cloning.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.values.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Digits<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Collects decimal digits, most significant first.
+///
+/// # Panics
+///
+/// If the iterator is empty or a digit is out of range, the cases in which
+/// `Digits::try_from_iter` returns an error.
+impl<T> FromIterator<T> for Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::try_from_iter(iter, 10).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Appends digits on the least significant end.
+///
+/// # Panics
+///
+/// If a digit is out of range for the radix; use `Digits::try_extend` to get
+/// an error instead.
+impl<T> Extend<T> for Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.try_extend(iter).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn try_from_iter<I>(iter: I, radix: u32) -> Result<Self, ConversionError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_radix(iter.into_iter().collect(), radix)
+    }
+
+    /// Appends digits on the least significant end, leaving the digits
+    /// untouched if any of them is out of range.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), ConversionError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let radix: T = radix_constant(self.radix)?;
+        let values: Vec<T> = iter.into_iter().collect();
+        if let Some(i) = values.iter().position(|&d| d < T::zero() || d >= radix) {
+            let position = self.values.len() + i;
+            return Err(ConversionError::invalid_digit(position, values[i]));
+        }
+        self.values.extend(values);
+        self.update_sign();
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LazyDigits<T> {
+    n: T,
+    radix: T,
+    front: T,
+    back: T,
+    len: usize,
+}
+
+pub fn digits_of<T>(n: T) -> LazyDigits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    LazyDigits::new(n, T::from(10).unwrap())
+}
+
+pub fn digits_of_radix<T>(n: T, radix: u32) -> Result<LazyDigits<T>, ConversionError>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    Ok(LazyDigits::new(n, radix_constant(radix)?))
+}
+
+impl<T> LazyDigits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub(crate) fn new(n: T, radix: T) -> Self {
+        let mut front = T::one();
+        let mut len = 1;
+        let mut rem = n / radix;
+        while !rem.is_zero() {
+            front = front * radix;
+            rem = rem / radix;
+            len += 1;
+        }
+
+        Self {
+            n,
+            radix,
+            front,
+            back: T::one(),
+            len,
+        }
+    }
+
+    fn digit_at(&self, place: T) -> T {
+        let digit = (self.n / place) % self.radix;
+        if digit < T::zero() {
+            T::zero() - digit
+        } else {
+            digit
+        }
+    }
+}
+
+impl<T> Iterator for LazyDigits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let digit = self.digit_at(self.front);
+        self.len -= 1;
+        if self.len > 0 {
+            self.front = self.front / self.radix;
+        }
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for LazyDigits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let digit = self.digit_at(self.back);
+        self.len -= 1;
+        if self.len > 0 {
+            self.back = self.back * self.radix;
+        }
+        Some(digit)
+    }
+}
+
+impl<T> ExactSizeIterator for LazyDigits<T> where T: Num + NumCast + PartialOrd + Copy {}
+
+impl<T> FusedIterator for LazyDigits<T> where T: Num + NumCast + PartialOrd + Copy {}
+
+impl<T> Digits<T> {
+    /// Overlapping runs of `k` adjacent digits, most significant first, like
+    /// `slice::windows`. Use `numbers` to get them as numbers instead.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is zero.
+    pub fn windows(&self, k: usize) -> Windows<'_, T> {
+        Windows {
+            inner: self.values.windows(k),
+            radix: self.radix,
+        }
+    }
+
+    /// Runs of `k` digits from the most significant end, where the last one
+    /// may be shorter, like `slice::chunks`.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is zero.
+    pub fn chunks(&self, k: usize) -> Chunks<'_, T> {
+        Chunks {
+            inner: self.values.chunks(k),
+            radix: self.radix,
+        }
+    }
+
+    /// Each digit with the value of its place, most significant first, e.g.
+    /// (3, 100), (4, 10) and (5, 1) for 345. Use `terms` for 300, 40 and 5.
+    pub fn place_values(&self) -> PlaceValues<'_, T> {
+        PlaceValues {
+            inner: self.values.iter().enumerate(),
+            len: self.values.len(),
+            radix: self.radix,
+            negative: self.sign == Sign::Negative,
+        }
+    }
+}
+
+macro_rules! impl_sub_slices {
+    ($name:ident, $inner:ident) => {
+        #[derive(Clone, Debug)]
+        pub struct $name<'a, T> {
+            inner: core::slice::$inner<'a, T>,
+            radix: u32,
+        }
+
+        impl<'a, T> $name<'a, T> {
+            /// The runs as non-negative numbers in the same radix. Leading
+            /// zeros are kept, as with `Digits::parse_with_leading_zeros`.
+            pub fn numbers(self) -> SubNumbers<Self> {
+                SubNumbers {
+                    radix: self.radix,
+                    inner: self,
+                }
+            }
+        }
+
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = &'a [T];
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $name<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+impl_sub_slices!(Windows, Windows);
+impl_sub_slices!(Chunks, Chunks);
+
+#[derive(Clone, Debug)]
+pub struct SubNumbers<I> {
+    inner: I,
+    radix: u32,
+}
+
+impl<'a, I, T> Iterator for SubNumbers<I>
+where
+    I: Iterator<Item = &'a [T]>,
+    T: Num + Copy + 'a,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.inner.next()?;
+        Some(Digits::new(values.to_vec(), self.radix))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, I, T> DoubleEndedIterator for SubNumbers<I>
+where
+    I: DoubleEndedIterator<Item = &'a [T]>,
+    T: Num + Copy + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let values = self.inner.next_back()?;
+        Some(Digits::new(values.to_vec(), self.radix))
+    }
+}
+
+impl<'a, I, T> ExactSizeIterator for SubNumbers<I>
+where
+    I: ExactSizeIterator<Item = &'a [T]>,
+    T: Num + Copy + 'a,
+{
+}
+
+impl<'a, I, T> FusedIterator for SubNumbers<I>
+where
+    I: FusedIterator<Item = &'a [T]>,
+    T: Num + Copy + 'a,
+{
+}
+
+/// Place values that do not fit `T` come out as overflow errors, while the
+/// smaller places after them still work.
+#[derive(Clone, Debug)]
+pub struct PlaceValues<'a, T> {
+    inner: core::iter::Enumerate<core::slice::Iter<'a, T>>,
+    len: usize,
+    radix: u32,
+    negative: bool,
+}
+
+impl<'a, T> PlaceValues<'a, T>
+where
+    T: Num + NumCast + CheckedMul + Copy,
+{
+    /// Each digit times its place value, signed like the number, so the
+    /// terms add up to it: -345 gives -300, -40 and -5.
+    pub fn terms(self) -> Terms<'a, T> {
+        Terms { inner: self }
+    }
+
+    // `unit` times the place value of digit `i`.
+    fn place(&self, i: usize, unit: T) -> Option<T> {
+        let radix = T::from(self.radix).unwrap();
+        (i + 1..self.len).try_fold(unit, |p, _| p.checked_mul(&radix))
+    }
+
+    // The error for the number that is `digit` at the place of digit `i`.
+    fn overflow(&self, i: usize, digit: T) -> ConversionError {
+        let mut values = vec![T::zero(); self.len - i];
+        values[0] = digit;
+        overflow_error(&values, T::from(self.radix).unwrap())
+    }
+
+    fn pair(&self, i: usize, d: T) -> Result<(T, T), ConversionError> {
+        match self.place(i, T::one()) {
+            Some(p) => Ok((d, p)),
+            None => Err(self.overflow(i, T::one())),
+        }
+    }
+}
+
+impl<T> Iterator for PlaceValues<'_, T>
+where
+    T: Num + NumCast + CheckedMul + Copy,
+{
+    type Item = Result<(T, T), ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, &d) = self.inner.next()?;
+        Some(self.pair(i, d))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for PlaceValues<'_, T>
+where
+    T: Num + NumCast + CheckedMul + Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (i, &d) = self.inner.next_back()?;
+        Some(self.pair(i, d))
+    }
+}
+
+impl<T> ExactSizeIterator for PlaceValues<'_, T> where T: Num + NumCast + CheckedMul + Copy {}
+
+impl<T> FusedIterator for PlaceValues<'_, T> where T: Num + NumCast + CheckedMul + Copy {}
+
+#[derive(Clone, Debug)]
+pub struct Terms<'a, T> {
+    inner: PlaceValues<'a, T>,
+}
+
+impl<T> Terms<'_, T>
+where
+    T: Num + NumCast + CheckedMul + Copy,
+{
+    fn term(&self, i: usize, d: T) -> Result<T, ConversionError> {
+        if d.is_zero() {
+            return Ok(d);
+        }
+        // Negative terms are built from -1 up, so that one reaching T::MIN,
+        // whose magnitude does not fit, still works.
+        let unit = match self.inner.negative {
+            true => T::zero() - T::one(),
+            false => T::one(),
+        };
+        let term = self.inner.place(i, unit).and_then(|p| p.checked_mul(&d));
+        term.ok_or_else(|| self.inner.overflow(i, d))
+    }
+}
+
+impl<T> Iterator for Terms<'_, T>
+where
+    T: Num + NumCast + CheckedMul + Copy,
+{
+    type Item = Result<T, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, &d) = self.inner.inner.next()?;
+        Some(self.term(i, d))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Terms<'_, T>
+where
+    T: Num + NumCast + CheckedMul + Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (i, &d) = self.inner.inner.next_back()?;
+        Some(self.term(i, d))
+    }
+}
+
+impl<T> ExactSizeIterator for Terms<'_, T> where T: Num + NumCast + CheckedMul + Copy {}
+
+impl<T> FusedIterator for Terms<'_, T> where T: Num + NumCast + CheckedMul + Copy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_iter_works() {
+        let digits: Vec<u32> = Digits::from(369u32).into_iter().collect();
+        assert_eq!(digits, vec![3, 6, 9]);
+    }
+
+    #[test]
+    fn into_iter_works_in_reverse() {
+        let mut digits = Digits::from(369u32).into_iter().rev();
+        assert_eq!(digits.next(), Some(9));
+        assert_eq!(digits.next(), Some(6));
+        assert_eq!(digits.next(), Some(3));
+        assert_eq!(digits.next(), None);
+    }
+
+    #[test]
+    fn into_iter_works_from_both_ends() {
+        let mut digits = Digits::from(12345u32).into_iter();
+        assert_eq!(digits.next(), Some(1));
+        assert_eq!(digits.next_back(), Some(5));
+        assert_eq!(digits.len(), 3);
+        assert_eq!(digits.next(), Some(2));
+        assert_eq!(digits.next_back(), Some(4));
+        assert_eq!(digits.next(), Some(3));
+        assert_eq!(digits.next_back(), None);
+        assert_eq!(digits.next(), None);
+    }
+
+    #[test]
+    fn into_iter_has_exact_size() {
+        let mut digits = Digits::from(u64::MAX).into_iter();
+        assert_eq!(digits.len(), 20);
+        digits.next();
+        assert_eq!(digits.len(), 19);
+        assert_eq!(digits.size_hint(), (19, Some(19)));
+    }
+
+    #[test]
+    fn into_iter_is_fused() {
+        let mut digits = Digits::from(7u32).into_iter().fuse();
+        assert_eq!(digits.next(), Some(7));
+        assert_eq!(digits.next(), None);
+        assert_eq!(digits.next(), None);
+    }
+
+    #[test]
+    fn len_is_unaffected_by_iteration() {
+        let digits = Digits::from(42u32);
+        let mut iter = (&digits).into_iter();
+        iter.next();
+        assert_eq!(digits.len(), 2);
+    }
+
+    #[test]
+    fn iter_works() {
+        let digits = Digits::from(3_141u32);
+        let iter = digits.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.copied().collect::<Vec<_>>(), vec![3, 1, 4, 1]);
+        // Borrowing leaves the digits in place for another pass.
+        assert_eq!(digits.iter().max(), Some(&4));
+        assert_eq!(digits.iter().next_back(), Some(&1));
+        assert_eq!(digits.to_int(), Ok(3_141));
+    }
+
+    #[test]
+    fn borrowed_into_iter_works() {
+        let digits = Digits::from(369u32);
+        let mut sum = 0;
+        for d in &digits {
+            sum += d;
+        }
+        assert_eq!(sum, 18);
+        assert_eq!(
+            (&digits).into_iter().rev().copied().collect::<Vec<_>>(),
+            vec![9, 6, 3]
+        );
+        assert_eq!(digits.len(), 3);
+    }
+
+    #[test]
+    fn digits_of_works() {
+        assert_eq!(digits_of(12345u32).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(digits_of(0u32).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(digits_of(10u32).collect::<Vec<_>>(), vec![1, 0]);
+    }
+
+    #[test]
+    fn digits_of_works_in_reverse() {
+        assert_eq!(
+            digits_of(12345u32).rev().collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
+        assert_eq!(digits_of(100u32).rev().collect::<Vec<_>>(), vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn digits_of_works_from_both_ends() {
+        let mut digits = digits_of(12345u32);
+        assert_eq!(digits.next(), Some(1));
+        assert_eq!(digits.next_back(), Some(5));
+        assert_eq!(digits.len(), 3);
+        assert_eq!(digits.next_back(), Some(4));
+        assert_eq!(digits.next(), Some(2));
+        assert_eq!(digits.next(), Some(3));
+        assert_eq!(digits.next(), None);
+        assert_eq!(digits.next_back(), None);
+    }
+
+    #[test]
+    fn digits_of_works_at_type_boundary() {
+        assert_eq!(digits_of(u8::MAX).collect::<Vec<_>>(), vec![2, 5, 5]);
+        assert_eq!(digits_of(u8::MAX).rev().collect::<Vec<_>>(), vec![5, 5, 2]);
+        assert_eq!(
+            digits_of(u64::MAX).collect::<Vec<_>>(),
+            Digits::from(u64::MAX).as_slice()
+        );
+        assert_eq!(digits_of(u128::MAX).rev().count(), 39);
+    }
+
+    #[test]
+    fn digits_of_works_with_negative_number() {
+        assert_eq!(digits_of(-123).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(digits_of(i8::MIN).collect::<Vec<_>>(), vec![1, 2, 8]);
+        assert_eq!(digits_of(i8::MIN).rev().collect::<Vec<_>>(), vec![8, 2, 1]);
+    }
+
+    #[test]
+    fn digits_of_radix_works() {
+        let digits = digits_of_radix(0xbeefu32, 16).unwrap();
+        assert_eq!(digits.collect::<Vec<_>>(), vec![11, 14, 14, 15]);
+        let digits = digits_of_radix(5u32, 2).unwrap();
+        assert_eq!(digits.rev().collect::<Vec<_>>(), vec![1, 0, 1]);
+        assert!(digits_of_radix(5u32, 1).is_err());
+    }
+
+    #[test]
+    fn digits_of_matches_digits() {
+        for n in (0..100_000u32).step_by(7) {
+            assert_eq!(digits_of(n).collect::<Vec<_>>(), Digits::from(n).as_slice());
+        }
+    }
+
+    #[test]
+    fn from_iter_works() {
+        let digits: Digits<u32> = (1..5).collect();
+        assert_eq!(digits.to_int(), Ok(1_234));
+        let digits: Digits<u32> = core::iter::once(0).collect();
+        assert_eq!(digits.to_int(), Ok(0));
+        let digits: Digits<u8> = Digits::from(1_234u32)
+            .into_iter()
+            .rev()
+            .map(|d| d as u8)
+            .collect();
+        assert_eq!(digits.as_slice(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "digit 17 at position 1 out of range")]
+    fn from_iter_panics_with_digit_out_of_range() {
+        let _: Digits<u32> = [1, 17].into_iter().collect();
+    }
+
+    #[test]
+    #[should_panic(expected = "unable to convert from empty digit sequence")]
+    fn from_iter_panics_with_empty_iterator() {
+        let _: Digits<u32> = core::iter::empty().collect();
+    }
+
+    #[test]
+    fn try_from_iter_works() {
+        let digits = Digits::try_from_iter([1u32, 0, 1], 2).unwrap();
+        assert_eq!(digits.to_int(), Ok(5));
+        assert_eq!(
+            Digits::try_from_iter([1u32, 2], 2).map(|d| d.to_int()),
+            Err(ConversionError::InvalidDigit {
+                position: 1,
+                value: 2
+            })
+        );
+        assert!(Digits::<u32>::try_from_iter([], 10).is_err());
+    }
+
+    #[test]
+    fn extend_works() {
+        let mut digits = Digits::from(1u32);
+        digits.extend([4, 2]);
+        assert_eq!(digits.to_int(), Ok(142));
+
+        let mut digits = Digits::from(0u32);
+        assert_eq!(digits.sign(), Sign::Zero);
+        digits.extend([7]);
+        assert_eq!(digits.sign(), Sign::Positive);
+        assert_eq!(digits.to_int(), Ok(7));
+
+        let mut digits = Digits::try_from(-3).unwrap();
+        digits.extend([0]);
+        assert_eq!(digits.to_int(), Ok(-30));
+    }
+
+    #[test]
+    #[should_panic(expected = "digit 2 at position 2 out of range")]
+    fn extend_panics_with_digit_out_of_range() {
+        Digits::with_radix(1u32, 2).unwrap().extend([1, 2]);
+    }
+
+    #[test]
+    fn try_extend_throws_error_with_digit_out_of_range() {
+        let mut digits = Digits::with_radix(0xfu32, 16).unwrap();
+        assert_eq!(
+            digits.try_extend([1, 16]),
+            Err(ConversionError::InvalidDigit {
+                position: 2,
+                value: 16
+            })
+        );
+        assert_eq!(digits.as_slice(), vec![0xf]);
+    }
+
+    #[test]
+    fn place_values_works() {
+        let digits = Digits::from(345u32);
+        let places: Result<Vec<_>, _> = digits.place_values().collect();
+        assert_eq!(places, Ok(vec![(3, 100), (4, 10), (5, 1)]));
+        let terms: Result<Vec<_>, _> = digits.place_values().terms().collect();
+        assert_eq!(terms, Ok(vec![300, 40, 5]));
+        assert_eq!(digits.place_values().len(), 3);
+        assert_eq!(digits.place_values().next_back(), Some(Ok((5, 1))));
+    }
+
+    #[test]
+    fn place_values_works_with_radix_and_sign() {
+        let digits = Digits::with_radix(0xabu32, 16).unwrap();
+        let places: Result<Vec<_>, _> = digits.place_values().collect();
+        assert_eq!(places, Ok(vec![(10, 16), (11, 1)]));
+        let digits = Digits::try_from(-345).unwrap();
+        let terms: Result<Vec<i32>, _> = digits.place_values().terms().collect();
+        assert_eq!(terms, Ok(vec![-300, -40, -5]));
+        // The leading term of i8::MIN in binary is i8::MIN itself, though
+        // its place value does not fit.
+        let digits = Digits::with_radix(i8::MIN, 2).unwrap();
+        assert_eq!(digits.place_values().terms().next(), Some(Ok(i8::MIN)));
+        assert!(digits.place_values().next().unwrap().is_err());
+    }
+
+    #[test]
+    fn place_values_throws_error_on_overflow() {
+        let digits = Digits::<u8>::from_radix(vec![3, 0, 0, 0, 5], 10).unwrap();
+        let places: Vec<_> = digits.place_values().collect();
+        assert_eq!(
+            places[..2],
+            [
+                Err(ConversionError::overflow(14)),
+                Err(ConversionError::overflow(10))
+            ]
+        );
+        assert_eq!(places[2..], [Ok((0, 100)), Ok((0, 10)), Ok((5, 1))]);
+        // A zero term fits even where its place does not.
+        let terms: Vec<_> = digits.place_values().terms().collect();
+        assert_eq!(terms[0], Err(ConversionError::overflow(15)));
+        assert_eq!(terms[1..], [Ok(0), Ok(0), Ok(0), Ok(5)]);
+    }
+
+    #[test]
+    fn windows_works() {
+        let digits = Digits::from(12_345u32);
+        let windows: Vec<&[u32]> = digits.windows(3).collect();
+        assert_eq!(windows, vec![&[1, 2, 3][..], &[2, 3, 4], &[3, 4, 5]]);
+        assert_eq!(digits.windows(5).len(), 1);
+        assert_eq!(digits.windows(6).len(), 0);
+    }
+
+    #[test]
+    fn windows_works_with_numbers() {
+        let digits = Digits::with_radix(0xabcu32, 16).unwrap();
+        let numbers: Vec<u32> = digits
+            .windows(2)
+            .numbers()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(numbers, vec![0xab, 0xbc]);
+        let digits = Digits::from(1_012u32);
+        let numbers: Vec<Digits<u32>> = digits.windows(2).numbers().rev().collect();
+        assert_eq!(numbers[0].to_int(), Ok(12));
+        assert_eq!(numbers[1].as_slice(), [0, 1]);
+        assert_eq!(numbers[1].sign(), Sign::Positive);
+    }
+
+    #[test]
+    fn windows_works_for_adjacent_products() {
+        let digits = crate::factorial_digits(30);
+        let best = digits
+            .windows(4)
+            .map(|w| w.iter().product::<u64>())
+            .max()
+            .unwrap();
+        let expected = (0..digits.len() - 3)
+            .map(|i| digits[i..i + 4].iter().product::<u64>())
+            .max()
+            .unwrap();
+        assert_eq!(best, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_panics_with_zero_size() {
+        Digits::from(12u32).windows(0);
+    }
+
+    #[test]
+    fn chunks_works() {
+        let digits = Digits::from(1_234_567u32);
+        let chunks: Vec<&[u32]> = digits.chunks(3).collect();
+        assert_eq!(chunks, vec![&[1, 2, 3][..], &[4, 5, 6], &[7]]);
+        let numbers: Vec<u32> = digits
+            .chunks(3)
+            .numbers()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(numbers, vec![123, 456, 7]);
+        assert_eq!(digits.chunks(2).numbers().len(), 4);
+    }
+}
@@ -1,254 +1,2127 @@
-use std::cmp::PartialOrd;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::PartialOrd;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
 
-use num::traits::Num;
-use num_traits::{NumCast, NumOps};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedSub, Num, NumCast, WrappingAdd, WrappingMul,
+    WrappingSub,
+};
+
+mod arbitrary;
+mod arith;
+mod bcd;
+pub mod benford;
+#[cfg(feature = "bigint")]
+mod bigint;
+mod builder;
+pub mod checksum;
+mod cmp;
+#[cfg(feature = "constants")]
+pub mod constants;
+mod consts;
+mod digit;
+pub mod divisibility;
+mod factoradic;
+mod float;
+mod format;
+pub mod generate;
+mod iter;
+mod mixed;
+mod negabase;
+mod order;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod ratio;
+mod recreational;
+pub mod roman;
+mod script;
+#[cfg(feature = "serde")]
+pub mod serialize;
+mod sqrt;
+mod stream;
+mod ternary;
+mod transform;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod width;
+pub mod words;
+mod zeckendorf;
+
+pub use arbitrary::Shrink;
+#[cfg(feature = "proptest")]
+pub use arbitrary::{DigitsStrategy, DigitsValueTree};
+pub use bcd::BcdOrder;
+pub use builder::DigitsBuilder;
+pub use consts::{digit_count, nth_digit, DigitArray};
+pub use digit::Digit;
+pub use float::{FloatDigits, Rounding};
+pub use format::DigitsFormat;
+pub use iter::{
+    digits_of, digits_of_radix, Chunks, DigitsIter, LazyDigits, PlaceValues, SubNumbers, Terms,
+    Windows,
+};
+pub use negabase::NegativeBase;
+pub use order::{DigitsView, Order, OrderedIter};
+pub use ratio::RatioDigits;
+pub use recreational::{is_harshad, self_numbers, self_numbers_radix, DigitAddition, HappyIter};
+pub use script::Script;
+pub use sqrt::SqrtDigits;
+pub use stream::DigitStream;
+pub use ternary::BalancedTernary;
+pub use transform::{
+    DigitSignature, KaprekarIter, LeadingZeros, Palindromes, Permutations, ReverseAndAdd,
+    Rotations, Truncations,
+};
+pub use zeckendorf::Zeckendorf;
+
+/// Why a number could not be turned into digits or back, or why digits did
+/// not fit a representation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// An empty digit sequence or string.
+    Empty,
+    /// A negative number where only non-negative ones can be represented.
+    NegativeInput,
+    /// The value does not fit the target type. `needed_bits` is the width of
+    /// its magnitude, when that is known.
+    Overflow {
+        needed_bits: Option<u32>,
+    },
+    /// A character that is not a digit, at `position` in the input.
+    InvalidChar {
+        position: usize,
+        value: char,
+    },
+    /// A digit outside the range allowed at `position`.
+    InvalidDigit {
+        position: usize,
+        value: i128,
+    },
+    /// A radix outside the supported range.
+    InvalidRadix {
+        radix: i64,
+    },
+    /// Digits in one radix where another was expected.
+    RadixMismatch {
+        expected: u32,
+        found: u32,
+    },
+    /// An operation that is only defined for decimal digits.
+    NotDecimal,
+    /// A digit sequence of the wrong length.
+    InvalidLength {
+        expected: usize,
+        found: usize,
+    },
+    /// A digit index past the end of the digits.
+    IndexOutOfRange {
+        index: usize,
+        len: usize,
+    },
+    /// A number outside the range a representation covers.
+    OutOfRange,
+    /// Valid digits that do not form a valid representation.
+    Malformed,
+    DivisionByZero,
+    NonFinite,
+    EmptyRange,
+    /// A repeating decimal cycle longer than the allowed `max_len` digits.
+    CycleTooLong {
+        max_len: usize,
+    },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "unable to convert from empty digit sequence"),
+            Self::NegativeInput => write!(f, "unable to convert from negative number"),
+            Self::Overflow {
+                needed_bits: Some(bits),
+            } => write!(f, "integer overflow, the value needs {} bits", bits),
+            Self::Overflow { needed_bits: None } => write!(f, "integer overflow"),
+            Self::InvalidChar { position, value } => {
+                write!(f, "invalid digit {:?} at position {}", value, position)
+            }
+            Self::InvalidDigit { position, value } => {
+                write!(f, "digit {} at position {} out of range", value, position)
+            }
+            Self::InvalidRadix { radix } => write!(f, "invalid radix {}", radix),
+            Self::RadixMismatch { expected, found } => {
+                write!(f, "expected radix {}, found radix {}", expected, found)
+            }
+            Self::NotDecimal => write!(f, "operation requires decimal digits"),
+            Self::InvalidLength { expected, found } => {
+                write!(f, "expected {} digits, found {}", expected, found)
+            }
+            Self::IndexOutOfRange { index, len } => {
+                write!(f, "digit index {} out of range for {} digits", index, len)
+            }
+            Self::OutOfRange => write!(f, "number out of range for the representation"),
+            Self::Malformed => write!(f, "malformed digit sequence"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::NonFinite => write!(f, "unable to convert non-finite float to digits"),
+            Self::EmptyRange => write!(f, "unable to pick from an empty range"),
+            Self::CycleTooLong { max_len } => {
+                write!(f, "repeating cycle longer than {} digits", max_len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ConversionError {}
+
+impl ConversionError {
+    // Digits wider than i128, which only u128 has, are reported as i128::MAX.
+    fn invalid_digit<T: NumCast>(position: usize, value: T) -> Self {
+        Self::InvalidDigit {
+            position,
+            value: value.to_i128().unwrap_or(i128::MAX),
+        }
+    }
+
+    fn overflow(needed_bits: u32) -> Self {
+        Self::Overflow {
+            needed_bits: Some(needed_bits),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    Positive,
+    Negative,
+    Zero,
+}
+
+/// The number of digits kept inline with the `smallvec` feature. Shorter
+/// numbers convert faster, longer ones somewhat slower, as they spill to the
+/// heap after trying to fit.
+#[cfg(feature = "smallvec")]
+pub const INLINE_DIGITS: usize = 8;
+
+// The digit storage. With the `smallvec` feature numbers of up to
+// `INLINE_DIGITS` digits live inside `Digits` instead of on the heap.
+#[cfg(feature = "smallvec")]
+type DigitVec<T> = smallvec::SmallVec<[T; INLINE_DIGITS]>;
+#[cfg(not(feature = "smallvec"))]
+type DigitVec<T> = Vec<T>;
+
+#[derive(Clone, Debug)]
+pub struct Digits<T> {
+    values: DigitVec<T>,
+    radix: u32,
+    sign: Sign,
+    // Whether leading zeros are part of the value, see `width.rs`.
+    fixed_width: bool,
+}
+
+impl<T> Digits<T> {
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    pub fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    /// The digits, most significant first.
+    ///
+    /// `Digits` used to dereference to its `Vec`. Code that relied on that
+    /// should borrow the digits here, or through `AsRef<[T]>`, for slice
+    /// methods that are not available on `Digits` itself, e.g.
+    /// `digits.as_slice() == [1, 2]` for `*digits == [1, 2]` and
+    /// `digits.as_slice().starts_with(&[1])`.
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The number of digits, counting any leading zeros.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The digit at `index`, counting from the most significant.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+
+    /// The most significant digit, like `most_significant` but without
+    /// panicking.
+    pub fn first(&self) -> Option<&T> {
+        self.values.first()
+    }
+
+    /// The least significant digit, like `least_significant` but without
+    /// panicking.
+    pub fn last(&self) -> Option<&T> {
+        self.values.last()
+    }
+
+    #[cfg(not(feature = "smallvec"))]
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+
+    #[cfg(feature = "smallvec")]
+    pub fn into_vec(self) -> Vec<T> {
+        self.values.into_vec()
+    }
+
+    pub fn most_significant(&self) -> &T {
+        &self.values[0]
+    }
+
+    pub fn least_significant(&self) -> &T {
+        &self.values[self.values.len() - 1]
+    }
+}
+
+impl<T: Clone> Digits<T> {
+    pub fn to_vec(&self) -> Vec<T> {
+        self.values.to_vec()
+    }
+}
+
+impl<T: PartialEq> Digits<T> {
+    pub fn contains(&self, digit: &T) -> bool {
+        self.values.contains(digit)
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: NumCast + Copy,
+{
+    pub fn format(&self) -> DigitsFormat<'_, T> {
+        DigitsFormat::new(self)
+    }
+
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.values.iter().map(|d| {
+            char::from_digit(d.to_u32().unwrap(), self.radix).expect("digit is below radix")
+        })
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num,
+{
+    fn new(values: impl Into<DigitVec<T>>, radix: u32) -> Self {
+        let values = values.into();
+        let sign = if values.iter().all(|d| d.is_zero()) {
+            Sign::Zero
+        } else {
+            Sign::Positive
+        };
+        Self {
+            values,
+            radix,
+            sign,
+            fixed_width: false,
+        }
+    }
+
+    fn new_signed(values: impl Into<DigitVec<T>>, radix: u32, negative: bool) -> Self {
+        let mut digits = Self::new(values, radix);
+        if negative && digits.sign == Sign::Positive {
+            digits.sign = Sign::Negative;
+        }
+        digits
+    }
+
+    // Keeps the sign consistent after the digits were changed in place.
+    fn update_sign(&mut self) {
+        self.sign = match self.sign {
+            _ if self.values.iter().all(|d| d.is_zero()) => Sign::Zero,
+            Sign::Negative => Sign::Negative,
+            _ => Sign::Positive,
+        };
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty)*) => {$(
+        impl From<$t> for Digits<$t> {
+            fn from(i: $t) -> Self {
+                let values = digits_from_int(i).expect("unsigned integers are never negative");
+                Self::new(values, 10)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_try_from_signed {
+    ($($t:ty)*) => {$(
+        impl TryFrom<$t> for Digits<$t> {
+            type Error = ConversionError;
+
+            fn try_from(i: $t) -> Result<Self, Self::Error> {
+                Self::with_radix(i, 10)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_try_from_digits {
+    ($($t:ty)*) => {$(
+        impl TryFrom<Digits<$t>> for $t {
+            type Error = ConversionError;
+
+            fn try_from(digits: Digits<$t>) -> Result<Self, Self::Error> {
+                digits.to_int()
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8 u16 u32 u64 u128 usize);
+impl_try_from_signed!(i8 i16 i32 i64 i128 isize);
+impl_try_from_digits!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl<T> core::str::FromStr for Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mut values, negative) = parse_decimal(s)?;
+        strip_leading_zeros(&mut values);
+        Ok(Self::new_signed(values, 10, negative))
+    }
+}
+
+impl<T> fmt::Display for Digits<T>
+where
+    T: NumCast + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits: String = self.chars().collect();
+        f.pad_integral(self.sign != Sign::Negative, "", &digits)
+    }
+}
+
+impl<T, I> core::ops::Index<I> for Digits<T>
+where
+    I: core::slice::SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<T> AsRef<[T]> for Digits<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.values
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn with_radix(n: T, radix: u32) -> Result<Self, ConversionError> {
+        let values = signed_digits_from_int_radix(n, radix_constant(radix)?);
+        Ok(Self::new_signed(values, radix, n < T::zero()))
+    }
+
+    pub fn from_radix(values: Vec<T>, radix: u32) -> Result<Self, ConversionError> {
+        let radix_value = radix_constant(radix)?;
+        if values.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        if let Some(position) = values
+            .iter()
+            .position(|&d| d < T::zero() || d >= radix_value)
+        {
+            return Err(ConversionError::invalid_digit(position, values[position]));
+        }
+        Ok(Self::new(values, radix))
+    }
+
+    /// The same number written in another radix, by repeated division of
+    /// the digit vector, so sequences longer than any integer type convert
+    /// too.
+    pub fn convert_radix(&self, radix: u32) -> Result<Digits<T>, ConversionError> {
+        radix_constant::<T>(radix)?;
+        let values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        let converted: DigitVec<T> = to_mixed_radix(&values, self.radix as u64, |_| radix as u64)
+            .into_iter()
+            .map(|d| T::from(d).unwrap())
+            .collect();
+        Ok(Self::new_signed(
+            converted,
+            radix,
+            self.sign == Sign::Negative,
+        ))
+    }
+
+    /// Replaces the digit at `index`, counting from the most significant. The
+    /// sign follows along if the number becomes or stops being zero.
+    pub fn set_digit(&mut self, index: usize, digit: T) -> Result<(), ConversionError> {
+        self.check_index(index, self.values.len())?;
+        self.check_digit(index, digit)?;
+        self.values[index] = digit;
+
+        self.update_sign();
+        Ok(())
+    }
+
+    /// Like `set_digit`, but leaves `self` as it is and returns the changed
+    /// copy, e.g. to walk every number one digit away.
+    pub fn with_digit_replaced(&self, index: usize, digit: T) -> Result<Self, ConversionError> {
+        let mut digits = self.clone();
+        digits.set_digit(index, digit)?;
+        Ok(digits)
+    }
+
+    /// Inserts a digit before `index`, or at the end if `index` is the length.
+    pub fn insert_digit(&mut self, index: usize, digit: T) -> Result<(), ConversionError> {
+        self.check_index(index, self.values.len() + 1)?;
+        self.check_digit(index, digit)?;
+        self.values.insert(index, digit);
+
+        self.update_sign();
+        Ok(())
+    }
+
+    /// Removes and returns the digit at `index`. The last remaining digit
+    /// cannot be removed, since there would be no number left.
+    pub fn remove_digit(&mut self, index: usize) -> Result<T, ConversionError> {
+        self.check_index(index, self.values.len())?;
+        if self.values.len() == 1 {
+            return Err(ConversionError::Empty);
+        }
+        let digit = self.values.remove(index);
+
+        self.update_sign();
+        Ok(digit)
+    }
+
+    pub fn swap_digits(&mut self, i: usize, j: usize) -> Result<(), ConversionError> {
+        self.check_index(i, self.values.len())?;
+        self.check_index(j, self.values.len())?;
+        self.values.swap(i, j);
+        Ok(())
+    }
+
+    /// Applies `f` to every digit, e.g. `|d| 9 - d` for the nines'
+    /// complement, and checks that the results are still digits in the
+    /// radix. The sign, the radix and any leading zeros stay as they are.
+    pub fn map_digits<F>(&self, mut f: F) -> Result<Self, ConversionError>
+    where
+        F: FnMut(T) -> T,
+    {
+        let mut digits = self.clone();
+        for (i, d) in digits.values.iter_mut().enumerate() {
+            *d = f(*d);
+            self.check_digit(i, *d)?;
+        }
+
+        digits.update_sign();
+        Ok(digits)
+    }
+
+    fn check_index(&self, index: usize, end: usize) -> Result<(), ConversionError> {
+        if index >= end {
+            return Err(ConversionError::IndexOutOfRange {
+                index,
+                len: self.values.len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_digit(&self, index: usize, digit: T) -> Result<(), ConversionError> {
+        if digit < T::zero() || digit >= radix_constant(self.radix)? {
+            return Err(ConversionError::invalid_digit(index, digit));
+        }
+        Ok(())
+    }
+
+    pub fn to_int(&self) -> Result<T, ConversionError>
+    where
+        T: CheckedAdd + CheckedMul + CheckedSub,
+    {
+        let radix = T::from(self.radix).unwrap();
+        match self.sign {
+            Sign::Negative => negative_int_from_digits_radix(&self.values, radix),
+            _ => int_from_digits_radix(&self.values, radix),
+        }
+    }
+
+    /// Like `to_int`, but clamps to the bounds of `T` instead of failing.
+    pub fn to_int_saturating(&self) -> T
+    where
+        T: Bounded + CheckedAdd + CheckedMul + CheckedSub,
+    {
+        let radix = T::from(self.radix).unwrap();
+        let negative = self.sign == Sign::Negative;
+        let mut n = T::zero();
+        // The magnitude only grows from one digit to the next, so once it
+        // overflows the whole number does.
+        for d in &self.values {
+            let next = match negative {
+                true => n.checked_mul(&radix).and_then(|n| n.checked_sub(d)),
+                false => n.checked_mul(&radix).and_then(|n| n.checked_add(d)),
+            };
+            n = match next {
+                Some(next) => next,
+                None if negative => return T::min_value(),
+                None => return T::max_value(),
+            };
+        }
+        n
+    }
+
+    /// Like `to_int`, but wraps around at the bounds of `T` like the
+    /// `wrapping_*` integer methods, keeping the value modulo 2^bits.
+    pub fn to_int_wrapping(&self) -> T
+    where
+        T: WrappingAdd + WrappingMul + WrappingSub,
+    {
+        let radix = T::from(self.radix).unwrap();
+        let negative = self.sign == Sign::Negative;
+        self.values.iter().fold(T::zero(), |n, d| match negative {
+            true => n.wrapping_mul(&radix).wrapping_sub(d),
+            false => n.wrapping_mul(&radix).wrapping_add(d),
+        })
+    }
+
+    pub fn parse_with_leading_zeros(s: &str) -> Result<Self, ConversionError> {
+        let (values, negative) = parse_decimal(s)?;
+        Ok(Self::new_signed(values, 10, negative))
+    }
+
+    pub fn iso7064_mod11_2(&self, ten: T) -> Result<T, ConversionError> {
+        if self.radix != 10 {
+            return Err(ConversionError::NotDecimal);
+        }
+
+        let two: T = constant(2)?;
+        let ten_value: T = constant(10)?;
+        let eleven: T = constant(11)?;
+        let twelve: T = constant(12)?;
+
+        let p = self
+            .values
+            .iter()
+            .fold(T::zero(), |p, &d| ((p + d) * two) % eleven);
+
+        match (twelve - p) % eleven {
+            check if check == ten_value => Ok(ten),
+            check => Ok(check),
+        }
+    }
+
+    pub fn is_zero_based_permutation(&self) -> bool {
+        let mut seen = vec![false; self.values.len()];
+        for d in &self.values {
+            match d.to_usize() {
+                Some(i) if i < seen.len() && !seen[i] => seen[i] = true,
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// How often each digit value occurs, indexed by digit, with one entry
+    /// per digit of the radix.
+    pub fn counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.radix as usize];
+        for d in &self.values {
+            counts[d.to_usize().unwrap()] += 1;
+        }
+        counts
+    }
+
+    pub fn count_of(&self, digit: T) -> usize {
+        self.values.iter().filter(|&&d| d == digit).count()
+    }
+
+    /// The digit that occurs most often, preferring the smallest on ties.
+    pub fn most_common_digit(&self) -> T {
+        let counts = self.counts();
+        let (digit, _) = counts
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, count)| count)
+            .unwrap();
+        T::from(digit).unwrap()
+    }
+
+    pub fn digit_hamming_distance(&self, other: &Digits<T>) -> usize {
+        let (longer, shorter) = if self.values.len() >= other.values.len() {
+            (&self.values, &other.values)
+        } else {
+            (&other.values, &self.values)
+        };
+        let (padded, aligned) = longer.split_at(longer.len() - shorter.len());
+
+        padded.iter().filter(|d| !d.is_zero()).count()
+            + aligned.iter().zip(shorter).filter(|(a, b)| a != b).count()
+    }
+
+    /// The fewest digit insertions, deletions and substitutions that turn
+    /// one sequence into the other, so a dropped digit counts once where
+    /// `digit_hamming_distance` counts every digit it shifts.
+    pub fn digit_edit_distance(&self, other: &Digits<T>) -> usize {
+        let (a, b) = (&self.values, &other.values);
+        // One row of the usual table at a time, distances from a prefix of
+        // `a` to each prefix of `b`.
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, x) in a.iter().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, y) in b.iter().enumerate() {
+                let substitution = diagonal + (x != y) as usize;
+                diagonal = row[j + 1];
+                row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+            }
+        }
+        row[b.len()]
+    }
+}
+
+pub fn factorial_digits(n: u32) -> Digits<u64> {
+    let mut values = vec![1];
+    for k in 2..=n as u64 {
+        values = long_mul(&values, &digits_from_int(k).unwrap(), 10);
+    }
+    Digits::new(values, 10)
+}
+
+pub fn fibonacci_digits(n: u32) -> Digits<u64> {
+    let mut a = vec![0];
+    let mut b = vec![1];
+    for _ in 0..n {
+        let next = long_add(&a, &b, 10);
+        a = core::mem::replace(&mut b, next);
+    }
+    Digits::new(a, 10)
+}
+
+fn constant<T>(n: u8) -> Result<T, ConversionError>
+where
+    T: NumCast,
+{
+    T::from(n).ok_or_else(|| ConversionError::overflow(u8::BITS - n.leading_zeros()))
+}
+
+fn radix_constant<T>(radix: u32) -> Result<T, ConversionError>
+where
+    T: NumCast,
+{
+    if !(2..=36).contains(&radix) {
+        return Err(ConversionError::InvalidRadix {
+            radix: radix as i64,
+        });
+    }
+    T::from(radix).ok_or_else(|| ConversionError::overflow(u32::BITS - radix.leading_zeros()))
+}
+
+fn parse_decimal<T>(s: &str) -> Result<(Vec<T>, bool), ConversionError>
+where
+    T: Num + NumCast + Copy,
+{
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if unsigned.is_empty() {
+        return Err(ConversionError::Empty);
+    }
+    if negative && T::from(-1).is_none() {
+        return Err(ConversionError::NegativeInput);
+    }
+
+    let sign_len = s.len() - unsigned.len();
+    let values = unsigned
+        .chars()
+        .enumerate()
+        .map(|(position, c)| match c.to_digit(10) {
+            Some(d) => Ok(T::from(d).unwrap()),
+            None => Err(ConversionError::InvalidChar {
+                position: position + sign_len,
+                value: c,
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((values, negative))
+}
+
+fn digits_from_int<T>(n: T) -> Result<DigitVec<T>, ConversionError>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    digits_from_int_radix(n, T::from(10).unwrap())
+}
+
+fn digits_from_int_radix<T>(n: T, radix: T) -> Result<DigitVec<T>, ConversionError>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    let zero = T::from(0).unwrap();
+
+    match n {
+        _ if n >= zero => {
+            #[cfg(feature = "smallvec")]
+            if let Some(v) = inline_digits(n, radix) {
+                return Ok(v);
+            }
+
+            let mut rem = n;
+            let mut v = DigitVec::with_capacity(max_digits::<T>(radix));
+
+            while (rem / radix) > zero {
+                v.push(rem % radix);
+                rem = rem / radix;
+            }
+            v.push(rem);
+            v.reverse();
+
+            Ok(v)
+        }
+        _ => Err(ConversionError::NegativeInput),
+    }
+}
+
+// Upper bound on the number of radix digits of any value of T, so the digit
+// vector is allocated once.
+fn max_digits<T>(radix: T) -> usize
+where
+    T: NumCast,
+{
+    let bits = core::mem::size_of::<T>() * 8;
+    bits.div_ceil(radix.to_u32().unwrap().ilog2() as usize)
+}
+
+// The digits of `n` in an inline buffer, or `None` if there are more than
+// `INLINE_DIGITS` of them. Filling a plain array from the back saves the
+// pushes and the reversal.
+#[cfg(feature = "smallvec")]
+fn inline_digits<T>(n: T, radix: T) -> Option<DigitVec<T>>
+where
+    T: Num + PartialOrd + Copy,
+{
+    let mut buf = [T::zero(); INLINE_DIGITS];
+    let mut rem = n;
+    for i in (0..INLINE_DIGITS).rev() {
+        let digit = rem % radix;
+        buf[i] = if digit < T::zero() {
+            T::zero() - digit
+        } else {
+            digit
+        };
+        rem = rem / radix;
+        if rem.is_zero() {
+            buf.copy_within(i.., 0);
+            return Some(DigitVec::from_buf_and_len(buf, INLINE_DIGITS - i));
+        }
+    }
+    None
+}
+
+fn signed_digits_from_int_radix<T>(n: T, radix: T) -> DigitVec<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    if n >= T::zero() {
+        return digits_from_int_radix(n, radix).expect("n is not negative");
+    }
+
+    #[cfg(feature = "smallvec")]
+    if let Some(v) = inline_digits(n, radix) {
+        return v;
+    }
+
+    // Peel digits off the negative value directly so that T::MIN, whose
+    // magnitude does not fit in T, is still decomposed correctly.
+    let mut rem = n;
+    let mut v = DigitVec::with_capacity(max_digits::<T>(radix));
+    while !rem.is_zero() {
+        v.push(T::zero() - rem % radix);
+        rem = rem / radix;
+    }
+    v.reverse();
+    v
+}
+
+#[cfg(test)]
+fn int_from_digits<T>(v: Vec<T>) -> T
+where
+    T: Num + NumCast + CheckedAdd + CheckedMul + Copy,
+{
+    int_from_digits_radix(&v, T::from(10).unwrap()).unwrap()
+}
+
+// The error for digits too large for T, with the bits the value needs.
+fn overflow_error<T>(v: &[T], radix: T) -> ConversionError
+where
+    T: NumCast + Copy,
+{
+    let values: Vec<u64> = v.iter().map(|d| d.to_u64().unwrap()).collect();
+    let bits = to_mixed_radix(&values, radix.to_u64().unwrap(), |_| 2);
+    ConversionError::overflow(bits.len() as u32)
+}
+
+// The error for a digit sum too large for T. The sum itself fits a u128 for
+// any number of digits that fits in memory.
+fn sum_overflow_error<T>(v: &[T]) -> ConversionError
+where
+    T: NumCast + Copy,
+{
+    let sum: u128 = v.iter().map(|d| d.to_u128().unwrap()).sum();
+    ConversionError::overflow(u128::BITS - sum.leading_zeros())
+}
+
+fn int_from_digits_radix<T>(v: &[T], radix: T) -> Result<T, ConversionError>
+where
+    T: Num + NumCast + CheckedAdd + CheckedMul + Copy,
+{
+    v.iter().try_fold(T::zero(), |number, digit| {
+        number
+            .checked_mul(&radix)
+            .and_then(|n| n.checked_add(digit))
+            .ok_or_else(|| overflow_error(v, radix))
+    })
+}
+
+fn negative_int_from_digits_radix<T>(v: &[T], radix: T) -> Result<T, ConversionError>
+where
+    T: Num + NumCast + CheckedMul + CheckedSub + Copy,
+{
+    v.iter().try_fold(T::zero(), |number, digit| {
+        number
+            .checked_mul(&radix)
+            .and_then(|n| n.checked_sub(digit))
+            .ok_or_else(|| overflow_error(v, radix))
+    })
+}
+
+fn long_add<T>(a: &[T], b: &[T], radix: T) -> Vec<T>
+where
+    T: Num + NumCast + Copy,
+{
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = T::zero();
+
+    let mut a = a.iter().rev();
+    let mut b = b.iter().rev();
+    loop {
+        let cur = match (a.next(), b.next()) {
+            (None, None) => break,
+            (Some(&x), None) | (None, Some(&x)) => x + carry,
+            (Some(&x), Some(&y)) => x + y + carry,
+        };
+        sum.push(cur % radix);
+        carry = cur / radix;
+    }
+    if !carry.is_zero() {
+        sum.push(carry);
+    }
+
+    sum.reverse();
+    sum
+}
+
+// Computes a - b for a >= b, keeping the width of a.
+fn long_sub<T>(a: &[T], b: &[T], radix: T) -> Vec<T>
+where
+    T: Num + PartialOrd + Copy,
+{
+    let mut diff = Vec::with_capacity(a.len());
+    let mut borrow = T::zero();
+
+    let mut b = b.iter().rev();
+    for &x in a.iter().rev() {
+        let y = b.next().copied().unwrap_or_else(T::zero) + borrow;
+        if x >= y {
+            diff.push(x - y);
+            borrow = T::zero();
+        } else {
+            diff.push(x + radix - y);
+            borrow = T::one();
+        }
+    }
+
+    diff.reverse();
+    diff
+}
+
+fn long_mul<T>(a: &[T], b: &[T], radix: T) -> Vec<T>
+where
+    T: Num + NumCast + Copy,
+{
+    let mut product = vec![T::zero(); a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate().rev() {
+        let mut carry = T::zero();
+        for (j, &y) in b.iter().enumerate().rev() {
+            let cur = product[i + j + 1] + x * y + carry;
+            product[i + j + 1] = cur % radix;
+            carry = cur / radix;
+        }
+        product[i] = product[i] + carry;
+    }
+
+    strip_leading_zeros(&mut product);
+    product
+}
+
+// The vectors digits are kept in, the storage of `Digits` as well as the
+// plain `Vec`s the arithmetic works on.
+trait DigitStore<T>: core::ops::DerefMut<Target = [T]> {
+    fn remove_front(&mut self, n: usize);
+}
+
+impl<T> DigitStore<T> for Vec<T> {
+    fn remove_front(&mut self, n: usize) {
+        self.drain(..n);
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<T> DigitStore<T> for DigitVec<T> {
+    fn remove_front(&mut self, n: usize) {
+        self.drain(..n);
+    }
+}
+
+fn strip_leading_zeros<T, V>(v: &mut V)
+where
+    T: Num + Copy,
+    V: DigitStore<T>,
+{
+    let leading = v.iter().take_while(|d| d.is_zero()).count();
+    v.remove_front(leading.min(v.len().saturating_sub(1)));
+}
+
+// Converts a digit vector in `radix` into a mixed radix system where the
+// digit at position i from the right has base `base(i)`, by repeated short
+// division. Both vectors are most significant first.
+fn to_mixed_radix(values: &[u64], radix: u64, mut base: impl FnMut(usize) -> u64) -> Vec<u64> {
+    let mut n = values.to_vec();
+    strip_leading_zeros(&mut n);
+    let mut mixed = Vec::new();
+    loop {
+        let b = base(mixed.len()) as u128;
+        let mut rem = 0u128;
+        for d in n.iter_mut() {
+            let cur = rem * radix as u128 + *d as u128;
+            *d = (cur / b) as u64;
+            rem = cur % b;
+        }
+        mixed.push(rem as u64);
+        strip_leading_zeros(&mut n);
+        if n == [0] {
+            break;
+        }
+    }
+    mixed.reverse();
+    mixed
+}
+
+// The inverse of `to_mixed_radix`, evaluating the mixed radix digits by
+// Horner's rule on a digit vector in `radix`.
+fn from_mixed_radix(mixed: &[u64], radix: u64, mut base: impl FnMut(usize) -> u64) -> Vec<u64> {
+    let mut n = vec![0u64];
+    for (i, &d) in mixed.iter().enumerate() {
+        let b = base(mixed.len() - 1 - i) as u128;
+        let mut carry = d as u128;
+        for x in n.iter_mut().rev() {
+            let cur = *x as u128 * b + carry;
+            *x = (cur % radix as u128) as u64;
+            carry = cur / radix as u128;
+        }
+        while carry > 0 {
+            n.insert(0, (carry % radix as u128) as u64);
+            carry /= radix as u128;
+        }
+    }
+    strip_leading_zeros(&mut n);
+    n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn digits_struct_works() {
+        let mut digits = Digits::from(42u32).into_iter();
+        assert_eq!(digits.next(), Some(4));
+        assert_eq!(digits.next(), Some(2));
+        assert_eq!(digits.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_fold)]
+    fn iterator_adapters_work() {
+        let digits = Digits::from(42u32);
+        assert_eq!(digits.len(), 2);
+
+        let digits = Digits::from(369u32);
+        assert_eq!(digits.into_iter().count(), 3);
+
+        let mut digits = Digits::from(42u32).into_iter().cycle();
+        assert_eq!(digits.next(), Some(4));
+        assert_eq!(digits.next(), Some(2));
+        assert_eq!(digits.next(), Some(4));
+        assert_eq!(digits.next(), Some(2));
+        assert_eq!(digits.next(), Some(4));
+        assert_eq!(digits.next(), Some(2));
+
+        let mut digits = Digits::from(369u32).into_iter().enumerate();
+        assert_eq!(digits.next(), Some((0, 3)));
+        assert_eq!(digits.next(), Some((1, 6)));
+        assert_eq!(digits.next(), Some((2, 9)));
+        assert_eq!(digits.next(), None);
+
+        let digits = Digits::from(369u32);
+        assert_eq!(digits.into_iter().fold(0, |acc, x| acc + x), 18);
+    }
+
+    #[test]
+    fn contains_works() {
+        let digits = Digits::from(369u32);
+        assert!(digits.contains(&3));
+        assert!(!digits.contains(&4));
+    }
+
+    #[test]
+    fn iso7064_mod11_2_works() {
+        // ORCID 0000-0002-1825-0097
+        let digits = Digits::from(21825009u64);
+        assert_eq!(digits.iso7064_mod11_2(10), Ok(7));
+    }
+
+    #[test]
+    fn iso7064_mod11_2_works_with_check_value_ten() {
+        // ORCID 0000-0002-1694-233X
+        let digits = Digits::from(21694233u64);
+        assert_eq!(digits.iso7064_mod11_2(10), Ok(10));
+        assert_eq!(digits.iso7064_mod11_2('X' as u64), Ok('X' as u64));
+    }
+
+    #[test]
+    fn iso7064_mod11_2_works_with_zero() {
+        assert_eq!(Digits::from(0u8).iso7064_mod11_2(10), Ok(1));
+    }
+
+    #[test]
+    fn digits_from_works() {
+        assert_eq!(digits_from_int(42).as_deref(), Ok(&[4, 2][..]));
+    }
+
+    #[test]
+    fn digits_from_works_with_zero() {
+        assert_eq!(digits_from_int(0).as_deref(), Ok(&[0][..]));
+    }
+
+    #[test]
+    fn digits_from_works_with_zeros() {
+        assert_eq!(digits_from_int(00).as_deref(), Ok(&[0][..]));
+    }
+
+    #[test]
+    fn digits_from_works_with_u8_max() {
+        assert_eq!(digits_from_int(u8::MAX).as_deref(), Ok(&[2, 5, 5][..]));
+    }
+
+    #[test]
+    fn digits_from_works_with_u16_max() {
+        assert_eq!(
+            digits_from_int(u16::MAX).as_deref(),
+            Ok(&[6, 5, 5, 3, 5][..])
+        );
+    }
+
+    #[test]
+    fn digits_from_works_with_u32_max() {
+        assert_eq!(
+            digits_from_int(u32::MAX).as_deref(),
+            Ok(&[4, 2, 9, 4, 9, 6, 7, 2, 9, 5][..])
+        );
+    }
+
+    #[test]
+    fn digits_from_works_with_u64_max() {
+        assert_eq!(
+            digits_from_int(u64::MAX).as_deref(),
+            Ok(&[1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5][..])
+        );
+    }
+
+    #[test]
+    fn digits_from_works_around_inline_length() {
+        assert_eq!(
+            digits_from_int(12_345_678u32).as_deref(),
+            Ok(&[1, 2, 3, 4, 5, 6, 7, 8][..])
+        );
+        assert_eq!(
+            digits_from_int(123_456_789u32).as_deref(),
+            Ok(&[1, 2, 3, 4, 5, 6, 7, 8, 9][..])
+        );
+        assert_eq!(signed_digits_from_int_radix(-99_999_999i32, 10)[..], [9; 8]);
+        assert_eq!(
+            signed_digits_from_int_radix(i32::MIN, 10)[..],
+            [2, 1, 4, 7, 4, 8, 3, 6, 4, 8]
+        );
+    }
+
+    #[test]
+    fn conversion_error_display_works() {
+        let err = ConversionError::InvalidChar {
+            position: 2,
+            value: 'a',
+        };
+        assert_eq!(err.to_string(), "invalid digit 'a' at position 2");
+        let err = ConversionError::Overflow {
+            needed_bits: Some(133),
+        };
+        assert_eq!(
+            err.to_string(),
+            "integer overflow, the value needs 133 bits"
+        );
+        assert_eq!(
+            ConversionError::Overflow { needed_bits: None }.to_string(),
+            "integer overflow"
+        );
+    }
+
+    #[test]
+    fn digits_from_throws_error_with_negative_number() {
+        assert_eq!(digits_from_int(-42), Err(ConversionError::NegativeInput));
+    }
+
+    #[test]
+    fn from_digits_works() {
+        let v = vec![4, 2];
+        assert_eq!(int_from_digits(v), 42);
+    }
+
+    #[test]
+    fn from_digits_works_with_zero() {
+        assert_eq!(int_from_digits(vec![0]), 0);
+    }
+
+    #[test]
+    fn from_digits_works_with_u8_max() {
+        assert_eq!(int_from_digits(vec![2, 5, 5]), u8::MAX);
+    }
+
+    #[test]
+    fn from_digits_works_with_u16_max() {
+        assert_eq!(int_from_digits(vec![6, 5, 5, 3, 5]), u16::MAX);
+    }
+
+    #[test]
+    fn from_digits_works_with_u32_max() {
+        assert_eq!(
+            int_from_digits(vec![4, 2, 9, 4, 9, 6, 7, 2, 9, 5]),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn from_digits_works_with_u64_max() {
+        assert_eq!(
+            int_from_digits(vec![
+                1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5
+            ]),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn long_mul_works() {
+        assert_eq!(long_mul(&[4, 2], &[1, 2], 10), vec![5, 0, 4]);
+        assert_eq!(long_mul(&[9, 9, 9], &[9, 9], 10), vec![9, 8, 9, 0, 1]);
+        assert_eq!(long_mul(&[4, 2], &[0], 10), vec![0]);
+    }
+
+    #[test]
+    fn long_mul_works_with_radix() {
+        assert_eq!(long_mul(&[1, 0, 1], &[1, 1], 2), vec![1, 1, 1, 1]);
+        assert_eq!(long_mul(&[15, 15], &[15, 15], 16), vec![15, 14, 0, 1]);
+    }
+
+    #[test]
+    fn long_mul_works_with_u8_digits() {
+        let a: Vec<u8> = vec![9; 20];
+        let b: Vec<u8> = vec![9; 20];
+        let mut expected = vec![9; 19];
+        expected.push(8);
+        expected.extend(vec![0; 19]);
+        expected.push(1);
+        assert_eq!(long_mul(&a, &b, 10), expected);
+    }
+
+    #[test]
+    fn factorial_digits_works() {
+        assert_eq!(factorial_digits(0).as_slice(), vec![1]);
+        assert_eq!(factorial_digits(1).as_slice(), vec![1]);
+        assert_eq!(factorial_digits(10).as_slice(), vec![3, 6, 2, 8, 8, 0, 0]);
+    }
+
+    #[test]
+    fn factorial_digits_works_with_u64_range() {
+        assert_eq!(
+            factorial_digits(20).as_slice(),
+            Digits::from(2_432_902_008_176_640_000u64).as_slice()
+        );
+    }
+
+    #[test]
+    fn factorial_digits_works_beyond_u64_range() {
+        assert_eq!(
+            factorial_digits(25).as_slice(),
+            vec![1, 5, 5, 1, 1, 2, 1, 0, 0, 4, 3, 3, 3, 0, 9, 8, 5, 9, 8, 4, 0, 0, 0, 0, 0, 0]
+        );
+
+        let digits = factorial_digits(100);
+        assert_eq!(digits.len(), 158);
+        assert_eq!(digits[..10], [9, 3, 3, 2, 6, 2, 1, 5, 4, 4]);
+        assert_eq!(digits.iter().sum::<u64>(), 648);
+    }
+
+    #[test]
+    fn long_add_works() {
+        assert_eq!(long_add(&[4, 2], &[1, 2], 10), vec![5, 4]);
+        assert_eq!(long_add(&[9, 9, 9], &[1], 10), vec![1, 0, 0, 0]);
+        assert_eq!(long_add(&[1], &[9, 9, 9], 10), vec![1, 0, 0, 0]);
+        assert_eq!(long_add(&[0], &[0], 10), vec![0]);
+    }
+
+    #[test]
+    fn long_sub_works() {
+        assert_eq!(long_sub(&[5, 4], &[1, 2], 10), vec![4, 2]);
+        assert_eq!(long_sub(&[1, 0, 0, 0], &[1], 10), vec![0, 9, 9, 9]);
+        assert_eq!(long_sub(&[4, 2], &[4, 2], 10), vec![0, 0]);
+        assert_eq!(long_sub(&[1, 0, 0u8], &[1, 1], 2), vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn fibonacci_digits_works() {
+        assert_eq!(fibonacci_digits(0).as_slice(), vec![0]);
+        assert_eq!(fibonacci_digits(1).as_slice(), vec![1]);
+        assert_eq!(fibonacci_digits(2).as_slice(), vec![1]);
+        assert_eq!(fibonacci_digits(10).as_slice(), vec![5, 5]);
+    }
+
+    #[test]
+    fn fibonacci_digits_works_beyond_u64_range() {
+        assert_eq!(
+            fibonacci_digits(100).as_slice(),
+            vec![3, 5, 4, 2, 2, 4, 8, 4, 8, 1, 7, 9, 2, 6, 1, 9, 1, 5, 0, 7, 5]
+        );
+    }
+
+    #[test]
+    fn is_zero_based_permutation_works() {
+        assert!(Digits::from(3102u32).is_zero_based_permutation());
+        assert!(Digits::from(0u32).is_zero_based_permutation());
+        assert!(Digits::from(1_234_567_890u64).is_zero_based_permutation());
+        assert!(Digits::from(10u32).is_zero_based_permutation());
+    }
+
+    #[test]
+    fn counts_works() {
+        let counts = Digits::from(1_223_334_444u64).counts();
+        assert_eq!(counts, vec![0, 1, 2, 3, 4, 0, 0, 0, 0, 0]);
+        assert_eq!(Digits::from(0u32).counts()[0], 1);
+    }
+
+    #[test]
+    fn counts_works_with_radix() {
+        assert_eq!(Digits::with_radix(0b1011, 2).unwrap().counts(), vec![1, 3]);
+        let counts = Digits::with_radix(0xffau32, 16).unwrap().counts();
+        assert_eq!(counts.len(), 16);
+        assert_eq!((counts[0xa], counts[0xf]), (1, 2));
+    }
+
+    #[test]
+    fn count_of_works() {
+        let digits = Digits::from(1_223_334_444u64);
+        assert_eq!(digits.count_of(4), 4);
+        assert_eq!(digits.count_of(0), 0);
+        assert_eq!(Digits::from(100u32).count_of(0), 2);
+    }
+
+    #[test]
+    fn most_common_digit_works() {
+        assert_eq!(Digits::from(1_223_334_444u64).most_common_digit(), 4);
+        assert_eq!(Digits::from(7u32).most_common_digit(), 7);
+        assert_eq!(Digits::from(1_000u32).most_common_digit(), 0);
+    }
+
+    #[test]
+    fn most_common_digit_prefers_smallest_on_ties() {
+        assert_eq!(Digits::from(9_191u32).most_common_digit(), 1);
+        assert_eq!(Digits::from(987_654_321u32).most_common_digit(), 1);
+    }
+
+    #[test]
+    fn is_zero_based_permutation_rejects_repeated_digit() {
+        assert!(!Digits::from(3100u32).is_zero_based_permutation());
+        assert!(!Digits::from(11u32).is_zero_based_permutation());
+    }
+
+    #[test]
+    fn is_zero_based_permutation_rejects_out_of_range_digit() {
+        assert!(!Digits::from(3104u32).is_zero_based_permutation());
+        assert!(!Digits::from(1u32).is_zero_based_permutation());
+        assert!(!factorial_digits(30).is_zero_based_permutation());
+    }
+
+    #[test]
+    fn digit_hamming_distance_works() {
+        let digits = Digits::from(123u32);
+        assert_eq!(digits.digit_hamming_distance(&Digits::from(153u32)), 1);
+        assert_eq!(digits.digit_hamming_distance(&Digits::from(123u32)), 0);
+        assert_eq!(digits.digit_hamming_distance(&Digits::from(321u32)), 2);
+    }
+
+    #[test]
+    fn digit_hamming_distance_works_with_unequal_lengths() {
+        let digits = Digits::from(123u32);
+        assert_eq!(digits.digit_hamming_distance(&Digits::from(23u32)), 1);
+        assert_eq!(digits.digit_hamming_distance(&Digits::from(5123u32)), 1);
+        assert_eq!(Digits::from(7u32).digit_hamming_distance(&digits), 3);
+        assert_eq!(
+            Digits::from(0u32).digit_hamming_distance(&Digits::from(100u32)),
+            1
+        );
+    }
+
+    #[test]
+    fn digit_edit_distance_works() {
+        let digits = Digits::from(123_456u32);
+        assert_eq!(digits.digit_edit_distance(&Digits::from(123_456u32)), 0);
+        assert_eq!(digits.digit_edit_distance(&Digits::from(123_956u32)), 1);
+        assert_eq!(digits.digit_edit_distance(&Digits::from(12_456u32)), 1);
+        assert_eq!(digits.digit_edit_distance(&Digits::from(1_293_456u32)), 1);
+        assert_eq!(digits.digit_edit_distance(&Digits::from(124_356u32)), 2);
+        assert_eq!(digits.digit_edit_distance(&Digits::from(7u32)), 6);
+        // Hamming counts the digits that shifted past the dropped one.
+        assert_eq!(digits.digit_hamming_distance(&Digits::from(12_456u32)), 3);
+    }
 
-#[derive(Debug, PartialEq)]
-pub struct ConversionError {
-    details: String,
-}
+    #[test]
+    fn digit_edit_distance_is_symmetric() {
+        let numbers = [0u64, 7, 10, 1_001, 98_765, 10_293_847, 56_473_829];
+        for &a in &numbers {
+            for &b in &numbers {
+                let (a, b) = (Digits::from(a), Digits::from(b));
+                assert_eq!(a.digit_edit_distance(&b), b.digit_edit_distance(&a));
+                assert!(a.digit_edit_distance(&b) <= a.len().max(b.len()));
+            }
+        }
+    }
 
-impl ConversionError {
-    fn new(msg: &str) -> Self {
-        Self {
-            details: msg.to_string(),
+    #[test]
+    fn digit_edit_distance_works_with_leading_zeros() {
+        // Leading zeros are digits like any other here.
+        let account = Digits::<u8>::parse_with_leading_zeros("0012345").unwrap();
+        let typed = Digits::<u8>::from_radix(vec![1, 2, 3, 4, 5], 10).unwrap();
+        assert_eq!(account.digit_edit_distance(&typed), 2);
+    }
+
+    #[test]
+    fn radix_defaults_to_ten() {
+        assert_eq!(Digits::from(42u32).radix(), 10);
+        assert_eq!(factorial_digits(5).radix(), 10);
+    }
+
+    #[test]
+    fn with_radix_works() {
+        assert_eq!(Digits::with_radix(5, 2).unwrap().as_slice(), vec![1, 0, 1]);
+        assert_eq!(
+            Digits::with_radix(0o755, 8).unwrap().as_slice(),
+            vec![7, 5, 5]
+        );
+        assert_eq!(
+            Digits::with_radix(0xbeef, 16).unwrap().as_slice(),
+            vec![11, 14, 14, 15]
+        );
+        assert_eq!(Digits::with_radix(35, 36).unwrap().as_slice(), vec![35]);
+        assert_eq!(Digits::with_radix(0, 2).unwrap().as_slice(), vec![0]);
+        assert_eq!(Digits::with_radix(5, 2).unwrap().radix(), 2);
+    }
+
+    #[test]
+    fn with_radix_works_with_u8_max() {
+        assert_eq!(
+            Digits::with_radix(u8::MAX, 2).unwrap().as_slice(),
+            vec![1, 1, 1, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            Digits::with_radix(u8::MAX, 16).unwrap().as_slice(),
+            vec![15, 15]
+        );
+    }
+
+    #[test]
+    fn with_radix_throws_error_with_invalid_radix() {
+        for radix in [0, 1, 37] {
+            assert_eq!(
+                Digits::with_radix(42, radix).map(|d| d.to_vec()),
+                Err(ConversionError::InvalidRadix {
+                    radix: radix as i64
+                })
+            );
         }
     }
-}
 
-impl fmt::Display for ConversionError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.details)
+    #[test]
+    fn with_radix_works_with_negative_number() {
+        let digits = Digits::with_radix(-42, 16).unwrap();
+        assert_eq!(digits.as_slice(), vec![2, 10]);
+        assert_eq!(digits.sign(), Sign::Negative);
+        assert_eq!(digits.to_int(), Ok(-42));
     }
-}
 
-impl Error for ConversionError {
-    fn description(&self) -> &str {
-        &self.details
+    #[test]
+    fn from_radix_works() {
+        let digits = Digits::from_radix(vec![1, 0, 1], 2).unwrap();
+        assert_eq!(digits.radix(), 2);
+        assert_eq!(digits.to_int(), Ok(5));
+
+        let digits = Digits::from_radix(vec![15u8, 15], 16).unwrap();
+        assert_eq!(digits.to_int(), Ok(u8::MAX));
     }
-}
 
-#[derive(Clone)]
-pub struct Digits<T> {
-    values: Vec<T>,
-    index: usize,
-}
+    #[test]
+    fn from_radix_throws_error_with_digit_out_of_range() {
+        let err = |position, value| Err(ConversionError::InvalidDigit { position, value });
+        assert_eq!(
+            Digits::from_radix(vec![1, 2, 1], 2).map(|d| d.to_vec()),
+            err(1, 2)
+        );
+        assert_eq!(
+            Digits::from_radix(vec![1, 16], 16).map(|d| d.to_vec()),
+            err(1, 16)
+        );
+        assert_eq!(
+            Digits::from_radix(vec![-1], 10).map(|d| d.to_vec()),
+            err(0, -1)
+        );
+    }
 
-impl<T> From<T> for Digits<T>
-where
-    T: Num + NumCast + PartialOrd + Copy,
-{
-    fn from(i: T) -> Self {
-        match digits_from_int(i) {
-            Ok(values) => Self { values, index: 0 },
-            Err(err) => {
-                panic!("{}", err)
+    #[test]
+    fn from_radix_throws_error_with_empty_digits() {
+        assert!(Digits::<u32>::from_radix(vec![], 10).is_err());
+    }
+
+    #[test]
+    fn convert_radix_works() {
+        let digits = Digits::from(255u32).convert_radix(16).unwrap();
+        assert_eq!(digits.to_string(), "ff");
+        assert_eq!(digits.radix(), 16);
+        let digits = Digits::with_radix(-255i32, 2).unwrap();
+        assert_eq!(digits.convert_radix(10).unwrap().to_int(), Ok(-255));
+        assert_eq!(
+            Digits::from(0u32).convert_radix(7).unwrap().to_string(),
+            "0"
+        );
+        let digits = Digits::<u8>::parse_with_leading_zeros("0042").unwrap();
+        assert_eq!(digits.convert_radix(10).unwrap().as_slice(), vec![4, 2]);
+    }
+
+    #[test]
+    fn convert_radix_round_trips() {
+        for radix in 2..=36 {
+            for n in (0u64..5_000).step_by(13) {
+                let digits = Digits::from(n).convert_radix(radix).unwrap();
+                assert!(digits == Digits::with_radix(n, radix).unwrap());
+                assert_eq!(
+                    digits.convert_radix(10).unwrap().as_slice(),
+                    Digits::from(n).as_slice()
+                );
             }
         }
     }
-}
 
-impl<T> std::ops::Deref for Digits<T> {
-    type Target = Vec<T>;
+    #[test]
+    fn convert_radix_works_beyond_integer_range() {
+        let factorial = factorial_digits(100);
+        let binary = factorial.convert_radix(2).unwrap();
+        // 100! has 525 bits, the last 97 of which are zero.
+        assert_eq!(binary.len(), 525);
+        assert_eq!(binary.iter().rev().take_while(|&&d| d == 0).count(), 97);
+        assert_eq!(
+            binary.convert_radix(10).unwrap().as_slice(),
+            factorial.as_slice()
+        );
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.values
+    #[test]
+    fn convert_radix_throws_error_with_invalid_radix() {
+        assert!(Digits::from(12u32).convert_radix(1).is_err());
+        assert!(Digits::from(12u32).convert_radix(37).is_err());
     }
-}
 
-impl<T> Iterator for Digits<T>
-where
-    T: Copy,
-{
-    type Item = T;
+    #[test]
+    fn to_int_works() {
+        assert_eq!(Digits::from(42u32).to_int(), Ok(42));
+        assert_eq!(Digits::from(u64::MAX).to_int(), Ok(u64::MAX));
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.values.len() {
-            return None;
+    #[test]
+    fn radix_round_trip_works() {
+        for radix in 2..=36 {
+            for n in [0u32, 1, 35, 36, 1295, 65_535, u32::MAX] {
+                assert_eq!(Digits::with_radix(n, radix).unwrap().to_int(), Ok(n));
+            }
         }
-        self.index += 1;
-        Some(self.values[self.index - 1])
     }
-}
 
-fn digits_from_int<T>(n: T) -> Result<Vec<T>, ConversionError>
-where
-    T: Num + NumCast + PartialOrd + Copy,
-{
-    let zero = T::from(0).unwrap();
-    let ten = T::from(10).unwrap();
+    #[test]
+    fn iso7064_mod11_2_throws_error_with_non_decimal_radix() {
+        assert!(Digits::with_radix(5, 2)
+            .unwrap()
+            .iso7064_mod11_2(10)
+            .is_err());
+    }
 
-    match n {
-        _ if n >= zero => {
-            let mut rem = n;
-            let mut v = Vec::new();
+    #[test]
+    fn try_from_works() {
+        assert_eq!(Digits::try_from(42).unwrap().as_slice(), vec![4, 2]);
+        assert_eq!(Digits::try_from(0i8).unwrap().as_slice(), vec![0]);
+        assert_eq!(Digits::try_from(i64::MAX).unwrap().to_int(), Ok(i64::MAX));
+    }
+
+    #[test]
+    fn try_from_works_with_negative_number() {
+        let digits = Digits::try_from(-123).unwrap();
+        assert_eq!(digits.as_slice(), vec![1, 2, 3]);
+        assert_eq!(digits.sign(), Sign::Negative);
+        assert_eq!(digits.to_int(), Ok(-123));
+    }
+
+    #[test]
+    fn try_from_works_with_signed_min() {
+        let digits = Digits::try_from(i8::MIN).unwrap();
+        assert_eq!(digits.as_slice(), vec![1, 2, 8]);
+        assert_eq!(digits.to_int(), Ok(i8::MIN));
+
+        let digits = Digits::try_from(i128::MIN).unwrap();
+        assert_eq!(digits.len(), 39);
+        assert_eq!(digits.to_int(), Ok(i128::MIN));
+    }
+
+    #[test]
+    fn from_works_with_u128_and_usize() {
+        assert_eq!(Digits::from(u128::MAX).len(), 39);
+        assert_eq!(Digits::from(42usize).as_slice(), vec![4, 2]);
+    }
 
-            while (rem / ten) > zero {
-                let last = rem % ten;
-                rem = rem / ten;
-                v.insert(0, last);
+    #[test]
+    fn u128_round_trips() {
+        let max = Digits::from(u128::MAX);
+        assert_eq!(max.to_string(), "340282366920938463463374607431768211455");
+        assert_eq!(max.to_int(), Ok(u128::MAX));
+        for n in [0, 1, u64::MAX as u128 + 1, u128::MAX - 1, u128::MAX] {
+            for radix in 2..=36 {
+                let digits = Digits::with_radix(n, radix).unwrap();
+                assert_eq!(digits.to_int(), Ok(n));
             }
-            v.insert(0, rem);
+            let parsed: Digits<u128> = n.to_string().parse().unwrap();
+            assert_eq!(parsed.to_int(), Ok(n));
+            assert_eq!(digits_of(n).collect::<Vec<_>>(), Digits::from(n).as_slice());
+        }
+        assert_eq!(Digits::with_radix(u128::MAX, 2).unwrap().len(), 128);
+    }
 
-            Ok(v)
+    #[test]
+    fn i128_round_trips() {
+        for n in [i128::MIN, i128::MIN + 1, -1, 0, i128::MAX] {
+            for radix in 2..=36 {
+                let digits = Digits::with_radix(n, radix).unwrap();
+                assert_eq!(digits.to_int(), Ok(n));
+            }
+            let parsed: Digits<i128> = n.to_string().parse().unwrap();
+            assert_eq!(parsed.to_int(), Ok(n));
         }
-        _ => Err(ConversionError::new(
-            "unable to convert from negative integer to digits",
-        )),
+        assert_eq!(
+            Digits::try_from(i128::MIN).unwrap().to_string(),
+            "-170141183460469231731687303715884105728"
+        );
     }
-}
 
-fn int_from_digits<T>(v: Vec<T>) -> T
-where
-    T: Num + NumCast + NumOps + Copy,
-{
-    let mut number = T::from(0).unwrap();
-    let ten = T::from(10).unwrap();
-    for (idx, mut digit) in v.into_iter().rev().enumerate() {
-        for _ in 0..idx {
-            digit = digit * ten;
+    #[test]
+    fn to_int_saturating_works() {
+        assert_eq!(Digits::from(255u8).to_int_saturating(), 255);
+        let digits = Digits::from_radix(vec![2u8, 5, 6], 10).unwrap();
+        assert_eq!(digits.to_int_saturating(), u8::MAX);
+        assert_eq!(factorial_digits(40).to_int_saturating(), u64::MAX);
+        assert_eq!(Digits::try_from(-128i8).unwrap().to_int_saturating(), -128);
+        let digits: Digits<i8> = "-129".parse().unwrap();
+        assert_eq!(digits.to_int_saturating(), i8::MIN);
+        let digits: Digits<i8> = "128".parse().unwrap();
+        assert_eq!(digits.to_int_saturating(), i8::MAX);
+    }
+
+    #[test]
+    fn to_int_wrapping_works() {
+        let digits = Digits::from_radix(vec![2u8, 5, 6], 10).unwrap();
+        assert_eq!(digits.to_int_wrapping(), 0);
+        let digits: Digits<i8> = "-129".parse().unwrap();
+        assert_eq!(digits.to_int_wrapping(), 127);
+        let digits: Digits<u32> = "12345678901234".parse().unwrap();
+        assert_eq!(digits.to_int_wrapping(), 12_345_678_901_234u64 as u32);
+        for n in [0u64, 1, 99, u64::MAX] {
+            assert_eq!(Digits::from(n).to_int_wrapping(), n);
+        }
+        for n in [i64::MIN, -1, i64::MAX] {
+            let digits = Digits::with_radix(n, 7).unwrap();
+            assert_eq!(digits.to_int_wrapping(), n);
         }
-        number = number + digit;
     }
-    number
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn to_int_throws_error_beyond_128_bits() {
+        let above_max: Digits<u128> = "340282366920938463463374607431768211456".parse().unwrap();
+        assert_eq!(
+            above_max.to_int(),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(129)
+            })
+        );
+        let above_max: Digits<i128> = "170141183460469231731687303715884105728".parse().unwrap();
+        assert_eq!(
+            above_max.to_int(),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(128)
+            })
+        );
+        let below_min: Digits<i128> = "-170141183460469231731687303715884105729".parse().unwrap();
+        assert!(below_min.to_int().is_err());
+    }
 
     #[test]
-    fn digits_struct_works() {
-        let mut digits = Digits::from(42);
-        assert_eq!(digits.next(), Some(4));
-        assert_eq!(digits.next(), Some(2));
-        assert_eq!(digits.next(), None);
+    fn to_int_throws_error_on_overflow() {
+        assert_eq!(
+            Digits::from_radix(vec![9u64; 40], 10).unwrap().to_int(),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(133)
+            })
+        );
+        assert!(Digits::from_radix(vec![2u8, 5, 6], 10)
+            .unwrap()
+            .to_int()
+            .is_err());
+        assert!(factorial_digits(25).to_int().is_err());
     }
 
     #[test]
-    fn iterator_adapters_work() {
-        let digits = Digits::from(42);
-        assert_eq!(digits.len(), 2);
+    fn to_int_works_at_type_boundary() {
+        assert_eq!(factorial_digits(20).to_int(), Ok(2_432_902_008_176_640_000));
+        assert_eq!(
+            Digits::from_radix(vec![1u8, 1, 1, 1, 1, 1, 1, 1], 2)
+                .unwrap()
+                .to_int(),
+            Ok(u8::MAX)
+        );
+        assert!(Digits::from_radix(vec![1u8, 0, 0, 0, 0, 0, 0, 0, 0], 2)
+            .unwrap()
+            .to_int()
+            .is_err());
+    }
 
-        let digits = Digits::from(369);
-        assert_eq!(digits.count(), 3);
+    #[test]
+    fn try_from_digits_works() {
+        assert_eq!(u32::try_from(Digits::from(42u32)), Ok(42));
+        assert_eq!(i64::try_from(Digits::try_from(42i64).unwrap()), Ok(42));
+        assert!(u64::try_from(factorial_digits(21)).is_err());
+    }
 
-        let mut digits = Digits::from(42).cycle();
-        assert_eq!(digits.next(), Some(4));
-        assert_eq!(digits.next(), Some(2));
-        assert_eq!(digits.next(), Some(4));
-        assert_eq!(digits.next(), Some(2));
+    #[test]
+    fn sign_works() {
+        assert_eq!(Digits::from(42u32).sign(), Sign::Positive);
+        assert_eq!(Digits::from(0u32).sign(), Sign::Zero);
+        assert_eq!(Digits::try_from(0i32).unwrap().sign(), Sign::Zero);
+        assert_eq!(Digits::try_from(42i32).unwrap().sign(), Sign::Positive);
+        assert_eq!(Digits::try_from(-1i32).unwrap().sign(), Sign::Negative);
+        assert_eq!(
+            Digits::from_radix(vec![0, 0], 10).unwrap().sign(),
+            Sign::Zero
+        );
+        assert_eq!(factorial_digits(3).sign(), Sign::Positive);
+    }
+
+    #[test]
+    fn iteration_yields_magnitude_digits_for_negative_number() {
+        let mut digits = Digits::try_from(-42).unwrap().into_iter();
         assert_eq!(digits.next(), Some(4));
         assert_eq!(digits.next(), Some(2));
-
-        let mut digits = Digits::from(369).enumerate();
-        assert_eq!(digits.next(), Some((0, 3)));
-        assert_eq!(digits.next(), Some((1, 6)));
-        assert_eq!(digits.next(), Some((2, 9)));
         assert_eq!(digits.next(), None);
+    }
 
-        let digits = Digits::from(369);
-        assert_eq!(digits.fold(0, |acc, x| acc + x), 18);
+    #[test]
+    fn signed_round_trip_works() {
+        for n in [i64::MIN, -1_000_000, -10, -9, -1, 0, 1, 9, 10, i64::MAX] {
+            assert_eq!(Digits::try_from(n).unwrap().to_int(), Ok(n));
+            assert_eq!(i64::try_from(Digits::try_from(n).unwrap()), Ok(n));
+        }
+        for radix in [2, 3, 16, 36] {
+            for n in [i16::MIN, -255, -1, 0, 1, 255, i16::MAX] {
+                assert_eq!(Digits::with_radix(n, radix).unwrap().to_int(), Ok(n));
+            }
+        }
     }
 
     #[test]
-    fn contains_works() {
-        let digits = Digits::from(369);
-        assert!(digits.contains(&3));
-        assert_eq!(digits.contains(&4), false);
+    fn from_str_works() {
+        let digits: Digits<u8> = "42".parse().unwrap();
+        assert_eq!(digits.as_slice(), vec![4, 2]);
+        assert_eq!(digits.sign(), Sign::Positive);
+
+        let digits: Digits<u8> = "+7".parse().unwrap();
+        assert_eq!(digits.as_slice(), vec![7]);
     }
 
     #[test]
-    fn digits_from_works() {
-        assert_eq!(digits_from_int(42), Ok(vec![4, 2]));
+    fn from_str_works_with_zero() {
+        let digits: Digits<u8> = "0".parse().unwrap();
+        assert_eq!(digits.as_slice(), vec![0]);
+        assert_eq!(digits.sign(), Sign::Zero);
+
+        let digits: Digits<u8> = "000".parse().unwrap();
+        assert_eq!(digits.as_slice(), vec![0]);
     }
 
     #[test]
-    fn digits_from_works_with_zero() {
-        assert_eq!(digits_from_int(0), Ok(vec![0]));
+    fn from_str_strips_leading_zeros() {
+        let digits: Digits<u8> = "007".parse().unwrap();
+        assert_eq!(digits.as_slice(), vec![7]);
     }
 
     #[test]
-    fn digits_from_works_with_zeros() {
-        assert_eq!(digits_from_int(00), Ok(vec![0]));
+    fn from_str_works_with_negative_number() {
+        let digits: Digits<i32> = "-123".parse().unwrap();
+        assert_eq!(digits.as_slice(), vec![1, 2, 3]);
+        assert_eq!(digits.sign(), Sign::Negative);
+        assert_eq!(digits.to_int(), Ok(-123));
+
+        let digits: Digits<i32> = "-0".parse().unwrap();
+        assert_eq!(digits.sign(), Sign::Zero);
     }
 
     #[test]
-    fn digits_from_works_with_u8_max() {
-        assert_eq!(digits_from_int(u8::MAX), Ok(vec![2, 5, 5]));
+    fn from_str_works_beyond_u128_range() {
+        let s = "1".repeat(1_000);
+        let digits: Digits<u8> = s.parse().unwrap();
+        assert_eq!(digits.len(), 1_000);
+        assert!(digits.iter().all(|&d| d == 1));
     }
 
     #[test]
-    fn digits_from_works_with_u16_max() {
-        assert_eq!(digits_from_int(u16::MAX), Ok(vec![6, 5, 5, 3, 5]));
+    fn from_str_throws_error_with_invalid_digit() {
+        assert_eq!(
+            "12a4".parse::<Digits<u8>>().map(|d| d.to_vec()),
+            Err(ConversionError::InvalidChar {
+                position: 2,
+                value: 'a'
+            })
+        );
+        assert_eq!(
+            "-1 2".parse::<Digits<i8>>().map(|d| d.to_vec()),
+            Err(ConversionError::InvalidChar {
+                position: 2,
+                value: ' '
+            })
+        );
+        assert!("--1".parse::<Digits<i8>>().is_err());
+        assert!("١٢".parse::<Digits<u8>>().is_err());
     }
 
     #[test]
-    fn digits_from_works_with_u32_max() {
+    fn from_str_throws_error_with_empty_string() {
+        assert_eq!(
+            "".parse::<Digits<u8>>().map(|d| d.to_vec()),
+            Err(ConversionError::Empty)
+        );
         assert_eq!(
-            digits_from_int(u32::MAX),
-            Ok(vec![4, 2, 9, 4, 9, 6, 7, 2, 9, 5])
+            "-".parse::<Digits<i8>>().map(|d| d.to_vec()),
+            Err(ConversionError::Empty)
         );
     }
 
     #[test]
-    fn digits_from_works_with_u64_max() {
+    fn from_str_throws_error_with_negative_number_for_unsigned_type() {
         assert_eq!(
-            digits_from_int(u64::MAX),
-            Ok(vec![
-                1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5
-            ])
+            "-42".parse::<Digits<u32>>().map(|d| d.to_vec()),
+            Err(ConversionError::NegativeInput)
         );
     }
 
     #[test]
-    fn digits_from_throws_error_with_negative_number() {
+    fn parse_with_leading_zeros_works() {
+        let digits = Digits::<u8>::parse_with_leading_zeros("007").unwrap();
+        assert_eq!(digits.as_slice(), vec![0, 0, 7]);
+        assert_eq!(digits.to_int(), Ok(7));
+
+        let digits = Digits::<u8>::parse_with_leading_zeros("000").unwrap();
+        assert_eq!(digits.as_slice(), vec![0, 0, 0]);
+        assert_eq!(digits.sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(Digits::from(42u32).to_string(), "42");
+        assert_eq!(Digits::from(0u32).to_string(), "0");
+        assert_eq!(Digits::try_from(-123).unwrap().to_string(), "-123");
+        assert_eq!(
+            factorial_digits(25).to_string(),
+            "15511210043330985984000000"
+        );
+    }
+
+    #[test]
+    fn display_works_with_radix() {
+        assert_eq!(Digits::with_radix(5, 2).unwrap().to_string(), "101");
+        assert_eq!(Digits::with_radix(0xbeef, 16).unwrap().to_string(), "beef");
+        assert_eq!(Digits::with_radix(-35, 36).unwrap().to_string(), "-z");
+    }
+
+    #[test]
+    fn display_works_with_formatter_flags() {
+        let digits = Digits::from(42u32);
+        assert_eq!(format!("{:5}", digits), "   42");
+        assert_eq!(format!("{:<5}|", digits), "42   |");
+        assert_eq!(format!("{:05}", digits), "00042");
+        assert_eq!(format!("{:+}", digits), "+42");
+        assert_eq!(format!("{:05}", Digits::try_from(-42).unwrap()), "-0042");
+    }
+
+    #[test]
+    fn max_digits_is_an_upper_bound() {
+        assert_eq!(max_digits(10u8), 3);
+        assert!(max_digits(10u64) >= Digits::from(u64::MAX).len());
+        assert!(max_digits(10u128) >= Digits::from(u128::MAX).len());
+        assert!(max_digits(10i8) >= Digits::try_from(i8::MIN).unwrap().len());
+        for radix in 2..=36 {
+            let n = Digits::with_radix(u32::MAX, radix).unwrap();
+            assert!(max_digits(radix) >= n.len());
+        }
+    }
+
+    #[test]
+    fn digits_from_does_not_reallocate() {
+        let digits = digits_from_int(u64::MAX).unwrap();
+        assert_eq!(digits.capacity(), max_digits(10u64));
+    }
+
+    #[test]
+    fn slice_api_works() {
+        let digits = Digits::from(1_234u32);
+        assert_eq!(digits.as_slice(), [1, 2, 3, 4]);
+        assert_eq!(digits.as_ref(), [1, 2, 3, 4]);
+        assert_eq!(digits.len(), 4);
+        assert!(!digits.is_empty());
+        assert_eq!(digits.first(), Some(&1));
+        assert_eq!(digits.last(), Some(&4));
+        assert_eq!(digits.to_vec(), vec![1, 2, 3, 4]);
+        assert_eq!(digits.into_vec(), vec![1, 2, 3, 4]);
+        let zeros = Digits::<u8>::parse_with_leading_zeros("007").unwrap();
+        assert_eq!(zeros.len(), 3);
+    }
+
+    #[test]
+    fn index_works() {
+        let digits = Digits::from(1_234u32);
+        assert_eq!(digits[0], 1);
+        assert_eq!(digits[3], 4);
+        assert_eq!(digits.get(1), Some(&2));
+        assert_eq!(digits.get(4), None);
+    }
+
+    #[test]
+    fn most_and_least_significant_work() {
+        let digits = Digits::with_radix(0xbeefu32, 16).unwrap();
+        assert_eq!(*digits.most_significant(), 0xb);
+        assert_eq!(*digits.least_significant(), 0xf);
+        let digits = Digits::from(0u32);
+        assert_eq!(digits.most_significant(), digits.least_significant());
+    }
+
+    #[test]
+    fn set_digit_works() {
+        let mut digits = Digits::from(1_234u32);
+        digits.set_digit(0, 9).unwrap();
+        assert_eq!(digits.to_int(), Ok(9_234));
+
+        let mut digits = Digits::try_from(-5).unwrap();
+        digits.set_digit(0, 0).unwrap();
+        assert_eq!(digits.sign(), Sign::Zero);
+        digits.set_digit(0, 7).unwrap();
+        assert_eq!(digits.sign(), Sign::Positive);
+
+        let mut digits = Digits::try_from(-15).unwrap();
+        digits.set_digit(1, 0).unwrap();
+        assert_eq!(digits.to_int(), Ok(-10));
+    }
+
+    #[test]
+    fn set_digit_throws_error_with_digit_out_of_range() {
+        let mut digits = Digits::with_radix(0xffu32, 16).unwrap();
         assert_eq!(
-            digits_from_int(-42),
-            Err(ConversionError {
-                details: "unable to convert from negative integer to digits".to_string()
+            digits.set_digit(0, 17),
+            Err(ConversionError::InvalidDigit {
+                position: 0,
+                value: 17
             })
         );
+        assert_eq!(digits.to_int(), Ok(0xff));
+        assert!(Digits::try_from(-5).unwrap().set_digit(0, -1).is_err());
     }
 
     #[test]
-    fn from_digits_works() {
-        let v = vec![4, 2];
-        assert_eq!(int_from_digits(v), 42);
+    fn set_digit_throws_error_with_index_out_of_range() {
+        assert_eq!(
+            Digits::from(12u32).set_digit(2, 1),
+            Err(ConversionError::IndexOutOfRange { index: 2, len: 2 })
+        );
     }
 
     #[test]
-    fn from_digits_works_with_zero() {
-        assert_eq!(int_from_digits(vec![0]), 0);
+    fn with_digit_replaced_works() {
+        let digits = Digits::from(56_003u32);
+        let family: Vec<u32> = (0..10)
+            .map(|d| digits.with_digit_replaced(2, d).unwrap().to_int().unwrap())
+            .collect();
+        assert_eq!(family[0], 56_003);
+        assert_eq!(family[9], 56_903);
+        assert_eq!(digits.to_int(), Ok(56_003));
+        assert!(digits.with_digit_replaced(5, 1).is_err());
+        assert!(digits.with_digit_replaced(0, 10).is_err());
     }
 
     #[test]
-    fn from_digits_works_with_u8_max() {
-        assert_eq!(int_from_digits(vec![2, 5, 5]), u8::MAX);
+    fn insert_digit_works() {
+        let mut digits = Digits::from(13u32);
+        digits.insert_digit(1, 2).unwrap();
+        assert_eq!(digits.to_int(), Ok(123));
+        digits.insert_digit(3, 4).unwrap();
+        assert_eq!(digits.to_int(), Ok(1_234));
+        digits.insert_digit(0, 0).unwrap();
+        assert_eq!(digits.as_slice(), vec![0, 1, 2, 3, 4]);
+
+        let mut digits = Digits::from(0u32);
+        digits.insert_digit(0, 7).unwrap();
+        assert_eq!(digits.sign(), Sign::Positive);
+        assert_eq!(digits.to_int(), Ok(70));
     }
 
     #[test]
-    fn from_digits_works_with_u16_max() {
-        assert_eq!(int_from_digits(vec![6, 5, 5, 3, 5]), u16::MAX);
+    fn insert_digit_throws_error_with_invalid_arguments() {
+        let mut digits = Digits::from(12u32);
+        assert_eq!(
+            digits.insert_digit(3, 1),
+            Err(ConversionError::IndexOutOfRange { index: 3, len: 2 })
+        );
+        assert_eq!(
+            digits.insert_digit(1, 10),
+            Err(ConversionError::InvalidDigit {
+                position: 1,
+                value: 10
+            })
+        );
+        assert_eq!(digits.to_int(), Ok(12));
     }
 
     #[test]
-    fn from_digits_works_with_u32_max() {
+    fn remove_digit_works() {
+        let mut digits = Digits::try_from(-1_203).unwrap();
+        assert_eq!(digits.remove_digit(0), Ok(1));
+        assert_eq!(digits.to_int(), Ok(-203));
+        assert_eq!(digits.remove_digit(2), Ok(3));
+        assert_eq!(digits.remove_digit(0), Ok(2));
+        assert_eq!(digits.sign(), Sign::Zero);
+        assert_eq!(digits.to_int(), Ok(0));
+    }
+
+    #[test]
+    fn remove_digit_throws_error_with_last_digit() {
+        let mut digits = Digits::from(7u32);
+        assert_eq!(digits.remove_digit(0), Err(ConversionError::Empty));
         assert_eq!(
-            int_from_digits(vec![4, 2, 9, 4, 9, 6, 7, 2, 9, 5]),
-            u32::MAX
+            digits.remove_digit(1),
+            Err(ConversionError::IndexOutOfRange { index: 1, len: 1 })
         );
+        assert_eq!(digits.to_int(), Ok(7));
     }
 
     #[test]
-    fn from_digits_works_with_u64_max() {
+    fn swap_digits_works() {
+        let mut digits = Digits::from(1_234u32);
+        digits.swap_digits(0, 3).unwrap();
+        assert_eq!(digits.to_int(), Ok(4_231));
+        digits.swap_digits(1, 1).unwrap();
+        assert_eq!(digits.to_int(), Ok(4_231));
         assert_eq!(
-            int_from_digits(vec![
-                1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5
-            ]),
-            u64::MAX
+            digits.swap_digits(0, 4),
+            Err(ConversionError::IndexOutOfRange { index: 4, len: 4 })
+        );
+    }
+
+    #[test]
+    fn map_digits_works() {
+        let digits = Digits::from(1_234u32);
+        let complement = digits.map_digits(|d| 9 - d).unwrap();
+        assert_eq!(complement.to_int(), Ok(8_765));
+        let shifted = digits.map_digits(|d| (d + 3) % 10).unwrap();
+        assert_eq!(shifted.to_int(), Ok(4_567));
+        assert_eq!(digits.to_int(), Ok(1_234));
+    }
+
+    #[test]
+    fn map_digits_works_with_sign_and_leading_zeros() {
+        let digits = Digits::try_from(-909).unwrap();
+        let complement = digits.map_digits(|d| 9 - d).unwrap();
+        assert_eq!(complement.as_slice(), vec![0, 9, 0]);
+        assert_eq!(complement.to_int(), Ok(-90));
+        let zero = Digits::from(99u32).map_digits(|_| 0).unwrap();
+        assert_eq!(zero.sign(), Sign::Zero);
+        let hex = Digits::with_radix(0x1a2u32, 16).unwrap();
+        assert_eq!(hex.map_digits(|d| 15 - d).unwrap().to_int(), Ok(0xe5d));
+    }
+
+    #[test]
+    fn map_digits_throws_error_with_digit_out_of_range() {
+        assert_eq!(
+            Digits::from(1_234u32)
+                .map_digits(|d| d * 3)
+                .map(|d| d.to_int()),
+            Err(ConversionError::InvalidDigit {
+                position: 3,
+                value: 12
+            })
         );
+        assert!(Digits::try_from(5).unwrap().map_digits(|d| d - 6).is_err());
     }
 }
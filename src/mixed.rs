@@ -0,0 +1,225 @@
+use alloc::vec::Vec;
+
+use num_traits::{Num, NumCast};
+
+use crate::{digits_from_int, from_mixed_radix, to_mixed_radix};
+use crate::{ConversionError, DigitVec, Digits, Sign};
+
+// The base of mixed radix position `i` from the right, with `above` for the
+// positions left of the given radices.
+fn base(radices: &[u32], above: u64) -> impl Fn(usize) -> u64 + '_ {
+    move |i| match radices.len().checked_sub(i + 1) {
+        Some(j) => radices[j] as u64,
+        None => above,
+    }
+}
+
+fn check_radices(radices: &[u32]) -> Result<(), ConversionError> {
+    match radices.iter().find(|&&r| r < 2) {
+        Some(&radix) => Err(ConversionError::InvalidRadix {
+            radix: radix as i64,
+        }),
+        None => Ok(()),
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// The number in a mixed radix system, most significant first, with a
+    /// digit for each of `radices` and one unbounded digit in front. A
+    /// duration of 95400 seconds with `[24, 60, 60]` is `[1, 2, 30, 0]`,
+    /// one day, two hours and thirty minutes.
+    pub fn to_mixed_radix(&self, radices: &[u32]) -> Result<Vec<T>, ConversionError> {
+        check_radices(radices)?;
+        if self.sign == Sign::Negative {
+            return Err(ConversionError::NegativeInput);
+        }
+        let values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        let radix = self.radix as u64;
+        let mut mixed = to_mixed_radix(&values, radix, base(radices, radix));
+        // Below the top the positions match the radices; above they are
+        // plain digits in the original radix, which make up the front digit.
+        let top = mixed.len().saturating_sub(radices.len());
+        let front: Vec<u64> = mixed.drain(..top).collect();
+        let front = front
+            .iter()
+            .try_fold(0u128, |acc, &d| {
+                acc.checked_mul(radix as u128)?.checked_add(d as u128)
+            })
+            .and_then(T::from)
+            .ok_or_else(|| {
+                ConversionError::overflow(to_mixed_radix(&front, radix, |_| 2).len() as u32)
+            })?;
+
+        let mut result = Vec::with_capacity(radices.len() + 1);
+        result.push(front);
+        result.extend(core::iter::repeat_n(T::zero(), radices.len() - mixed.len()));
+        for d in mixed {
+            result.push(
+                T::from(d)
+                    .ok_or_else(|| ConversionError::overflow(u64::BITS - d.leading_zeros()))?,
+            );
+        }
+        Ok(result)
+    }
+
+    /// Builds the decimal digits of a number given in a mixed radix system,
+    /// the inverse of `to_mixed_radix`, e.g. `[1, 2, 30, 0]` with
+    /// `[24, 60, 60]` is 95400.
+    pub fn from_mixed_radix(values: &[T], radices: &[u32]) -> Result<Self, ConversionError> {
+        check_radices(radices)?;
+        if values.len() != radices.len() + 1 {
+            return Err(ConversionError::InvalidLength {
+                expected: radices.len() + 1,
+                found: values.len(),
+            });
+        }
+        let mut mixed = digits_from_int(values[0])
+            .map_err(|_| ConversionError::invalid_digit(0, values[0]))?
+            .into_iter()
+            .map(|d| d.to_u64().unwrap())
+            .collect::<Vec<_>>();
+        for (i, (&d, &radix)) in values[1..].iter().zip(radices).enumerate() {
+            match d.to_u64() {
+                Some(d) if d < radix as u64 => mixed.push(d),
+                _ => return Err(ConversionError::invalid_digit(i + 1, d)),
+            }
+        }
+
+        // Decimal digits fit every `T`.
+        let values: DigitVec<T> = from_mixed_radix(&mixed, 10, base(radices, 10))
+            .into_iter()
+            .map(|d| T::from(d).unwrap())
+            .collect();
+        Ok(Self::new(values, 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const DURATION: [u32; 3] = [24, 60, 60];
+
+    #[test]
+    fn to_mixed_radix_works() {
+        let digits = Digits::from(95_400u32);
+        assert_eq!(digits.to_mixed_radix(&DURATION), Ok(vec![1, 2, 30, 0]));
+        assert_eq!(
+            Digits::from(59u32).to_mixed_radix(&DURATION),
+            Ok(vec![0, 0, 0, 59])
+        );
+        assert_eq!(
+            Digits::from(0u32).to_mixed_radix(&DURATION),
+            Ok(vec![0, 0, 0, 0])
+        );
+        assert_eq!(Digits::from(450u32).to_mixed_radix(&[]), Ok(vec![450]));
+        assert_eq!(
+            Digits::from(u64::MAX).to_mixed_radix(&DURATION),
+            Ok(vec![u64::MAX / 86_400, 7, 0, 15])
+        );
+    }
+
+    #[test]
+    fn to_mixed_radix_works_with_radix() {
+        let digits = Digits::with_radix(95_400u32, 16).unwrap();
+        assert_eq!(digits.to_mixed_radix(&DURATION), Ok(vec![1, 2, 30, 0]));
+        // Imperial length: 100 inches is 2 yards, 2 feet and 4 inches.
+        let digits = Digits::with_radix(100u32, 2).unwrap();
+        assert_eq!(digits.to_mixed_radix(&[3, 12]), Ok(vec![2, 2, 4]));
+    }
+
+    #[test]
+    fn to_mixed_radix_works_beyond_integer_range() {
+        // 10^40 seconds do not fit a u128, but the days do.
+        let digits: Digits<u128> = alloc::format!("1{}", "0".repeat(40)).parse().unwrap();
+        assert!(digits.to_int().is_err());
+        let mixed = digits.to_mixed_radix(&DURATION).unwrap();
+        assert_eq!(mixed[0], 115_740_740_740_740_740_740_740_740_740_740_740);
+        assert_eq!(mixed[1..], [17, 46, 40]);
+    }
+
+    #[test]
+    fn to_mixed_radix_throws_error_with_invalid_arguments() {
+        let digits = Digits::from(100u32);
+        assert_eq!(
+            digits.to_mixed_radix(&[60, 1]),
+            Err(ConversionError::InvalidRadix { radix: 1 })
+        );
+        assert_eq!(
+            Digits::try_from(-1).unwrap().to_mixed_radix(&DURATION),
+            Err(ConversionError::NegativeInput)
+        );
+    }
+
+    #[test]
+    fn to_mixed_radix_throws_error_on_overflow() {
+        let digits = Digits::<u8>::from_radix(vec![1, 0, 0, 0], 10).unwrap();
+        assert_eq!(
+            digits.to_mixed_radix(&[2]),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(9)
+            })
+        );
+        // The front digit fits, but 30000 does not.
+        let digits: Digits<u8> = "100000".parse().unwrap();
+        assert_eq!(
+            digits.to_mixed_radix(&[70_000]),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(15)
+            })
+        );
+        assert_eq!(
+            Digits::<u32>::from(100_000u32).to_mixed_radix(&[70_000]),
+            Ok(vec![1, 30_000])
+        );
+    }
+
+    #[test]
+    fn from_mixed_radix_works() {
+        let digits = Digits::from_mixed_radix(&[1u32, 2, 30, 0], &DURATION).unwrap();
+        assert_eq!(digits.to_int(), Ok(95_400));
+        let digits = Digits::from_mixed_radix(&[0u32, 0, 0, 0], &DURATION).unwrap();
+        assert_eq!(digits.to_int(), Ok(0));
+        let digits = Digits::from_mixed_radix(&[u128::MAX, 0], &[2]).unwrap();
+        assert_eq!(digits.len(), 39);
+        assert!(digits.to_int().is_err());
+    }
+
+    #[test]
+    fn from_mixed_radix_round_trips() {
+        for n in (0u64..200_000).step_by(7) {
+            let mixed = Digits::from(n).to_mixed_radix(&DURATION).unwrap();
+            let digits = Digits::from_mixed_radix(&mixed, &DURATION).unwrap();
+            assert_eq!(digits.to_int(), Ok(n));
+        }
+    }
+
+    #[test]
+    fn from_mixed_radix_throws_error_with_invalid_digits() {
+        assert_eq!(
+            Digits::from_mixed_radix(&[1u32, 24, 0, 0], &DURATION).map(|d| d.to_int()),
+            Err(ConversionError::InvalidDigit {
+                position: 1,
+                value: 24
+            })
+        );
+        assert_eq!(
+            Digits::from_mixed_radix(&[-1i32, 0, 0, 0], &DURATION).map(|d| d.to_int()),
+            Err(ConversionError::InvalidDigit {
+                position: 0,
+                value: -1
+            })
+        );
+        assert_eq!(
+            Digits::from_mixed_radix(&[1u32, 2], &DURATION).map(|d| d.to_int()),
+            Err(ConversionError::InvalidLength {
+                expected: 4,
+                found: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use num_traits::{Num, NumCast};
+
+use crate::ConversionError;
+
+/// The digits of a number in a negative base such as -2 (negabinary) or -10
+/// (negadecimal), most significant first. Every integer, negative or not,
+/// has a unique representation with digits in `0..|radix|` and no sign.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NegativeBase<T> {
+    values: Vec<T>,
+    radix: i32,
+}
+
+fn check_radix(radix: i32) -> Result<i128, ConversionError> {
+    if !(-36..=-2).contains(&radix) {
+        return Err(ConversionError::InvalidRadix {
+            radix: radix as i64,
+        });
+    }
+    Ok(radix as i128)
+}
+
+impl<T> NegativeBase<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn from_int(n: T, radix: i32) -> Result<Self, ConversionError> {
+        let base = check_radix(radix)?;
+        let mut values = Vec::new();
+        let mut n = match n.to_i128() {
+            Some(n) => n,
+            // A u128 above i128::MAX takes its last digit in the unsigned
+            // range, after which the quotient is negative and fits.
+            None => {
+                let (n, b) = (n.to_u128().unwrap(), base.unsigned_abs());
+                values.push(T::from(n % b).unwrap());
+                -((n / b) as i128)
+            }
+        };
+
+        loop {
+            // Rust's remainder takes the sign of the dividend, so shift a
+            // negative remainder into 0..|radix| and compensate the quotient.
+            let (mut q, mut r) = (n / base, n % base);
+            if r < 0 {
+                r -= base;
+                q += 1;
+            }
+            values.push(T::from(r).unwrap());
+            n = q;
+            if n == 0 {
+                break;
+            }
+        }
+        values.reverse();
+        Ok(Self { values, radix })
+    }
+
+    pub fn from_digits(values: Vec<T>, radix: i32) -> Result<Self, ConversionError> {
+        let base = check_radix(radix)?;
+        if values.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        if let Some(position) = values
+            .iter()
+            .position(|d| !matches!(d.to_i128(), Some(d) if (0..-base).contains(&d)))
+        {
+            return Err(ConversionError::invalid_digit(position, values[position]));
+        }
+        Ok(Self { values, radix })
+    }
+
+    pub fn to_int(&self) -> Result<T, ConversionError> {
+        let overflow = || ConversionError::Overflow { needed_bits: None };
+        let base = self.radix as i128;
+        let (last, rest) = self.values.split_last().unwrap();
+        let high = rest.iter().try_fold(0i128, |acc, d| {
+            acc.checked_mul(base)
+                .and_then(|acc| acc.checked_add(d.to_i128().unwrap()))
+                .ok_or_else(overflow)
+        })?;
+
+        // Without its last digit any value from i128::MIN to u128::MAX still
+        // fits an i128, so only the final step is done on the magnitudes.
+        let last = last.to_u128().unwrap();
+        let product = high
+            .unsigned_abs()
+            .checked_mul(base.unsigned_abs())
+            .ok_or_else(overflow)?;
+        let n = if high < 0 {
+            product.checked_add(last).and_then(T::from)
+        } else if product > last {
+            0i128.checked_sub_unsigned(product - last).and_then(T::from)
+        } else {
+            T::from(last - product)
+        };
+        n.ok_or_else(overflow)
+    }
+}
+
+impl<T> NegativeBase<T> {
+    pub fn digits(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn radix(&self) -> i32 {
+        self.radix
+    }
+}
+
+impl<T> fmt::Display for NegativeBase<T>
+where
+    T: NumCast + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let radix = self.radix.unsigned_abs();
+        let digits: String = self
+            .values
+            .iter()
+            .map(|d| char::from_digit(d.to_u32().unwrap(), radix).unwrap())
+            .collect();
+        f.pad(&digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn from_int_works_with_negabinary() {
+        let expected = [
+            (0i32, "0"),
+            (1, "1"),
+            (2, "110"),
+            (3, "111"),
+            (4, "100"),
+            (-1, "11"),
+            (-2, "10"),
+            (-3, "1101"),
+        ];
+        for (n, s) in expected {
+            assert_eq!(NegativeBase::from_int(n, -2).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn from_int_works_with_negadecimal() {
+        let digits = NegativeBase::from_int(15i32, -10).unwrap();
+        assert_eq!(digits.digits(), [1, 9, 5]);
+        let digits = NegativeBase::from_int(-15i32, -10).unwrap();
+        assert_eq!(digits.digits(), [2, 5]);
+        assert_eq!(digits.radix(), -10);
+    }
+
+    #[test]
+    fn from_int_works_with_unsigned_types() {
+        let digits = NegativeBase::from_int(6u8, -2).unwrap();
+        assert_eq!(digits.to_string(), "11010");
+        assert_eq!(digits.to_int(), Ok(6));
+    }
+
+    #[test]
+    fn from_int_works_with_128_bit_types() {
+        for radix in -36..=-2 {
+            for n in [u128::MAX, u128::MAX - 1, i128::MAX as u128 + 1, 0] {
+                let digits = NegativeBase::from_int(n, radix).unwrap();
+                assert_eq!(digits.to_int(), Ok(n));
+            }
+            for n in [i128::MIN, i128::MAX, -1] {
+                let digits = NegativeBase::from_int(n, radix).unwrap();
+                assert_eq!(digits.to_int(), Ok(n));
+            }
+        }
+    }
+
+    #[test]
+    fn from_int_throws_error_with_invalid_radix() {
+        for radix in [-1, 0, 2, -37] {
+            assert_eq!(
+                NegativeBase::from_int(5i32, radix),
+                Err(ConversionError::InvalidRadix {
+                    radix: radix as i64
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn to_int_round_trips() {
+        for radix in [-2, -3, -10, -16, -36] {
+            for n in -1_000i32..1_000 {
+                assert_eq!(NegativeBase::from_int(n, radix).unwrap().to_int(), Ok(n));
+            }
+        }
+        for n in [i64::MIN, i64::MAX] {
+            assert_eq!(NegativeBase::from_int(n, -2).unwrap().to_int(), Ok(n));
+        }
+    }
+
+    #[test]
+    fn to_int_throws_error_with_overflow() {
+        // 1101 in negabinary is -3, which u8 cannot hold.
+        let digits = NegativeBase::from_digits(vec![1u8, 1, 0, 1], -2).unwrap();
+        assert_eq!(
+            digits.to_int(),
+            Err(ConversionError::Overflow { needed_bits: None })
+        );
+    }
+
+    #[test]
+    fn from_digits_throws_error_with_digit_out_of_range() {
+        assert_eq!(
+            NegativeBase::from_digits(vec![1u8, 2], -2),
+            Err(ConversionError::InvalidDigit {
+                position: 1,
+                value: 2
+            })
+        );
+        assert!(NegativeBase::from_digits(vec![-1i8], -2).is_err());
+        assert!(NegativeBase::<u8>::from_digits(vec![], -2).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::slice;
+
+use num_traits::{Num, NumCast};
+
+use crate::{ConversionError, Digits};
+
+/// The order digits are read in. `Digits` always stores the most significant
+/// digit first; views read it either way without copying.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Order {
+    #[default]
+    MostSignificantFirst,
+    LeastSignificantFirst,
+}
+
+impl Order {
+    pub fn reversed(self) -> Order {
+        match self {
+            Order::MostSignificantFirst => Order::LeastSignificantFirst,
+            Order::LeastSignificantFirst => Order::MostSignificantFirst,
+        }
+    }
+}
+
+/// A borrowed view of the digits in a chosen order. Indexing and iteration
+/// follow the view's order, so in a least significant first view index `i`
+/// is the digit worth `radix^i`.
+#[derive(Clone, Copy, Debug)]
+pub struct DigitsView<'a, T> {
+    values: &'a [T],
+    order: Order,
+}
+
+impl<'a, T> DigitsView<'a, T> {
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match self.order {
+            Order::MostSignificantFirst => self.values.get(index),
+            Order::LeastSignificantFirst => {
+                let i = self.values.len().checked_sub(index)?.checked_sub(1)?;
+                Some(&self.values[i])
+            }
+        }
+    }
+
+    /// The same digits in the opposite order.
+    pub fn reversed(&self) -> DigitsView<'a, T> {
+        DigitsView {
+            values: self.values,
+            order: self.order.reversed(),
+        }
+    }
+
+    pub fn iter(&self) -> OrderedIter<'a, T> {
+        OrderedIter {
+            inner: self.values.iter(),
+            order: self.order,
+        }
+    }
+}
+
+impl<T: Clone> DigitsView<'_, T> {
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<'a, T> IntoIterator for DigitsView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = OrderedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OrderedIter<'a, T> {
+    inner: slice::Iter<'a, T>,
+    order: Order,
+}
+
+impl<'a, T> Iterator for OrderedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.order {
+            Order::MostSignificantFirst => self.inner.next(),
+            Order::LeastSignificantFirst => self.inner.next_back(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for OrderedIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.order {
+            Order::MostSignificantFirst => self.inner.next_back(),
+            Order::LeastSignificantFirst => self.inner.next(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for OrderedIter<'_, T> {}
+
+impl<T> FusedIterator for OrderedIter<'_, T> {}
+
+impl<T> Digits<T> {
+    pub fn view(&self, order: Order) -> DigitsView<'_, T> {
+        DigitsView {
+            values: &self.values,
+            order,
+        }
+    }
+
+    pub fn big_endian(&self) -> DigitsView<'_, T> {
+        self.view(Order::MostSignificantFirst)
+    }
+
+    /// The digits least significant first, as schoolbook arithmetic and
+    /// place-value sums want them.
+    pub fn little_endian(&self) -> DigitsView<'_, T> {
+        self.view(Order::LeastSignificantFirst)
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// Like `from_radix`, with the digits given in the stated order.
+    pub fn from_radix_in(
+        mut values: Vec<T>,
+        radix: u32,
+        order: Order,
+    ) -> Result<Self, ConversionError> {
+        if order == Order::LeastSignificantFirst {
+            values.reverse();
+        }
+        Self::from_radix(values, radix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn little_endian_works() {
+        let digits = Digits::from(1_234u32);
+        let view = digits.little_endian();
+        assert_eq!(view.order(), Order::LeastSignificantFirst);
+        assert_eq!(view.len(), 4);
+        assert_eq!(view.to_vec(), vec![4, 3, 2, 1]);
+        assert_eq!(view.get(0), Some(&4));
+        assert_eq!(view.get(3), Some(&1));
+        assert_eq!(view.get(4), None);
+        assert_eq!(view.get(usize::MAX), None);
+        assert_eq!(
+            view.iter().rev().copied().collect::<Vec<_>>(),
+            digits.as_slice()
+        );
+    }
+
+    #[test]
+    fn big_endian_works() {
+        let digits = Digits::from(1_234u32);
+        let view = digits.big_endian();
+        assert_eq!(view.to_vec(), digits.as_slice());
+        assert_eq!(view.get(0), Some(&1));
+        assert_eq!(view.get(4), None);
+        assert_eq!(view.iter().len(), 4);
+    }
+
+    #[test]
+    fn reversed_works() {
+        let digits = Digits::from(1_234u32);
+        let view = digits.big_endian().reversed();
+        assert_eq!(view.order(), Order::LeastSignificantFirst);
+        assert_eq!(view.to_vec(), digits.little_endian().to_vec());
+        assert_eq!(view.reversed().to_vec(), digits.as_slice());
+    }
+
+    #[test]
+    fn little_endian_works_with_place_values() {
+        let digits = Digits::with_radix(0xbeefu32, 16).unwrap();
+        let sum = digits
+            .little_endian()
+            .into_iter()
+            .enumerate()
+            .map(|(i, &d)| d * 16u32.pow(i as u32))
+            .sum::<u32>();
+        assert_eq!(sum, 0xbeef);
+    }
+
+    #[test]
+    fn from_radix_in_works() {
+        let digits = Digits::from_radix_in(vec![4u32, 3, 2, 1], 10, Order::LeastSignificantFirst);
+        assert_eq!(digits.unwrap().to_int(), Ok(1_234));
+        let digits = Digits::from_radix_in(vec![1u32, 2], 10, Order::MostSignificantFirst);
+        assert_eq!(digits.unwrap().to_int(), Ok(12));
+        assert!(Digits::from_radix_in(vec![2u32], 2, Order::LeastSignificantFirst).is_err());
+    }
+}
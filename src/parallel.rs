@@ -0,0 +1,182 @@
+//! Parallel versions of the operations that scan every digit, for sequences
+//! with millions of digits. Short sequences are not worth the overhead, so
+//! the sequential methods remain the default.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use num_traits::{CheckedAdd, Num, NumCast};
+use rayon::prelude::*;
+
+use crate::{
+    long_add, long_mul, radix_constant, strip_leading_zeros, sum_overflow_error, to_mixed_radix,
+    ConversionError, DigitVec, Digits, Sign,
+};
+
+// Below this many digits a conversion is done by plain short division.
+const CONVERSION_CUTOFF: usize = 512;
+
+// `base^exp` as digits in `radix`, by repeated squaring.
+fn pow_in_radix(base: u64, mut exp: usize, radix: u64) -> Vec<u64> {
+    let mut result = vec![1];
+    let mut square = Vec::new();
+    let mut b = base;
+    while b > 0 {
+        square.insert(0, b % radix);
+        b /= radix;
+    }
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = long_mul(&result, &square, radix);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            square = long_mul(&square, &square, radix);
+        }
+    }
+    result
+}
+
+// Converts by splitting the digits in two, converting both halves in
+// parallel and recombining them as high * from^len(low) + low.
+fn convert(values: &[u64], from: u64, to: u64) -> Vec<u64> {
+    if values.len() <= CONVERSION_CUTOFF {
+        return to_mixed_radix(values, from, |_| to);
+    }
+    let (high, low) = values.split_at(values.len() / 2);
+    let ((high, low), shift) = rayon::join(
+        || rayon::join(|| convert(high, from, to), || convert(low, from, to)),
+        || pow_in_radix(from, low.len(), to),
+    );
+    let mut result = long_add(&long_mul(&high, &shift, to), &low, to);
+    strip_leading_zeros(&mut result);
+    result
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + Send + Sync,
+{
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        self.values.par_iter()
+    }
+
+    /// Like `sum`, with the digits split across threads.
+    pub fn par_sum(&self) -> Result<T, ConversionError>
+    where
+        T: CheckedAdd,
+    {
+        let overflow = || sum_overflow_error(&self.values);
+        let add = |a: T, b: T| a.checked_add(&b).ok_or_else(overflow);
+        self.values
+            .par_iter()
+            .try_fold(T::zero, |acc, &d| add(acc, d))
+            .try_reduce(T::zero, add)
+    }
+
+    /// Like `counts`, with the digits split across threads.
+    pub fn par_counts(&self) -> Vec<usize> {
+        let radix = self.radix as usize;
+        self.values
+            .par_iter()
+            .fold(
+                || vec![0; radix],
+                |mut counts, d| {
+                    counts[d.to_usize().unwrap()] += 1;
+                    counts
+                },
+            )
+            .reduce(
+                || vec![0; radix],
+                |mut a, b| {
+                    a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                    a
+                },
+            )
+    }
+
+    /// Like `convert_radix`, with long sequences split in half recursively
+    /// and the halves converted in parallel.
+    pub fn par_convert_radix(&self, radix: u32) -> Result<Digits<T>, ConversionError> {
+        radix_constant::<T>(radix)?;
+        let values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        let converted: DigitVec<T> = convert(&values, self.radix as u64, radix as u64)
+            .into_iter()
+            .map(|d| T::from(d).unwrap())
+            .collect();
+        Ok(Digits::new_signed(
+            converted,
+            radix,
+            self.sign == Sign::Negative,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn par_iter_works() {
+        let digits = Digits::from(1_234u32);
+        let doubled: Vec<u32> = digits.par_iter().map(|d| d * 2).collect();
+        assert_eq!(doubled, vec![2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn par_sum_works() {
+        let factorial = crate::factorial_digits(1_000);
+        assert_eq!(factorial.par_sum(), factorial.sum());
+        assert_eq!(factorial.par_sum(), Ok(10_539));
+    }
+
+    #[test]
+    fn par_sum_throws_error_with_overflow() {
+        let digits = Digits::<u8>::from_radix(vec![9; 100], 10).unwrap();
+        assert_eq!(
+            digits.par_sum(),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(10)
+            })
+        );
+    }
+
+    #[test]
+    fn par_counts_works() {
+        let factorial = crate::factorial_digits(1_000);
+        assert_eq!(factorial.par_counts(), factorial.counts());
+        let digits = Digits::with_radix(0xbeefu32, 16).unwrap();
+        assert_eq!(digits.par_counts(), digits.counts());
+    }
+
+    #[test]
+    fn par_convert_radix_works() {
+        let digits = Digits::from(255u32).par_convert_radix(16).unwrap();
+        assert_eq!(digits.to_string(), "ff");
+        let digits = Digits::with_radix(-255i32, 2).unwrap();
+        assert_eq!(digits.par_convert_radix(10).unwrap().to_int(), Ok(-255));
+        assert_eq!(
+            Digits::from(0u32).par_convert_radix(7).unwrap().to_string(),
+            "0"
+        );
+    }
+
+    #[test]
+    fn par_convert_radix_works_with_long_sequences() {
+        let factorial = crate::factorial_digits(1_000);
+        let values: Vec<u64> = factorial.iter().copied().collect();
+        for radix in [2, 7, 16, 36] {
+            let expected = to_mixed_radix(&values, 10, |_| radix as u64);
+            let converted = factorial.par_convert_radix(radix).unwrap();
+            assert_eq!(converted.as_slice(), expected);
+            let back = converted.par_convert_radix(10).unwrap();
+            assert_eq!(back.as_slice(), factorial.as_slice());
+        }
+    }
+
+    #[test]
+    fn par_convert_radix_throws_error_with_invalid_radix() {
+        assert!(Digits::from(12u32).par_convert_radix(1).is_err());
+    }
+}
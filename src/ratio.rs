@@ -0,0 +1,276 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use num_traits::{Num, NumCast};
+
+use crate::{ConversionError, Digits, LazyDigits};
+
+/// The decimal expansion of a ratio: the integer part, the fractional digits
+/// before the repeating part, and the repeating cycle, which is empty when
+/// the expansion terminates.
+#[derive(Clone)]
+pub struct RatioDigits<T> {
+    integer: Digits<T>,
+    prefix: Vec<T>,
+    cycle: Vec<T>,
+    negative: bool,
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// Expands `numerator / denominator` by long division, e.g. 1/6 gives
+    /// prefix `1` and cycle `6`.
+    ///
+    /// The cycle is built in full, in time and memory linear in its length,
+    /// which can be up to `denominator - 1` digits. Past
+    /// `RatioDigits::MAX_CYCLE_LEN` digits this gives up with
+    /// `CycleTooLong`; `from_ratio_with_max_cycle` sets another limit.
+    pub fn from_ratio(numerator: T, denominator: T) -> Result<RatioDigits<T>, ConversionError> {
+        Self::from_ratio_with_max_cycle(numerator, denominator, RatioDigits::<T>::MAX_CYCLE_LEN)
+    }
+
+    /// Like `from_ratio`, but with a cycle of at most `max_cycle_len` digits.
+    pub fn from_ratio_with_max_cycle(
+        numerator: T,
+        denominator: T,
+        max_cycle_len: usize,
+    ) -> Result<RatioDigits<T>, ConversionError> {
+        if denominator.is_zero() {
+            return Err(ConversionError::DivisionByZero);
+        }
+        let (n, d) = (magnitude(numerator), magnitude(denominator));
+        if d > u128::MAX / 10 {
+            return Err(ConversionError::Overflow {
+                needed_bits: Some(u128::BITS - d.leading_zeros()),
+            });
+        }
+
+        // The prefix is as long as the larger power of 2 or 5 in the reduced
+        // denominator, after which the remainders repeat with a pure cycle.
+        let reduced = d / gcd(n % d, d);
+        let (twos, rest) = factor_out(reduced, 2);
+        let (fives, _) = factor_out(rest, 5);
+
+        let mut remainder = n % d;
+        let next_digit = |remainder: &mut u128| {
+            *remainder *= 10;
+            let digit = T::from(*remainder / d).unwrap();
+            *remainder %= d;
+            digit
+        };
+        let prefix: Vec<T> = (0..twos.max(fives))
+            .map(|_| next_digit(&mut remainder))
+            .collect();
+
+        let mut cycle = Vec::new();
+        if remainder != 0 {
+            let start = remainder;
+            loop {
+                if cycle.len() == max_cycle_len {
+                    return Err(ConversionError::CycleTooLong {
+                        max_len: max_cycle_len,
+                    });
+                }
+                cycle.push(next_digit(&mut remainder));
+                if remainder == start {
+                    break;
+                }
+            }
+        }
+
+        let integer: Vec<T> = LazyDigits::new(n / d, 10)
+            .map(|d| T::from(d).unwrap())
+            .collect();
+        let negative = (numerator < T::zero()) != (denominator < T::zero()) && n != 0;
+        Ok(RatioDigits {
+            integer: Digits::new_signed(integer, 10, negative),
+            prefix,
+            cycle,
+            negative,
+        })
+    }
+}
+
+impl<T> RatioDigits<T> {
+    /// The longest cycle `from_ratio` builds.
+    pub const MAX_CYCLE_LEN: usize = 1 << 20;
+
+    pub fn integer(&self) -> &Digits<T> {
+        &self.integer
+    }
+
+    /// The fractional digits before the repeating cycle.
+    pub fn prefix(&self) -> &[T] {
+        &self.prefix
+    }
+
+    pub fn cycle(&self) -> &[T] {
+        &self.cycle
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+}
+
+/// Writes the expansion with the cycle in parentheses, e.g. `0.1(6)`.
+impl<T> fmt::Display for RatioDigits<T>
+where
+    T: NumCast + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s: String = self.integer.chars().collect();
+        let digit = |d: &T| char::from_digit(d.to_u32().unwrap(), 10).unwrap();
+        if !self.prefix.is_empty() || !self.cycle.is_empty() {
+            s.push('.');
+            s.extend(self.prefix.iter().map(digit));
+        }
+        if !self.cycle.is_empty() {
+            s.push('(');
+            s.extend(self.cycle.iter().map(digit));
+            s.push(')');
+        }
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+fn magnitude<T: NumCast>(x: T) -> u128 {
+    x.to_i128()
+        .map(i128::unsigned_abs)
+        .or_else(|| x.to_u128())
+        .unwrap()
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn factor_out(mut n: u128, p: u128) -> (usize, u128) {
+    let mut count = 0;
+    while n.is_multiple_of(p) {
+        n /= p;
+        count += 1;
+    }
+    (count, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn from_ratio_works() {
+        let expansion = Digits::from_ratio(1u32, 7).unwrap();
+        assert_eq!(expansion.integer().as_slice(), [0]);
+        assert!(expansion.prefix().is_empty());
+        assert_eq!(expansion.cycle(), [1, 4, 2, 8, 5, 7]);
+        assert_eq!(expansion.to_string(), "0.(142857)");
+    }
+
+    #[test]
+    fn from_ratio_works_with_prefix() {
+        let expansion = Digits::from_ratio(1u32, 6).unwrap();
+        assert_eq!(expansion.prefix(), [1]);
+        assert_eq!(expansion.cycle(), [6]);
+        assert_eq!(Digits::from_ratio(7u32, 12).unwrap().to_string(), "0.58(3)");
+        assert_eq!(Digits::from_ratio(1u32, 3).unwrap().to_string(), "0.(3)");
+    }
+
+    #[test]
+    fn from_ratio_works_with_terminating_expansion() {
+        let expansion = Digits::from_ratio(13u32, 8).unwrap();
+        assert_eq!(expansion.integer().as_slice(), [1]);
+        assert_eq!(expansion.prefix(), [6, 2, 5]);
+        assert!(expansion.cycle().is_empty());
+        assert_eq!(expansion.to_string(), "1.625");
+        assert_eq!(Digits::from_ratio(42u32, 6).unwrap().to_string(), "7");
+        assert_eq!(Digits::from_ratio(0u32, 6).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn from_ratio_works_with_unreduced_ratio() {
+        assert_eq!(
+            Digits::from_ratio(2u32, 14).unwrap().to_string(),
+            "0.(142857)"
+        );
+        assert_eq!(
+            Digits::from_ratio(22u32, 7).unwrap().to_string(),
+            "3.(142857)"
+        );
+    }
+
+    #[test]
+    fn from_ratio_works_with_negative_numbers() {
+        let expansion = Digits::from_ratio(-1i32, 6).unwrap();
+        assert!(expansion.is_negative());
+        assert_eq!(expansion.to_string(), "-0.1(6)");
+        assert_eq!(Digits::from_ratio(-7i32, -2).unwrap().to_string(), "3.5");
+        assert_eq!(
+            Digits::from_ratio(i32::MIN, 1).unwrap().to_string(),
+            "-2147483648"
+        );
+    }
+
+    #[test]
+    fn from_ratio_finds_longest_recurring_cycle() {
+        let longest = (2u32..1_000)
+            .max_by_key(|&d| Digits::from_ratio(1, d).unwrap().cycle().len())
+            .unwrap();
+        assert_eq!(longest, 983);
+        assert_eq!(Digits::from_ratio(1u32, 983).unwrap().cycle().len(), 982);
+    }
+
+    #[test]
+    fn from_ratio_works_with_wide_types() {
+        let expansion = Digits::from_ratio(1u64, 9_999_999_999).unwrap();
+        assert_eq!(expansion.to_string(), "0.(0000000001)");
+        let expansion = Digits::from_ratio(3u128, 10u128.pow(30)).unwrap();
+        assert_eq!(expansion.prefix().len(), 30);
+        assert_eq!(expansion.prefix()[29], 3);
+    }
+
+    #[test]
+    fn from_ratio_throws_error_with_long_cycle() {
+        // 1/1000000007 repeats after 1000000006 digits.
+        assert_eq!(
+            Digits::from_ratio(1u64, 1_000_000_007).map(|e| e.to_string()),
+            Err(ConversionError::CycleTooLong {
+                max_len: RatioDigits::<u64>::MAX_CYCLE_LEN
+            })
+        );
+        assert_eq!(
+            Digits::from_ratio_with_max_cycle(1u32, 983, 981).map(|e| e.to_string()),
+            Err(ConversionError::CycleTooLong { max_len: 981 })
+        );
+        let expansion = Digits::from_ratio_with_max_cycle(1u32, 983, 982).unwrap();
+        assert_eq!(expansion.cycle().len(), 982);
+        // Terminating expansions need no cycle at all.
+        let expansion = Digits::from_ratio_with_max_cycle(1u32, 8, 0).unwrap();
+        assert_eq!(expansion.to_string(), "0.125");
+    }
+
+    #[test]
+    fn from_ratio_throws_error_with_zero_denominator() {
+        assert_eq!(
+            Digits::from_ratio(1u32, 0).map(|e| e.to_string()),
+            Err(ConversionError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn from_ratio_throws_error_with_huge_denominator() {
+        assert_eq!(
+            Digits::from_ratio(1u128, u128::MAX).map(|e| e.to_string()),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(128)
+            })
+        );
+    }
+}
@@ -0,0 +1,1071 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::{Range, RangeInclusive};
+
+use num_traits::{checked_pow, CheckedAdd, CheckedMul, CheckedSub, Num, NumCast};
+
+use crate::{
+    digits_of, long_add, long_mul, long_sub, overflow_error, radix_constant, strip_leading_zeros,
+    sum_overflow_error, ConversionError, Digits, LazyDigits, Sign,
+};
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn sum(&self) -> Result<T, ConversionError>
+    where
+        T: CheckedAdd,
+    {
+        self.values.iter().try_fold(T::zero(), |acc, d| {
+            acc.checked_add(d)
+                .ok_or_else(|| sum_overflow_error(&self.values))
+        })
+    }
+
+    pub fn digital_root(&self) -> T {
+        // The digital root is the value mod (radix - 1), with multiples of
+        // radix - 1 mapped to radix - 1 itself, so it never needs the sum.
+        let modulus = T::from(self.radix - 1).unwrap();
+        if self.values.iter().all(|d| d.is_zero()) {
+            return T::zero();
+        }
+        match self
+            .values
+            .iter()
+            .fold(T::zero(), |acc, &d| (acc + d) % modulus)
+        {
+            r if r.is_zero() => modulus,
+            r => r,
+        }
+    }
+
+    pub fn additive_persistence(&self) -> usize {
+        let significant = self.values.iter().skip_while(|d| d.is_zero()).count();
+        if significant <= 1 {
+            return 0;
+        }
+
+        let radix = self.radix as u64;
+        let mut n: u64 = self.values.iter().map(|d| d.to_u64().unwrap()).sum();
+        let mut steps = 1;
+        while n >= radix {
+            n = LazyDigits::new(n, radix).sum();
+            steps += 1;
+        }
+        steps
+    }
+
+    pub fn product(&self) -> Result<T, ConversionError>
+    where
+        T: CheckedMul,
+    {
+        if self.values.iter().any(|d| d.is_zero()) {
+            return Ok(T::zero());
+        }
+        self.values.iter().try_fold(T::one(), |acc, d| {
+            acc.checked_mul(d).ok_or_else(|| {
+                let radix = self.radix as u64;
+                let product = self.values.iter().fold(vec![1], |acc, d| {
+                    long_mul(&acc, &[d.to_u64().unwrap()], radix)
+                });
+                overflow_error(&product, radix)
+            })
+        })
+    }
+
+    pub fn multiplicative_persistence(&self) -> usize {
+        let radix = self.radix as u64;
+        let mut digits: Vec<u64> = self
+            .values
+            .iter()
+            .skip_while(|d| d.is_zero())
+            .map(|d| d.to_u64().unwrap())
+            .collect();
+
+        // The digit products are kept as digit vectors, so long inputs whose
+        // product exceeds every primitive type are still handled.
+        let mut steps = 0;
+        while digits.len() > 1 {
+            steps += 1;
+            if digits.contains(&0) {
+                break;
+            }
+            digits = digits
+                .iter()
+                .fold(vec![1], |acc, &d| long_mul(&acc, &[d], radix));
+        }
+        steps
+    }
+
+    /// Checks whether the number is divisible by its digit sum. The
+    /// remainder is taken digit by digit, so the value is never rebuilt.
+    pub fn is_harshad(&self) -> bool
+    where
+        T: CheckedAdd,
+    {
+        let sum = match self.sum().ok().and_then(|s| s.to_u64()) {
+            Some(sum) => sum,
+            None => self.values.iter().map(|d| d.to_u64().unwrap()).sum(),
+        };
+        if sum == 0 {
+            return false;
+        }
+        let (radix, sum) = (self.radix as u128, sum as u128);
+        self.values
+            .iter()
+            .fold(0, |rem, d| (rem * radix + d.to_u128().unwrap()) % sum)
+            == 0
+    }
+
+    /// Checks whether the number equals the sum of its digits each raised to
+    /// the number of digits, e.g. 153 = 1³ + 5³ + 3³. A sum that overflows `T`
+    /// exceeds every value `T` can hold, so it is simply not narcissistic.
+    pub fn is_narcissistic(&self) -> bool
+    where
+        T: CheckedAdd + CheckedMul + CheckedSub,
+    {
+        let significant = self.values.iter().skip_while(|d| d.is_zero()).count();
+        self.is_perfect_digital_invariant(significant)
+    }
+
+    /// Checks whether the number equals the sum of its digits each raised to
+    /// `power`, e.g. 4150 = 4⁵ + 1⁵ + 5⁵ + 0⁵. Narcissistic numbers are the
+    /// case where `power` is the number of digits.
+    pub fn is_perfect_digital_invariant(&self, power: usize) -> bool
+    where
+        T: CheckedAdd + CheckedMul + CheckedSub,
+    {
+        self.equals_digit_power_sum(|_| power)
+    }
+
+    /// Checks whether the number equals the sum of its digits each raised to
+    /// its position from the left, counting from one, e.g. 135 = 1¹ + 3² + 5³.
+    pub fn is_disarium(&self) -> bool
+    where
+        T: CheckedAdd + CheckedMul + CheckedSub,
+    {
+        self.equals_digit_power_sum(|i| i + 1)
+    }
+
+    // Whether the number equals the sum of each significant digit raised to
+    // `power(i)` for its index `i`. A sum that overflows `T` exceeds every
+    // value `T` can hold, so it never matches.
+    fn equals_digit_power_sum(&self, power: impl Fn(usize) -> usize) -> bool
+    where
+        T: CheckedAdd + CheckedMul + CheckedSub,
+    {
+        if self.sign == Sign::Negative {
+            return false;
+        }
+        let significant = &self.values[self.values.iter().take_while(|d| d.is_zero()).count()..];
+        let sum = significant
+            .iter()
+            .enumerate()
+            .try_fold(T::zero(), |acc, (i, &d)| {
+                checked_pow(d, power(i)).and_then(|p| acc.checked_add(&p))
+            });
+        match (sum, self.to_int()) {
+            (Some(sum), Ok(n)) => sum == n,
+            _ => false,
+        }
+    }
+
+    /// Checks whether repeatedly summing the squares of the digits reaches 1.
+    pub fn is_happy(&self) -> bool {
+        self.happy_iter().last() == Some(1)
+    }
+
+    /// The sequence of digit-square sums starting from this number, ending
+    /// at 1 for happy numbers or just before the first repeated value.
+    pub fn happy_iter(&self) -> HappyIter {
+        let radix = self.radix as u64;
+        let start = self.values.iter().map(|d| d.to_u64().unwrap().pow(2)).sum();
+        HappyIter {
+            next: start,
+            remaining: distinct_values(start, |n| square_digit_sum(n, radix)),
+            radix,
+        }
+    }
+
+    /// Checks that the digits are exactly `1..radix`, each used once.
+    pub fn is_pandigital(&self) -> bool {
+        self.is_pandigital_range(1..=self.radix - 1)
+    }
+
+    /// Checks that the digits are exactly `0..radix`, each used once.
+    pub fn is_zero_pandigital(&self) -> bool {
+        self.is_pandigital_range(0..=self.radix - 1)
+    }
+
+    /// Checks that the digits are a permutation of `range`, e.g. `1..=5` for
+    /// a 1-to-5 pandigital number.
+    pub fn is_pandigital_range(&self, range: RangeInclusive<u32>) -> bool {
+        let (&low, &high) = (range.start(), range.end());
+        if low > high || high >= self.radix {
+            return false;
+        }
+        if self.values.len() != (high - low + 1) as usize {
+            return false;
+        }
+
+        // Radixes go up to 36, so one bit per digit fits in a u64.
+        let mut seen = 0u64;
+        for d in &self.values {
+            let d = d.to_u32().unwrap();
+            let bit = 1u64 << d;
+            if d < low || d > high || seen & bit != 0 {
+                return false;
+            }
+            seen |= bit;
+        }
+        true
+    }
+
+    /// The decimal number made of `len` ones, built digit by digit so any
+    /// length works.
+    pub fn repunit(len: usize) -> Result<Self, ConversionError> {
+        Self::repdigit(T::one(), len)
+    }
+
+    /// The decimal number made of `len` copies of the digit `d`. Repeated
+    /// zeros are just zero.
+    pub fn repdigit(d: T, len: usize) -> Result<Self, ConversionError> {
+        if len == 0 {
+            return Err(ConversionError::Empty);
+        }
+        if d < T::zero() || d >= T::from(10).unwrap() {
+            return Err(ConversionError::invalid_digit(0, d));
+        }
+        let len = if d.is_zero() { 1 } else { len };
+        Ok(Self::new(vec![d; len], 10))
+    }
+
+    /// Checks whether every digit is the same, like 777. Leading zeros are
+    /// ignored, and a single digit is a repdigit.
+    pub fn is_repdigit(&self) -> bool {
+        match self.significant() {
+            [] => true,
+            [first, rest @ ..] => rest.iter().all(|d| d == first),
+        }
+    }
+
+    /// Checks whether every digit is a one, like 1111.
+    pub fn is_repunit(&self) -> bool {
+        let significant = self.significant();
+        !significant.is_empty() && significant.iter().all(|d| d.is_one())
+    }
+
+    /// Checks whether the square ends in the number itself, like 76² = 5776.
+    /// Only the trailing digits of the square are worked out, so any length
+    /// works.
+    pub fn is_automorphic(&self) -> bool {
+        self.power_ends_with_self(2)
+    }
+
+    /// Checks whether the cube ends in the number itself, like 49³ = 117649.
+    pub fn is_trimorphic(&self) -> bool {
+        self.power_ends_with_self(3)
+    }
+
+    fn power_ends_with_self(&self, exponent: u32) -> bool {
+        if self.sign == Sign::Negative {
+            return false;
+        }
+        // Digits are widened so the long multiplication cannot overflow even
+        // for u8 digits in radix 36.
+        let n: Vec<u64> = self
+            .significant()
+            .iter()
+            .map(|d| d.to_u64().unwrap())
+            .collect();
+        let mut power = n.clone();
+        for _ in 1..exponent {
+            power = long_mul(&power, &n, self.radix as u64);
+            power.drain(..power.len().saturating_sub(n.len()));
+        }
+        let padding = n.len() - power.len();
+        n[..padding].iter().all(|&d| d == 0) && n[padding..] == power[..]
+    }
+
+    /// The sequence n, n + digitsum(n), ... without end. The additions are
+    /// done on the digit vectors, so the terms are never limited by `T`. A
+    /// negative number grows in magnitude and keeps its sign.
+    pub fn digitaddition_iter(&self) -> DigitAddition<T> {
+        let mut current = self.clone();
+        strip_leading_zeros(&mut current.values);
+        DigitAddition { current }
+    }
+
+    /// Checks whether no m satisfies m + digitsum(m) = n, like 20. Only the
+    /// few m within the largest possible digit sum below n are tried, so any
+    /// length works. Use `self_numbers` to check a whole range at once.
+    pub fn is_self_number(&self) -> bool {
+        if self.sign != Sign::Positive {
+            return false;
+        }
+        let radix = self.radix as u64;
+        let n: Vec<u64> = self
+            .values
+            .iter()
+            .skip_while(|d| d.is_zero())
+            .map(|d| d.to_u64().unwrap())
+            .collect();
+        let fits = |k: &[u64]| k.len() < n.len() || (k.len() == n.len() && k <= &n[..]);
+        let max_sum = (radix - 1) * n.len() as u64;
+        !(1..=max_sum)
+            .map(|k| (k, LazyDigits::new(k, radix).collect::<Vec<_>>()))
+            .take_while(|(_, k_digits)| fits(k_digits))
+            .any(|(k, k_digits)| long_sub(&n, &k_digits, radix).iter().sum::<u64>() == k)
+    }
+
+    // The digits without leading zeros, which belong to the value only when
+    // the width is fixed.
+    fn significant(&self) -> &[T] {
+        if self.fixed_width {
+            return &self.values;
+        }
+        &self.values[self.values.iter().take_while(|d| d.is_zero()).count()..]
+    }
+}
+
+fn square_digit_sum(n: u64, radix: u64) -> u64 {
+    LazyDigits::new(n, radix).map(|d| d * d).sum()
+}
+
+// Brent's cycle detection gives the length of the tail and of the cycle, so
+// the number of distinct values is known up front without remembering them.
+fn distinct_values(start: u64, f: impl Fn(u64) -> u64) -> usize {
+    let (mut power, mut cycle) = (1, 1);
+    let (mut tortoise, mut hare) = (start, f(start));
+    while tortoise != hare {
+        if power == cycle {
+            tortoise = hare;
+            power *= 2;
+            cycle = 0;
+        }
+        hare = f(hare);
+        cycle += 1;
+    }
+
+    let (mut tortoise, mut hare) = (start, start);
+    for _ in 0..cycle {
+        hare = f(hare);
+    }
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = f(tortoise);
+        hare = f(hare);
+        tail += 1;
+    }
+    tail + cycle
+}
+
+#[derive(Clone, Debug)]
+pub struct HappyIter {
+    next: u64,
+    remaining: usize,
+    radix: u64,
+}
+
+impl Iterator for HappyIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let current = self.next;
+        self.next = square_digit_sum(current, self.radix);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for HappyIter {}
+
+impl FusedIterator for HappyIter {}
+
+#[derive(Clone)]
+pub struct DigitAddition<T> {
+    current: Digits<T>,
+}
+
+impl<T> Iterator for DigitAddition<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let radix = self.current.radix;
+        let sum: u64 = self
+            .current
+            .values
+            .iter()
+            .map(|d| d.to_u64().unwrap())
+            .sum();
+        let sum: Vec<T> = LazyDigits::new(sum, radix as u64)
+            .map(|d| T::from(d).unwrap())
+            .collect();
+        let values = long_add(&self.current.values, &sum, T::from(radix).unwrap());
+        let next = Digits::new_signed(values, radix, self.current.sign == Sign::Negative);
+        Some(core::mem::replace(&mut self.current, next))
+    }
+}
+
+impl<T> FusedIterator for DigitAddition<T> where T: Num + NumCast + PartialOrd + Copy {}
+
+/// The self numbers in `range` in decimal, see `self_numbers_radix`.
+pub fn self_numbers(range: Range<u64>) -> Vec<u64> {
+    self_numbers_radix(range, 10).unwrap()
+}
+
+/// The self numbers in `range` in `radix`, found by sieving out every
+/// m + digitsum(m) instead of testing each number on its own.
+pub fn self_numbers_radix(range: Range<u64>, radix: u32) -> Result<Vec<u64>, ConversionError> {
+    let radix: u64 = radix_constant(radix)?;
+    if range.is_empty() {
+        return Ok(Vec::new());
+    }
+    let start = range.start;
+    let max_sum = (radix - 1) * LazyDigits::new(range.end, radix).count() as u64;
+    let mut generated = vec![false; (range.end - range.start) as usize];
+    for m in range.start.saturating_sub(max_sum)..range.end {
+        // Past `u64::MAX` it is also past the range.
+        let Some(n) = m.checked_add(LazyDigits::new(m, radix).sum::<u64>()) else {
+            continue;
+        };
+        if range.contains(&n) {
+            generated[(n - range.start) as usize] = true;
+        }
+    }
+    Ok(range
+        .filter(|&n| n > 0 && !generated[(n - start) as usize])
+        .collect())
+}
+
+/// Checks whether `n` is divisible by the sum of its decimal digits, without
+/// building a `Digits`, e.g. `(1..100).filter(|&n| is_harshad(n))`.
+pub fn is_harshad<T>(n: T) -> bool
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    let sum = digits_of(n).fold(T::zero(), |acc, d| acc + d);
+    !sum.is_zero() && (n % sum).is_zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factorial_digits;
+    use alloc::vec;
+
+    #[test]
+    fn sum_works() {
+        assert_eq!(Digits::from(369u32).sum(), Ok(18));
+        assert_eq!(Digits::from(0u32).sum(), Ok(0));
+        assert_eq!(Digits::from(u64::MAX).sum(), Ok(87));
+        assert_eq!(Digits::try_from(-123).unwrap().sum(), Ok(6));
+        assert_eq!(factorial_digits(100).sum(), Ok(648));
+    }
+
+    #[test]
+    fn sum_works_with_radix() {
+        assert_eq!(Digits::with_radix(0xff, 16).unwrap().sum(), Ok(30));
+        assert_eq!(Digits::with_radix(u32::MAX, 2).unwrap().sum(), Ok(32));
+    }
+
+    #[test]
+    fn sum_throws_error_on_overflow() {
+        let digits = Digits::from_radix(vec![9u8; 30], 10).unwrap();
+        assert_eq!(
+            digits.sum(),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(9)
+            })
+        );
+    }
+
+    #[test]
+    fn digital_root_works() {
+        assert_eq!(Digits::from(0u32).digital_root(), 0);
+        assert_eq!(Digits::from(7u32).digital_root(), 7);
+        assert_eq!(Digits::from(9u32).digital_root(), 9);
+        assert_eq!(Digits::from(18u32).digital_root(), 9);
+        assert_eq!(Digits::from(942u32).digital_root(), 6);
+        assert_eq!(Digits::from(132_189u32).digital_root(), 6);
+        assert_eq!(Digits::from(u8::MAX).digital_root(), 3);
+    }
+
+    #[test]
+    fn digital_root_works_without_overflow() {
+        let digits = Digits::from_radix(vec![9u8; 30], 10).unwrap();
+        assert_eq!(digits.digital_root(), 9);
+        assert_eq!(factorial_digits(100).digital_root(), 9);
+    }
+
+    #[test]
+    fn digital_root_works_with_radix() {
+        assert_eq!(Digits::with_radix(0xff, 16).unwrap().digital_root(), 15);
+        assert_eq!(Digits::with_radix(0x1f, 16).unwrap().digital_root(), 1);
+        assert_eq!(Digits::with_radix(6, 2).unwrap().digital_root(), 1);
+    }
+
+    #[test]
+    fn additive_persistence_works() {
+        assert_eq!(Digits::from(0u32).additive_persistence(), 0);
+        assert_eq!(Digits::from(7u32).additive_persistence(), 0);
+        assert_eq!(Digits::from(10u32).additive_persistence(), 1);
+        assert_eq!(Digits::from(2718u32).additive_persistence(), 2);
+        assert_eq!(Digits::from(199u32).additive_persistence(), 3);
+        assert_eq!(
+            Digits::from(19_999_999_999_999_999_999_999u128).additive_persistence(),
+            4
+        );
+    }
+
+    #[test]
+    fn additive_persistence_ignores_leading_zeros() {
+        let digits = Digits::<u8>::parse_with_leading_zeros("007").unwrap();
+        assert_eq!(digits.additive_persistence(), 0);
+    }
+
+    #[test]
+    fn additive_persistence_works_without_overflow() {
+        let digits = Digits::from_radix(vec![9u8; 30], 10).unwrap();
+        assert_eq!(digits.additive_persistence(), 2);
+    }
+
+    #[test]
+    fn product_works() {
+        assert_eq!(Digits::from(369u32).product(), Ok(162));
+        assert_eq!(Digits::from(7u32).product(), Ok(7));
+        assert_eq!(Digits::from(0u32).product(), Ok(0));
+        assert_eq!(Digits::try_from(-234).unwrap().product(), Ok(24));
+        assert_eq!(Digits::with_radix(0xff, 16).unwrap().product(), Ok(225));
+    }
+
+    #[test]
+    fn product_short_circuits_on_zero() {
+        let mut values = vec![9u8; 30];
+        values.push(0);
+        assert_eq!(Digits::from_radix(values, 10).unwrap().product(), Ok(0));
+        assert_eq!(factorial_digits(100).product(), Ok(0));
+    }
+
+    #[test]
+    fn product_throws_error_on_overflow() {
+        let digits = Digits::from_radix(vec![9u8; 3], 10).unwrap();
+        assert_eq!(
+            digits.product(),
+            Err(ConversionError::Overflow {
+                needed_bits: Some(10)
+            })
+        );
+    }
+
+    #[test]
+    fn multiplicative_persistence_works() {
+        assert_eq!(Digits::from(0u32).multiplicative_persistence(), 0);
+        assert_eq!(Digits::from(7u32).multiplicative_persistence(), 0);
+        assert_eq!(Digits::from(10u32).multiplicative_persistence(), 1);
+        assert_eq!(Digits::from(39u32).multiplicative_persistence(), 3);
+        assert_eq!(Digits::from(999u32).multiplicative_persistence(), 4);
+        assert_eq!(
+            Digits::from(277_777_788_888_899u64).multiplicative_persistence(),
+            11
+        );
+    }
+
+    #[test]
+    fn multiplicative_persistence_ignores_leading_zeros() {
+        let digits = Digits::<u8>::parse_with_leading_zeros("007").unwrap();
+        assert_eq!(digits.multiplicative_persistence(), 0);
+    }
+
+    #[test]
+    fn multiplicative_persistence_works_beyond_integer_range() {
+        // 9^50 has 48 digits and contains a zero.
+        let digits = Digits::from_radix(vec![9u8; 50], 10).unwrap();
+        assert_eq!(digits.multiplicative_persistence(), 2);
+    }
+
+    #[test]
+    fn multiplicative_persistence_works_with_radix() {
+        assert_eq!(
+            Digits::with_radix(0b111, 2)
+                .unwrap()
+                .multiplicative_persistence(),
+            1
+        );
+        assert_eq!(
+            Digits::with_radix(0xff, 16)
+                .unwrap()
+                .multiplicative_persistence(),
+            2
+        );
+    }
+
+    #[test]
+    fn is_pandigital_works() {
+        assert!(Digits::from(123_456_789u32).is_pandigital());
+        assert!(Digits::from(918_273_645u32).is_pandigital());
+        assert!(!Digits::from(1_234_567_890u64).is_pandigital());
+        assert!(!Digits::from(123_456_788u32).is_pandigital());
+        assert!(!Digits::from(12_345_678u32).is_pandigital());
+    }
+
+    #[test]
+    fn is_pandigital_works_with_radix() {
+        assert!(Digits::with_radix(0b1, 2).unwrap().is_pandigital());
+        assert!(Digits::with_radix(0x123456789abcdef_u64, 16)
+            .unwrap()
+            .is_pandigital());
+        assert!(!Digits::with_radix(123_456_789u32, 16)
+            .unwrap()
+            .is_pandigital());
+    }
+
+    #[test]
+    fn is_zero_pandigital_works() {
+        assert!(Digits::from(1_234_567_890u64).is_zero_pandigital());
+        assert!(Digits::from(1_406_357_289u64).is_zero_pandigital());
+        assert!(!Digits::from(123_456_789u32).is_zero_pandigital());
+        assert!(!Digits::from(1_234_567_899u64).is_zero_pandigital());
+        assert!(Digits::with_radix(0b10, 2).unwrap().is_zero_pandigital());
+    }
+
+    #[test]
+    fn is_pandigital_range_works() {
+        assert!(Digits::from(15_234u32).is_pandigital_range(1..=5));
+        assert!(Digits::from(4_567u32).is_pandigital_range(4..=7));
+        assert!(Digits::from(0u32).is_pandigital_range(0..=0));
+        assert!(!Digits::from(15_234u32).is_pandigital_range(1..=6));
+        assert!(!Digits::from(15_233u32).is_pandigital_range(1..=5));
+        assert!(!Digits::from(15_236u32).is_pandigital_range(1..=5));
+    }
+
+    #[test]
+    fn is_pandigital_range_rejects_invalid_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 1..=0;
+        assert!(!Digits::from(1u32).is_pandigital_range(empty));
+        assert!(!Digits::from(1u32).is_pandigital_range(1..=10));
+        assert!(!Digits::with_radix(1, 2).unwrap().is_pandigital_range(1..=2));
+    }
+
+    #[test]
+    fn is_harshad_works() {
+        assert!(Digits::from(18u32).is_harshad());
+        assert!(Digits::from(1_729u32).is_harshad());
+        assert!(Digits::from(7u32).is_harshad());
+        assert!(!Digits::from(19u32).is_harshad());
+        assert!(!Digits::from(0u32).is_harshad());
+        assert!(Digits::try_from(-21).unwrap().is_harshad());
+    }
+
+    #[test]
+    fn is_harshad_works_with_radix() {
+        // 0b110 has digit sum 2 and 6 is even.
+        assert!(Digits::with_radix(0b110, 2).unwrap().is_harshad());
+        assert!(!Digits::with_radix(0b111, 2).unwrap().is_harshad());
+    }
+
+    #[test]
+    fn is_harshad_works_beyond_integer_range() {
+        // 10^100 has digit sum 1, and a run of n nines is Harshad for n = 81
+        // but not for n = 100.
+        let mut values = vec![0u8; 101];
+        values[0] = 1;
+        assert!(Digits::from_radix(values, 10).unwrap().is_harshad());
+        let nines = Digits::from_radix(vec![9u8; 81], 10).unwrap();
+        assert!(nines.is_harshad());
+        let nines = Digits::from_radix(vec![9u8; 100], 10).unwrap();
+        assert!(!nines.is_harshad());
+    }
+
+    #[test]
+    fn is_harshad_free_function_works() {
+        let harshad: Vec<u32> = (1u32..=30).filter(|&n| is_harshad(n)).collect();
+        assert_eq!(
+            harshad,
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 18, 20, 21, 24, 27, 30]
+        );
+        assert!(!is_harshad(0u32));
+        assert!(is_harshad(-12i32));
+        assert!(is_harshad(u8::MAX - 3));
+    }
+
+    #[test]
+    fn is_harshad_free_function_agrees_with_method() {
+        for n in 1u32..2_000 {
+            assert_eq!(is_harshad(n), Digits::from(n).is_harshad());
+        }
+    }
+
+    #[test]
+    fn is_narcissistic_works() {
+        let narcissistic: Vec<u32> = (0u32..10_000)
+            .filter(|&n| Digits::from(n).is_narcissistic())
+            .collect();
+        assert_eq!(
+            narcissistic,
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 153, 370, 371, 407, 1_634, 8_208, 9_474]
+        );
+        assert!(!Digits::try_from(-153).unwrap().is_narcissistic());
+    }
+
+    #[test]
+    fn is_narcissistic_works_with_leading_zeros() {
+        let digits = Digits::<u32>::parse_with_leading_zeros("0153").unwrap();
+        assert!(digits.is_narcissistic());
+    }
+
+    #[test]
+    fn is_narcissistic_works_with_wide_types() {
+        assert!(Digits::from(4_679_307_774u64).is_narcissistic());
+        assert!(
+            Digits::from(115_132_219_018_763_992_565_095_597_973_971_522_401u128).is_narcissistic()
+        );
+        assert!(
+            !Digits::from(115_132_219_018_763_992_565_095_597_973_971_522_402u128)
+                .is_narcissistic()
+        );
+    }
+
+    #[test]
+    fn is_narcissistic_rejects_overflowing_sum() {
+        assert!(!Digits::from(99u8).is_narcissistic());
+        assert!(!Digits::from(u64::MAX).is_narcissistic());
+        assert!(!Digits::from(u128::MAX).is_narcissistic());
+    }
+
+    #[test]
+    fn is_narcissistic_works_with_radix() {
+        // 17 is 122 in base 3, and 1³ + 2³ + 2³ = 17.
+        assert!(Digits::with_radix(17u32, 3).unwrap().is_narcissistic());
+        assert!(!Digits::with_radix(16u32, 3).unwrap().is_narcissistic());
+    }
+
+    #[test]
+    fn is_happy_works() {
+        let happy: Vec<u32> = (1u32..=50)
+            .filter(|&n| Digits::from(n).is_happy())
+            .collect();
+        assert_eq!(happy, vec![1, 7, 10, 13, 19, 23, 28, 31, 32, 44, 49]);
+        assert!(!Digits::from(0u32).is_happy());
+        assert!(!Digits::from(4u32).is_happy());
+    }
+
+    #[test]
+    fn is_happy_works_beyond_integer_range() {
+        let digits = Digits::from_radix(vec![1u8; 100], 10).unwrap();
+        assert!(digits.is_happy());
+    }
+
+    #[test]
+    fn is_happy_works_with_radix() {
+        // Every number is happy in base 2 and base 4.
+        assert!((1u32..100).all(|n| Digits::with_radix(n, 2).unwrap().is_happy()));
+        assert!((1u32..100).all(|n| Digits::with_radix(n, 4).unwrap().is_happy()));
+        assert!(!Digits::with_radix(2u32, 3).unwrap().is_happy());
+    }
+
+    #[test]
+    fn happy_iter_works() {
+        let steps: Vec<u64> = Digits::from(19u32).happy_iter().collect();
+        assert_eq!(steps, vec![82, 68, 100, 1]);
+        assert_eq!(Digits::from(1u32).happy_iter().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn happy_iter_stops_before_repeating() {
+        let steps: Vec<u64> = Digits::from(4u32).happy_iter().collect();
+        assert_eq!(steps, vec![16, 37, 58, 89, 145, 42, 20, 4]);
+
+        let steps: Vec<u64> = Digits::from(2u32).happy_iter().collect();
+        assert_eq!(steps, vec![4, 16, 37, 58, 89, 145, 42, 20]);
+        assert_eq!(Digits::from(2u32).happy_iter().len(), 8);
+    }
+
+    #[test]
+    fn repunit_works() {
+        assert_eq!(Digits::<u32>::repunit(4).unwrap().to_int(), Ok(1_111));
+        assert_eq!(Digits::<u8>::repunit(1).unwrap().to_int(), Ok(1));
+        assert!(Digits::<u32>::repunit(4).unwrap().is_repunit());
+    }
+
+    #[test]
+    fn repunit_works_beyond_integer_range() {
+        let digits = Digits::<u8>::repunit(100).unwrap();
+        assert_eq!(digits.len(), 100);
+        assert!(digits.to_int().is_err());
+        assert_eq!(digits.sum(), Ok(100));
+    }
+
+    #[test]
+    fn repunit_throws_error_with_zero_length() {
+        assert!(matches!(
+            Digits::<u32>::repunit(0),
+            Err(ConversionError::Empty)
+        ));
+    }
+
+    #[test]
+    fn repdigit_works() {
+        assert_eq!(Digits::repdigit(7u32, 3).unwrap().to_int(), Ok(777));
+        assert_eq!(Digits::repdigit(0u32, 3).unwrap().as_slice(), vec![0]);
+        assert_eq!(
+            Digits::repdigit(9u64, 19).unwrap().to_int(),
+            Ok(10u64.pow(19) - 1)
+        );
+    }
+
+    #[test]
+    fn repdigit_throws_error_with_invalid_digit() {
+        assert!(matches!(
+            Digits::repdigit(10u32, 3),
+            Err(ConversionError::InvalidDigit {
+                position: 0,
+                value: 10
+            })
+        ));
+        assert!(Digits::repdigit(-1i32, 3).is_err());
+        assert!(Digits::repdigit(1u32, 0).is_err());
+    }
+
+    #[test]
+    fn is_repdigit_works() {
+        let repdigits: Vec<u32> = (0..1_000)
+            .filter(|&n| Digits::from(n).is_repdigit())
+            .collect();
+        let mut expected: Vec<u32> = (0..10).collect();
+        expected.extend((1..10).map(|d| d * 11));
+        expected.extend((1..10).map(|d| d * 111));
+        assert_eq!(repdigits, expected);
+        assert!(Digits::try_from(-555).unwrap().is_repdigit());
+        assert!(Digits::with_radix(0xfff, 16).unwrap().is_repdigit());
+    }
+
+    #[test]
+    fn is_repdigit_works_with_leading_zeros() {
+        let digits = Digits::<u8>::parse_with_leading_zeros("0033").unwrap();
+        assert!(digits.is_repdigit());
+        assert!(!digits.into_fixed_width().is_repdigit());
+    }
+
+    #[test]
+    fn is_repunit_works() {
+        assert!(Digits::from(1u32).is_repunit());
+        assert!(Digits::from(11_111u32).is_repunit());
+        assert!(!Digits::from(0u32).is_repunit());
+        assert!(!Digits::from(101u32).is_repunit());
+        assert!(!Digits::from(222u32).is_repunit());
+        assert!(Digits::with_radix(7u32, 2).unwrap().is_repunit());
+    }
+
+    #[test]
+    fn is_automorphic_works() {
+        let automorphic: Vec<u64> = (0..100_000)
+            .filter(|&n| Digits::from(n).is_automorphic())
+            .collect();
+        assert_eq!(
+            automorphic,
+            vec![0, 1, 5, 6, 25, 76, 376, 625, 9_376, 90_625]
+        );
+        assert!(!Digits::try_from(-5).unwrap().is_automorphic());
+    }
+
+    #[test]
+    fn is_automorphic_works_beyond_integer_range() {
+        // The two 39 digit automorphic numbers add up to 10^39 + 1.
+        for n in [
+            "896109004106619977392256259918212890625",
+            "103890995893380022607743740081787109376",
+        ] {
+            assert!(n.parse::<Digits<u8>>().unwrap().is_automorphic());
+        }
+        assert!(!factorial_digits(30).is_automorphic());
+    }
+
+    #[test]
+    fn is_automorphic_works_with_radix() {
+        for radix in [2, 6, 12, 36] {
+            for n in 0u64..2_000 {
+                let modulus =
+                    (radix as u64).pow(Digits::with_radix(n, radix).unwrap().len() as u32);
+                assert_eq!(
+                    Digits::with_radix(n, radix).unwrap().is_automorphic(),
+                    n * n % modulus == n
+                );
+            }
+        }
+        let digits = Digits::<u8>::from_radix(vec![35, 35], 36).unwrap();
+        assert!(!digits.is_automorphic());
+    }
+
+    #[test]
+    fn is_trimorphic_works() {
+        for n in 0u64..10_000 {
+            let modulus = 10u64.pow(Digits::from(n).len() as u32);
+            assert_eq!(
+                Digits::from(n).is_trimorphic(),
+                n * n * n % modulus == n,
+                "{n}"
+            );
+        }
+        assert!(Digits::from(49u32).is_trimorphic());
+        assert!(Digits::from(24u32).is_trimorphic());
+        assert!(!Digits::from(23u32).is_trimorphic());
+    }
+
+    #[test]
+    fn is_disarium_works() {
+        let disarium: Vec<u32> = (1..10_000)
+            .filter(|&n| Digits::from(n).is_disarium())
+            .collect();
+        assert_eq!(
+            disarium,
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 89, 135, 175, 518, 598, 1_306, 1_676, 2_427]
+        );
+        assert!(Digits::from(2_646_798u32).is_disarium());
+        assert!(!Digits::try_from(-89).unwrap().is_disarium());
+    }
+
+    #[test]
+    fn is_disarium_works_with_radix() {
+        // Binary digits sum to at most the number of digits, so only 0
+        // and 1 qualify.
+        let disarium: Vec<u32> = (0..1_000)
+            .filter(|&n| Digits::with_radix(n, 2).unwrap().is_disarium())
+            .collect();
+        assert_eq!(disarium, vec![0, 1]);
+        // 5 is 12 in ternary and 1 + 2² = 5.
+        assert!(Digits::with_radix(5u32, 3).unwrap().is_disarium());
+    }
+
+    #[test]
+    fn is_perfect_digital_invariant_works() {
+        let invariants = |power| -> Vec<u32> {
+            (2..200_000)
+                .filter(|&n| Digits::from(n).is_perfect_digital_invariant(power))
+                .collect()
+        };
+        assert_eq!(invariants(3), vec![153, 370, 371, 407]);
+        assert_eq!(invariants(4), vec![1_634, 8_208, 9_474]);
+        assert_eq!(
+            invariants(5),
+            vec![4_150, 4_151, 54_748, 92_727, 93_084, 194_979]
+        );
+        assert!(invariants(2).is_empty());
+    }
+
+    #[test]
+    fn is_perfect_digital_invariant_rejects_overflowing_sum() {
+        let digits = Digits::from(99u8);
+        assert!(!digits.is_perfect_digital_invariant(3));
+        assert!(Digits::from(0u8).is_perfect_digital_invariant(1));
+    }
+
+    #[test]
+    fn digitaddition_iter_works() {
+        let terms: Vec<u32> = Digits::from(1u32)
+            .digitaddition_iter()
+            .take(10)
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(terms, vec![1, 2, 4, 8, 16, 23, 28, 38, 49, 62]);
+        let terms: Vec<i32> = Digits::try_from(-12)
+            .unwrap()
+            .digitaddition_iter()
+            .take(3)
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(terms, vec![-12, -15, -21]);
+    }
+
+    #[test]
+    fn digitaddition_iter_works_beyond_integer_range() {
+        let digits = Digits::from_radix(vec![9u8; 30], 10).unwrap();
+        let next = digits.digitaddition_iter().nth(1).unwrap();
+        assert_eq!(next.len(), 31);
+        assert_eq!(next.sum(), Ok(1 + 2 + 6 + 9));
+    }
+
+    #[test]
+    fn digitaddition_iter_works_with_radix() {
+        let digits = Digits::with_radix(0xffu32, 16).unwrap();
+        let next = digits.digitaddition_iter().nth(1).unwrap();
+        assert_eq!(next.to_int(), Ok(0xff + 30));
+    }
+
+    #[test]
+    fn is_self_number_works() {
+        let self_numbers: Vec<u32> = (0..100)
+            .filter(|&n| Digits::from(n).is_self_number())
+            .collect();
+        assert_eq!(
+            self_numbers,
+            vec![1, 3, 5, 7, 9, 20, 31, 42, 53, 64, 75, 86, 97]
+        );
+        assert!(!Digits::try_from(-20).unwrap().is_self_number());
+    }
+
+    #[test]
+    fn is_self_number_works_beyond_integer_range() {
+        // 10^30 - 1 + 270 is generated by 10^30 - 1.
+        let digits = Digits::from_radix(vec![9u8; 30], 10).unwrap();
+        let generated = digits.digitaddition_iter().nth(1).unwrap();
+        assert!(!generated.is_self_number());
+    }
+
+    #[test]
+    fn self_numbers_works() {
+        let brute: Vec<u64> = (0..5_000)
+            .filter(|&n| Digits::from(n).is_self_number())
+            .collect();
+        assert_eq!(self_numbers(0..5_000), brute);
+        let tail: Vec<u64> = brute.iter().copied().filter(|&n| n >= 1_000).collect();
+        assert_eq!(self_numbers(1_000..5_000), tail);
+        assert!(self_numbers(5..5).is_empty());
+    }
+
+    #[test]
+    fn self_numbers_radix_works() {
+        for radix in [2, 3, 16] {
+            let brute: Vec<u64> = (0..2_000)
+                .filter(|&n| Digits::with_radix(n, radix).unwrap().is_self_number())
+                .collect();
+            assert_eq!(self_numbers_radix(0..2_000, radix), Ok(brute));
+        }
+        // In an odd radix m + digitsum(m) is always even, and every even
+        // number is reached, so the self numbers are the odd ones.
+        let odd: Vec<u64> = (1..100).step_by(2).collect();
+        assert_eq!(self_numbers_radix(0..100, 3), Ok(odd));
+        assert!(self_numbers_radix(0..10, 37).is_err());
+    }
+
+    #[test]
+    fn self_numbers_works_at_top_of_range() {
+        let range = (u64::MAX - 300)..u64::MAX;
+        for radix in [2, 10] {
+            let brute: Vec<u64> = range
+                .clone()
+                .filter(|&n| Digits::with_radix(n, radix).unwrap().is_self_number())
+                .collect();
+            assert_eq!(self_numbers_radix(range.clone(), radix), Ok(brute));
+        }
+        assert_eq!(
+            self_numbers((u64::MAX - 10)..u64::MAX).len(),
+            (0..10)
+                .filter(|k| Digits::from(u64::MAX - 10 + k).is_self_number())
+                .count()
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Roman numerals in standard subtractive notation, e.g. 1994 is MCMXCIV.
+//!
+//! Only the numbers 1 to 3999 can be written without overlines or other
+//! extensions, so anything outside that range is rejected.
+
+use alloc::string::String;
+
+use num_traits::{Num, NumCast};
+
+use crate::ConversionError;
+
+const NUMERALS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+fn out_of_range() -> ConversionError {
+    ConversionError::OutOfRange
+}
+
+pub fn to_roman<T>(n: T) -> Result<String, ConversionError>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    let mut n = match n.to_u32() {
+        Some(n @ 1..=3999) => n,
+        _ => return Err(out_of_range()),
+    };
+
+    let mut roman = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            roman.push_str(numeral);
+            n -= value;
+        }
+    }
+    Ok(roman)
+}
+
+/// Parses a numeral in either case. Non-standard forms such as IIII, IC or
+/// VX are rejected rather than read additively.
+pub fn parse<T>(s: &str) -> Result<T, ConversionError>
+where
+    T: NumCast,
+{
+    if s.is_empty() {
+        return Err(ConversionError::Empty);
+    }
+    if let Some((position, c)) = s
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !"IVXLCDM".contains(c.to_ascii_uppercase()))
+    {
+        return Err(ConversionError::InvalidChar { position, value: c });
+    }
+
+    // Read greedily with the same table the encoder uses. A numeral is
+    // well formed exactly when that consumes it and re-encodes to it. Past
+    // 3999 it cannot be, which also keeps a long run of Ms from overflowing.
+    let upper = s.to_ascii_uppercase();
+    let mut rest = upper.as_str();
+    let mut n = 0;
+    for (value, numeral) in NUMERALS {
+        while let Some(tail) = rest.strip_prefix(numeral) {
+            n += value;
+            if n > 3999 {
+                return Err(ConversionError::Malformed);
+            }
+            rest = tail;
+        }
+    }
+    if !rest.is_empty() || to_roman(n)? != upper {
+        return Err(ConversionError::Malformed);
+    }
+    T::from(n).ok_or_else(|| ConversionError::overflow(u32::BITS - n.leading_zeros()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn to_roman_works() {
+        let expected = [
+            (1u32, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (90, "XC"),
+            (400, "CD"),
+            (1994, "MCMXCIV"),
+            (2024, "MMXXIV"),
+            (3999, "MMMCMXCIX"),
+        ];
+        for (n, s) in expected {
+            assert_eq!(to_roman(n), Ok(s.to_string()));
+        }
+    }
+
+    #[test]
+    fn to_roman_throws_error_with_out_of_range_number() {
+        for n in [0i32, -5, 4000] {
+            assert_eq!(to_roman(n), Err(ConversionError::OutOfRange));
+        }
+    }
+
+    #[test]
+    fn parse_works() {
+        assert_eq!(parse("MCMXCIV"), Ok(1994u32));
+        assert_eq!(parse("mmxxiv"), Ok(2024u16));
+        assert_eq!(parse("I"), Ok(1u8));
+    }
+
+    #[test]
+    fn parse_round_trips() {
+        for n in 1u32..=3999 {
+            assert_eq!(parse(&to_roman(n).unwrap()), Ok(n));
+        }
+    }
+
+    #[test]
+    fn parse_throws_error_with_malformed_numeral() {
+        for s in [
+            "IIII", "IC", "VX", "IIV", "XIIIX", "MMMM", "DD", "CMCM", "IXI",
+        ] {
+            assert_eq!(parse::<u32>(s), Err(ConversionError::Malformed));
+        }
+    }
+
+    #[test]
+    fn parse_throws_error_with_too_many_thousands() {
+        // Enough Ms to overflow a u32 if they were all added up.
+        let s = "M".repeat(5_000_000);
+        assert_eq!(parse::<u32>(&s), Err(ConversionError::Malformed));
+    }
+
+    #[test]
+    fn parse_throws_error_with_invalid_character() {
+        assert_eq!(
+            parse::<u32>("MCMZ"),
+            Err(ConversionError::InvalidChar {
+                position: 3,
+                value: 'Z'
+            })
+        );
+        assert!(parse::<u32>("").is_err());
+        assert!(parse::<u8>("CCC").is_err());
+    }
+}
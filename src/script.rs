@@ -0,0 +1,192 @@
+use alloc::string::String;
+
+use num_traits::{Num, NumCast};
+
+use crate::{ConversionError, Digits, Sign};
+
+/// A Unicode script with its own decimal digits. Every script lays out zero
+/// to nine as consecutive code points, so only the zero is stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// The ASCII digits 0 to 9.
+    Latin,
+    /// ٠١٢٣٤٥٦٧٨٩
+    ArabicIndic,
+    /// ۰۱۲۳۴۵۶۷۸۹, as used for Persian and Urdu.
+    ExtendedArabicIndic,
+    /// ०१२३४५६७८९
+    Devanagari,
+    /// ০১২৩৪৫৬৭৮৯
+    Bengali,
+    /// ๐๑๒๓๔๕๖๗๘๙
+    Thai,
+    /// ０１２３４５６７８９
+    Fullwidth,
+}
+
+const SCRIPTS: [Script; 7] = [
+    Script::Latin,
+    Script::ArabicIndic,
+    Script::ExtendedArabicIndic,
+    Script::Devanagari,
+    Script::Bengali,
+    Script::Thai,
+    Script::Fullwidth,
+];
+
+impl Script {
+    fn zero(self) -> u32 {
+        match self {
+            Script::Latin => '0' as u32,
+            Script::ArabicIndic => 0x0660,
+            Script::ExtendedArabicIndic => 0x06f0,
+            Script::Devanagari => 0x0966,
+            Script::Bengali => 0x09e6,
+            Script::Thai => 0x0e50,
+            Script::Fullwidth => 0xff10,
+        }
+    }
+
+    /// The character for a decimal digit, or `None` if it is not below 10.
+    pub fn digit(self, d: u32) -> Option<char> {
+        if d > 9 {
+            return None;
+        }
+        char::from_u32(self.zero() + d)
+    }
+
+    /// The script and value of a decimal digit character.
+    pub fn of(c: char) -> Option<(Script, u32)> {
+        SCRIPTS.into_iter().find_map(|script| {
+            let d = (c as u32).checked_sub(script.zero())?;
+            (d <= 9).then_some((script, d))
+        })
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + Copy,
+{
+    /// Parses decimal digits written in any one of the supported scripts,
+    /// with the same sign handling as `from_str`.
+    pub fn from_unicode_str(s: &str) -> Result<Self, ConversionError> {
+        let mut script = None;
+        let mut ascii = String::with_capacity(s.len());
+        for c in s.chars() {
+            match Script::of(c) {
+                Some((found, d)) => {
+                    if *script.get_or_insert(found) != found {
+                        return Err(ConversionError::Malformed);
+                    }
+                    ascii.push(Script::Latin.digit(d).unwrap());
+                }
+                // Left as is, so parsing reports it at the same position.
+                None => ascii.push(c),
+            }
+        }
+        ascii.parse()
+    }
+
+    pub fn to_string_in(&self, script: Script) -> Result<String, ConversionError> {
+        if self.radix != 10 {
+            return Err(ConversionError::NotDecimal);
+        }
+        let mut s = String::with_capacity(self.values.len() * 3 + 1);
+        if self.sign == Sign::Negative {
+            s.push('-');
+        }
+        s.extend(
+            self.values
+                .iter()
+                .map(|d| script.digit(d.to_u32().unwrap()).unwrap()),
+        );
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn to_string_in_works() {
+        let digits = Digits::from(1_234_567_890u32);
+        let expected = [
+            (Script::Latin, "1234567890"),
+            (Script::ArabicIndic, "١٢٣٤٥٦٧٨٩٠"),
+            (Script::ExtendedArabicIndic, "۱۲۳۴۵۶۷۸۹۰"),
+            (Script::Devanagari, "१२३४५६७८९०"),
+            (Script::Bengali, "১২৩৪৫৬৭৮৯০"),
+            (Script::Thai, "๑๒๓๔๕๖๗๘๙๐"),
+            (Script::Fullwidth, "１２３４５６７８９０"),
+        ];
+        for (script, s) in expected {
+            assert_eq!(digits.to_string_in(script), Ok(s.to_string()));
+        }
+        let digits = Digits::try_from(-42).unwrap();
+        assert_eq!(
+            digits.to_string_in(Script::Devanagari),
+            Ok("-४२".to_string())
+        );
+    }
+
+    #[test]
+    fn to_string_in_throws_error_with_non_decimal_digits() {
+        let digits = Digits::with_radix(42u32, 16).unwrap();
+        assert_eq!(
+            digits.to_string_in(Script::Thai),
+            Err(ConversionError::NotDecimal)
+        );
+    }
+
+    #[test]
+    fn from_unicode_str_works() {
+        for s in ["٠١٢", "०१२", "０１２", "012"] {
+            let digits = Digits::<u8>::from_unicode_str(s).unwrap();
+            assert_eq!(digits.as_slice(), vec![1, 2]);
+        }
+        let digits = Digits::<i32>::from_unicode_str("-۴۲").unwrap();
+        assert_eq!(digits.to_int(), Ok(-42));
+    }
+
+    #[test]
+    fn from_unicode_str_round_trips() {
+        let factorial = crate::factorial_digits(30);
+        for script in SCRIPTS {
+            let s = factorial.to_string_in(script).unwrap();
+            let digits = Digits::<u64>::from_unicode_str(&s).unwrap();
+            assert_eq!(digits.as_slice(), factorial.as_slice());
+        }
+    }
+
+    #[test]
+    fn from_unicode_str_throws_error_with_mixed_scripts() {
+        assert_eq!(
+            Digits::<u8>::from_unicode_str("1٢").map(|d| d.to_int()),
+            Err(ConversionError::Malformed)
+        );
+    }
+
+    #[test]
+    fn from_unicode_str_throws_error_with_invalid_digit() {
+        assert_eq!(
+            Digits::<u8>::from_unicode_str("٤x").map(|d| d.to_int()),
+            Err(ConversionError::InvalidChar {
+                position: 1,
+                value: 'x'
+            })
+        );
+        assert!(Digits::<u8>::from_unicode_str("").is_err());
+    }
+
+    #[test]
+    fn of_works() {
+        assert_eq!(Script::of('७'), Some((Script::Devanagari, 7)));
+        assert_eq!(Script::of('5'), Some((Script::Latin, 5)));
+        assert_eq!(Script::of('a'), None);
+        assert_eq!(Script::Thai.digit(10), None);
+    }
+}
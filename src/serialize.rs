@@ -0,0 +1,290 @@
+//! Serde support for `Digits`.
+//!
+//! By default `Digits` serializes as an array of its decimal digits, with the
+//! first non-zero digit negated for a negative number, so -123 is
+//! `[-1, 2, 3]`. Use `#[serde(with = "digits::serialize::as_string")]` on a
+//! field to store the canonical number string instead, e.g. `"-123"`. Both
+//! representations are decimal only, and every digit is validated on
+//! deserialize.
+//!
+//! Leading zeros only appear in fixed-width numbers, so a value that has
+//! them, like `[0,0,7]` or `"007"`, deserializes in fixed-width mode. A
+//! fixed-width number without leading zeros looks like any other and comes
+//! back in variable width: `Digits::with_width(123, 3)` reads back as 123.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use num_traits::{Num, NumCast};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{self, Serialize, Serializer};
+
+use crate::{Digits, Sign};
+
+impl<T> Serialize for Digits<T>
+where
+    T: Serialize + Num + Copy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        as_array::serialize(self, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Digits<T>
+where
+    T: Deserialize<'de> + Num + NumCast + PartialOrd + Copy,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        as_array::deserialize(deserializer)
+    }
+}
+
+pub mod as_array {
+    use super::*;
+
+    pub fn serialize<T, S>(digits: &Digits<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + Num + Copy,
+        S: Serializer,
+    {
+        if digits.radix != 10 {
+            return Err(ser::Error::custom("only decimal digits can be serialized"));
+        }
+        let signed = match digits.sign {
+            Sign::Negative => digits.values.iter().position(|d| !d.is_zero()),
+            _ => None,
+        };
+        serializer.collect_seq(digits.values.iter().enumerate().map(|(i, &d)| {
+            if Some(i) == signed {
+                T::zero() - d
+            } else {
+                d
+            }
+        }))
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Digits<T>, D::Error>
+    where
+        T: Deserialize<'de> + Num + NumCast + PartialOrd + Copy,
+        D: Deserializer<'de>,
+    {
+        let mut values = Vec::<T>::deserialize(deserializer)?;
+        // A negated first non-zero digit makes the number negative; anything
+        // below -9 is left for `from_radix` to reject.
+        let negative = match values.iter_mut().find(|d| !d.is_zero()) {
+            Some(d) if *d < T::zero() && *d > T::zero() - T::from(10).unwrap() => {
+                *d = T::zero() - *d;
+                true
+            }
+            _ => false,
+        };
+        let fixed_width = values.len() > 1 && values[0].is_zero();
+        let mut digits = Digits::from_radix(values, 10).map_err(de::Error::custom)?;
+        if negative {
+            digits.sign = Sign::Negative;
+        }
+        if fixed_width {
+            digits = digits.into_fixed_width();
+        }
+        Ok(digits)
+    }
+}
+
+pub mod as_string {
+    use super::*;
+
+    pub fn serialize<T, S>(digits: &Digits<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: NumCast + Copy,
+        S: Serializer,
+    {
+        if digits.radix != 10 {
+            return Err(ser::Error::custom("only decimal digits can be serialized"));
+        }
+        serializer.serialize_str(&digits.to_string())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Digits<T>, D::Error>
+    where
+        T: Num + NumCast + PartialOrd + Copy,
+        D: Deserializer<'de>,
+    {
+        // An owned string, as borrowing fails for readers and for strings
+        // with escapes.
+        let s = String::deserialize(deserializer)?;
+        let unsigned = s.strip_prefix('-').unwrap_or(&s);
+        if unsigned.len() > 1 && unsigned.starts_with('0') {
+            return Digits::parse_fixed_width(&s).map_err(de::Error::custom);
+        }
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct AsString {
+        #[serde(with = "as_string")]
+        n: Digits<u8>,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct SignedAsString {
+        #[serde(with = "as_string")]
+        n: Digits<i32>,
+    }
+
+    #[test]
+    fn serialize_works() {
+        let json = serde_json::to_string(&Digits::from(42u32)).unwrap();
+        assert_eq!(json, "[4,2]");
+    }
+
+    #[test]
+    fn serialize_works_with_negative_number() {
+        let json = serde_json::to_string(&Digits::try_from(-42).unwrap()).unwrap();
+        assert_eq!(json, "[-4,2]");
+        let json = serde_json::to_string(&Digits::with_width(-42i32, 4).unwrap()).unwrap();
+        assert_eq!(json, "[0,0,-4,2]");
+        let json = serde_json::to_string(&Digits::try_from(0).unwrap()).unwrap();
+        assert_eq!(json, "[0]");
+    }
+
+    #[test]
+    fn serialize_throws_error_with_non_decimal_radix() {
+        assert!(serde_json::to_string(&Digits::with_radix(5, 2).unwrap()).is_err());
+    }
+
+    #[test]
+    fn deserialize_works() {
+        let digits: Digits<u8> = serde_json::from_str("[4,2]").unwrap();
+        assert_eq!(digits.as_slice(), vec![4, 2]);
+        assert_eq!(digits.radix(), 10);
+    }
+
+    #[test]
+    fn deserialize_works_with_negative_number() {
+        let digits: Digits<i32> = serde_json::from_str("[-4,2]").unwrap();
+        assert_eq!(digits.to_int(), Ok(-42));
+        let digits: Digits<i8> = serde_json::from_str("[0,-1,0]").unwrap();
+        assert_eq!(digits.to_int(), Ok(-10));
+        for n in [-1_000i64, -7, 0, 7, 1_000] {
+            let json = serde_json::to_string(&Digits::try_from(n).unwrap()).unwrap();
+            let back: Digits<i64> = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.to_int(), Ok(n));
+        }
+    }
+
+    #[test]
+    fn deserialize_throws_error_with_invalid_digit() {
+        assert!(serde_json::from_str::<Digits<u8>>("[4,12]").is_err());
+        assert!(serde_json::from_str::<Digits<u8>>("[-4,2]").is_err());
+        assert!(serde_json::from_str::<Digits<i8>>("[4,-1]").is_err());
+        assert!(serde_json::from_str::<Digits<i8>>("[-4,-1]").is_err());
+        assert!(serde_json::from_str::<Digits<i8>>("[-12]").is_err());
+        assert!(serde_json::from_str::<Digits<i8>>("[-128]").is_err());
+        assert!(serde_json::from_str::<Digits<u8>>("[]").is_err());
+    }
+
+    #[test]
+    fn as_string_works() {
+        let value = AsString {
+            n: Digits::from(42u8),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"n":"42"}"#);
+
+        let value: AsString = serde_json::from_str(&json).unwrap();
+        assert_eq!(value.n.as_slice(), vec![4, 2]);
+    }
+
+    #[test]
+    fn as_string_works_with_negative_number() {
+        let value = SignedAsString {
+            n: Digits::try_from(-123).unwrap(),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"n":"-123"}"#);
+
+        let value: SignedAsString = serde_json::from_str(&json).unwrap();
+        assert_eq!(value.n.to_int(), Ok(-123));
+    }
+
+    #[test]
+    fn as_string_works_with_reader() {
+        let json = br#"{"n":"-123"}"#;
+        let value: SignedAsString = serde_json::from_reader(&json[..]).unwrap();
+        assert_eq!(value.n.to_int(), Ok(-123));
+        // Escapes need an owned string too.
+        let value: AsString = serde_json::from_str(r#"{"n":"\u0034\u0032"}"#).unwrap();
+        assert_eq!(value.n.to_int(), Ok(42));
+    }
+
+    #[test]
+    fn as_string_works_beyond_integer_range() {
+        let digits = "9".repeat(100);
+        let json = alloc::format!(r#"{{"n":"{}"}}"#, digits);
+        let value: AsString = serde_json::from_str(&json).unwrap();
+        assert_eq!(value.n.len(), 100);
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    }
+
+    #[test]
+    fn as_string_throws_error_with_invalid_digit() {
+        assert!(serde_json::from_str::<AsString>(r#"{"n":"4x2"}"#).is_err());
+        assert!(serde_json::from_str::<AsString>(r#"{"n":""}"#).is_err());
+        assert!(serde_json::from_str::<AsString>(r#"{"n":[4,2]}"#).is_err());
+    }
+
+    #[test]
+    fn round_trip_works_with_fixed_width() {
+        for digits in [
+            Digits::with_width(7u32, 3).unwrap(),
+            Digits::with_width(0u32, 2).unwrap(),
+        ] {
+            let json = serde_json::to_string(&digits).unwrap();
+            let back: Digits<u32> = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.fixed_width(), digits.fixed_width());
+            assert!(back == digits);
+        }
+        let digits = Digits::with_width(-42i32, 4).unwrap();
+        let back: Digits<i32> =
+            serde_json::from_str(&serde_json::to_string(&digits).unwrap()).unwrap();
+        assert!(back == digits);
+
+        // Without leading zeros the width cannot be told apart.
+        let json = serde_json::to_string(&Digits::with_width(123u32, 3).unwrap()).unwrap();
+        let back: Digits<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.fixed_width(), None);
+        assert_eq!(back.to_int(), Ok(123));
+    }
+
+    #[test]
+    fn as_string_works_with_fixed_width() {
+        let value = SignedAsString {
+            n: Digits::with_width(-7, 3).unwrap(),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"n":"-007"}"#);
+        let back: SignedAsString = serde_json::from_str(&json).unwrap();
+        assert!(back.n == value.n);
+        let back: SignedAsString = serde_json::from_str(r#"{"n":"-7"}"#).unwrap();
+        assert_eq!(back.n.fixed_width(), None);
+    }
+
+    #[test]
+    fn round_trip_works() {
+        let digits = crate::factorial_digits(30);
+        let json = serde_json::to_string(&digits).unwrap();
+        let back: Digits<u64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.as_slice(), digits.as_slice());
+    }
+}
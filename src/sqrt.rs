@@ -0,0 +1,188 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+use num_traits::{Num, NumCast};
+
+use crate::{long_mul, long_sub, strip_leading_zeros, ConversionError, DigitVec, Digits, Sign};
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// The integer square root, worked out digit by digit on the digit
+    /// vector in the same radix, so any length works.
+    pub fn isqrt_digits(&self) -> Result<Digits<T>, ConversionError> {
+        let sqrt = self.sqrt_digits()?;
+        let integer_len = sqrt.integer_len();
+        let values: DigitVec<T> = sqrt.take(integer_len).collect();
+        Ok(Digits::new(values, self.radix))
+    }
+
+    /// The digits of the square root without end, the integer part first and
+    /// then the fractional digits, e.g. 1, 4, 1, 4, 2, ... for 2.
+    pub fn sqrt_digits(&self) -> Result<SqrtDigits<T>, ConversionError> {
+        if self.sign == Sign::Negative {
+            return Err(ConversionError::NegativeInput);
+        }
+        let mut values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        strip_leading_zeros(&mut values);
+        // The digits are brought down in pairs, starting from the radix
+        // point, so an odd length gets a zero in front.
+        if values.len() % 2 == 1 {
+            values.insert(0, 0);
+        }
+        let integer_len = values.len() / 2;
+        values.reverse();
+        Ok(SqrtDigits {
+            pending: values,
+            integer_len,
+            remainder: vec![0],
+            root: vec![0],
+            radix: self.radix as u64,
+            _digit: core::marker::PhantomData,
+        })
+    }
+}
+
+// Compares two magnitudes without leading zeros.
+fn cmp_magnitude(a: &[u64], b: &[u64]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+#[derive(Clone)]
+pub struct SqrtDigits<T> {
+    // The digits still to bring down, least significant first so the next
+    // pair is popped off the end. Zeros follow once they run out.
+    pending: Vec<u64>,
+    integer_len: usize,
+    remainder: Vec<u64>,
+    root: Vec<u64>,
+    radix: u64,
+    _digit: core::marker::PhantomData<T>,
+}
+
+impl<T> SqrtDigits<T> {
+    /// The number of digits before the radix point.
+    pub fn integer_len(&self) -> usize {
+        self.integer_len
+    }
+}
+
+impl<T> Iterator for SqrtDigits<T>
+where
+    T: NumCast,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.radix;
+        for _ in 0..2 {
+            self.remainder.push(self.pending.pop().unwrap_or(0));
+        }
+        strip_leading_zeros(&mut self.remainder);
+
+        // The next digit is the largest x with (2·root·r + x)·x at most the
+        // remainder, where 2·root·r + x is 2·root with x appended.
+        let twice = long_mul(&self.root, &[2], r);
+        let (x, product) = (0..r)
+            .rev()
+            .map(|x| {
+                let mut trial = twice.clone();
+                trial.push(x);
+                (x, long_mul(&trial, &[x], r))
+            })
+            .find(|(_, product)| cmp_magnitude(product, &self.remainder) != Ordering::Greater)
+            .unwrap();
+
+        self.remainder = long_sub(&self.remainder, &product, r);
+        strip_leading_zeros(&mut self.remainder);
+        self.root.push(x);
+        strip_leading_zeros(&mut self.root);
+        Some(T::from(x).unwrap())
+    }
+}
+
+impl<T> FusedIterator for SqrtDigits<T> where T: NumCast {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt_digits_works() {
+        for n in 0u64..3_000 {
+            let root = Digits::from(n).isqrt_digits().unwrap();
+            assert_eq!(root.to_int(), Ok(n.isqrt()));
+        }
+        let root = Digits::from(u64::MAX).isqrt_digits().unwrap();
+        assert_eq!(root.to_int(), Ok(u64::MAX.isqrt()));
+    }
+
+    #[test]
+    fn isqrt_digits_works_with_radix() {
+        for radix in [2, 3, 16, 36] {
+            for n in 0u32..1_000 {
+                let root = Digits::with_radix(n, radix)
+                    .unwrap()
+                    .isqrt_digits()
+                    .unwrap();
+                assert_eq!(root.radix(), radix);
+                assert_eq!(root.to_int(), Ok(n.isqrt()));
+            }
+        }
+    }
+
+    #[test]
+    fn isqrt_digits_works_beyond_integer_range() {
+        let square = crate::factorial_digits(60) * crate::factorial_digits(60);
+        let root = square.isqrt_digits().unwrap();
+        assert!(root == crate::factorial_digits(60));
+        let below = &square - &Digits::from(1u64);
+        assert!(below.isqrt_digits().unwrap() == &root - &Digits::from(1u64));
+    }
+
+    #[test]
+    fn isqrt_digits_ignores_leading_zeros() {
+        let digits = Digits::<u8>::parse_with_leading_zeros("00049").unwrap();
+        assert_eq!(digits.isqrt_digits().unwrap().as_slice(), vec![7]);
+    }
+
+    #[test]
+    fn isqrt_digits_throws_error_with_negative_number() {
+        assert!(matches!(
+            Digits::try_from(-4).unwrap().isqrt_digits(),
+            Err(ConversionError::NegativeInput)
+        ));
+    }
+
+    #[test]
+    fn sqrt_digits_works() {
+        let expected = "1414213562373095048801688724209698078569671875376948073176679737990732478462107038850387534327641572";
+        let sqrt = Digits::from(2u32).sqrt_digits().unwrap();
+        assert_eq!(sqrt.integer_len(), 1);
+        let digits: Vec<u32> = sqrt.take(100).collect();
+        let expected: Vec<u32> = expected.chars().map(|c| c.to_digit(10).unwrap()).collect();
+        assert_eq!(digits, expected);
+    }
+
+    #[test]
+    fn sqrt_digits_works_with_perfect_squares() {
+        let sqrt = Digits::from(15_129u32).sqrt_digits().unwrap();
+        assert_eq!(sqrt.integer_len(), 3);
+        let digits: Vec<u32> = sqrt.take(8).collect();
+        assert_eq!(digits, vec![1, 2, 3, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn sqrt_digits_works_with_radix() {
+        // √2 is 1.0110101000001001111... in binary.
+        let sqrt = Digits::with_radix(2u8, 2).unwrap().sqrt_digits().unwrap();
+        let digits: Vec<u8> = sqrt.take(20).collect();
+        assert_eq!(
+            digits,
+            vec![1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1]
+        );
+    }
+}
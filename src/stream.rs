@@ -0,0 +1,217 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::str::Chars;
+#[cfg(feature = "std")]
+use std::io::{self, BufReader, Bytes, Read};
+
+use num_traits::NumCast;
+
+use crate::{radix_constant, ConversionError};
+
+/// Digits read lazily from text, e.g. a thousand digit number spread over
+/// many lines, without building the whole number. Separators are skipped,
+/// ASCII whitespace by default, and anything else that is not a digit in the
+/// radix is an error.
+#[derive(Clone)]
+pub struct DigitStream<S, T> {
+    source: S,
+    radix: u32,
+    separators: Vec<char>,
+    position: usize,
+    failed: bool,
+    _digit: PhantomData<T>,
+}
+
+impl<'a, T> DigitStream<Chars<'a>, T> {
+    pub fn new(s: &'a str) -> Self {
+        Self::with_source(s.chars())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, T> DigitStream<Bytes<BufReader<R>>, T>
+where
+    R: Read,
+{
+    /// Reads the digits from `reader` through a buffer, one byte per
+    /// character, so the text should be ASCII.
+    pub fn from_reader(reader: R) -> Self {
+        Self::with_source(BufReader::new(reader).bytes())
+    }
+}
+
+impl<S, T> DigitStream<S, T> {
+    fn with_source(source: S) -> Self {
+        DigitStream {
+            source,
+            radix: 10,
+            separators: alloc::vec![' ', '\t', '\r', '\n'],
+            position: 0,
+            failed: false,
+            _digit: PhantomData,
+        }
+    }
+
+    pub fn with_radix(mut self, radix: u32) -> Result<Self, ConversionError> {
+        radix_constant::<u32>(radix)?;
+        self.radix = radix;
+        Ok(self)
+    }
+
+    /// Replaces the characters that are skipped, e.g. `&[',', '\n']`.
+    pub fn with_separators(mut self, separators: &[char]) -> Self {
+        self.separators = separators.to_vec();
+        self
+    }
+
+    // The digit for `c`, or `None` for a separator. The stream stops after
+    // the first error, since what follows is unlikely to make sense.
+    fn digit(&mut self, c: char) -> Option<Result<T, ConversionError>>
+    where
+        T: NumCast,
+    {
+        let position = self.position;
+        self.position += 1;
+        if self.separators.contains(&c) {
+            return None;
+        }
+        match c.to_digit(self.radix) {
+            Some(d) => {
+                Some(T::from(d).ok_or(ConversionError::overflow(u32::BITS - d.leading_zeros())))
+            }
+            None => Some(Err(ConversionError::InvalidChar { position, value: c })),
+        }
+        .inspect(|result| self.failed = result.is_err())
+    }
+}
+
+impl<T> Iterator for DigitStream<Chars<'_>, T>
+where
+    T: NumCast,
+{
+    type Item = Result<T, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            let c = self.source.next()?;
+            if let Some(digit) = self.digit(c) {
+                return Some(digit);
+            }
+        }
+        None
+    }
+}
+
+impl<T> FusedIterator for DigitStream<Chars<'_>, T> where T: NumCast {}
+
+/// Invalid characters come out as `InvalidData` errors wrapping the
+/// `ConversionError`.
+#[cfg(feature = "std")]
+impl<R, T> Iterator for DigitStream<Bytes<BufReader<R>>, T>
+where
+    R: Read,
+    T: NumCast,
+{
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            let byte = match self.source.next()? {
+                Ok(byte) => byte,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            };
+            if let Some(digit) = self.digit(byte as char) {
+                return Some(digit.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Digits;
+    use alloc::string::String;
+    use alloc::vec;
+
+    #[test]
+    fn digit_stream_works() {
+        let digits: Result<Vec<u8>, _> = DigitStream::new("123 456\n789").collect();
+        assert_eq!(digits, Ok(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        assert_eq!(DigitStream::<_, u8>::new("").count(), 0);
+    }
+
+    #[test]
+    fn digit_stream_works_with_text_blocks() {
+        // A thousand digits over twenty lines, summed without ever holding
+        // the number.
+        let line = "73167176531330624919225119674426574742355349194934\n";
+        let block: String = line.repeat(20);
+        let sum: u32 = DigitStream::<_, u32>::new(&block).map(|d| d.unwrap()).sum();
+        let line_sum: u32 = line.trim().chars().map(|c| c.to_digit(10).unwrap()).sum();
+        assert_eq!(sum, 20 * line_sum);
+        let digits: Vec<u8> = DigitStream::new(&block).collect::<Result<_, _>>().unwrap();
+        assert_eq!(Digits::from_radix(digits, 10).unwrap().len(), 1_000);
+    }
+
+    #[test]
+    fn digit_stream_works_with_separators_and_radix() {
+        let stream = DigitStream::new("ff,0a").with_separators(&[',']);
+        let digits: Result<Vec<u8>, _> = stream.with_radix(16).unwrap().collect();
+        assert_eq!(digits, Ok(vec![15, 15, 0, 10]));
+        assert!(DigitStream::<_, u8>::new("1").with_radix(37).is_err());
+    }
+
+    #[test]
+    fn digit_stream_throws_error_with_invalid_char() {
+        let mut stream = DigitStream::<_, u8>::new("12 3x45");
+        assert_eq!(stream.by_ref().take(3).count(), 3);
+        assert_eq!(
+            stream.next(),
+            Some(Err(ConversionError::InvalidChar {
+                position: 4,
+                value: 'x'
+            }))
+        );
+        assert_eq!(stream.next(), None);
+        let commas: Result<Vec<u8>, _> = DigitStream::new("1,2").collect();
+        assert!(commas.is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn digit_stream_works_with_reader() {
+        let reader = io::Cursor::new("31 41\n59");
+        let digits: io::Result<Vec<u8>> = DigitStream::from_reader(reader).collect();
+        assert_eq!(digits.unwrap(), vec![3, 1, 4, 1, 5, 9]);
+
+        let mut stream = DigitStream::<_, u8>::from_reader(&b"1?"[..]);
+        assert_eq!(stream.next().unwrap().unwrap(), 1);
+        let error = stream.next().unwrap().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "invalid digit '?' at position 1");
+        assert!(stream.next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn digit_stream_passes_on_read_errors() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+        let mut stream = DigitStream::<_, u8>::from_reader(Failing);
+        assert_eq!(
+            stream.next().unwrap().unwrap_err().to_string(),
+            "disk on fire"
+        );
+        assert!(stream.next().is_none());
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use num_traits::{Num, NumCast};
+
+use crate::{
+    from_mixed_radix, long_sub, strip_leading_zeros, to_mixed_radix, ConversionError, DigitVec,
+    Digits, Sign,
+};
+
+/// A number in balanced ternary, with trits -1, 0 and 1 most significant
+/// first. Negative numbers need no sign, they just lead with -1.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BalancedTernary {
+    trits: Vec<i8>,
+}
+
+impl BalancedTernary {
+    pub fn from_trits(trits: Vec<i8>) -> Result<Self, ConversionError> {
+        if trits.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        if let Some(position) = trits.iter().position(|t| !(-1..=1).contains(t)) {
+            return Err(ConversionError::invalid_digit(position, trits[position]));
+        }
+        Ok(Self { trits })
+    }
+
+    pub fn trits(&self) -> &[i8] {
+        &self.trits
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    pub fn to_balanced_ternary(&self) -> BalancedTernary {
+        let values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        let ternary = to_mixed_radix(&values, self.radix as u64, |_| 3);
+
+        // A plain ternary 2 is 3 - 1, so it becomes -1 with a carry.
+        let mut trits = Vec::with_capacity(ternary.len() + 1);
+        let mut carry = 0;
+        for &d in ternary.iter().rev() {
+            let t = d as i8 + carry;
+            carry = (t > 1) as i8;
+            trits.push(t - 3 * carry);
+        }
+        if carry > 0 {
+            trits.push(carry);
+        }
+        if self.sign == Sign::Negative {
+            trits.iter_mut().for_each(|t| *t = -*t);
+        }
+        trits.reverse();
+        BalancedTernary { trits }
+    }
+
+    /// The decimal digits of a balanced ternary number.
+    pub fn from_balanced_ternary(ternary: &BalancedTernary) -> Self {
+        // Split into the sums of the +1 and the -1 trits, which are plain
+        // ternary numbers, and subtract the smaller from the larger.
+        let part = |trit: i8| {
+            let ones: Vec<u64> = ternary.trits.iter().map(|&t| (t == trit) as u64).collect();
+            from_mixed_radix(&ones, 10, |_| 3)
+        };
+        let (positive, negative) = (part(1), part(-1));
+        let ordering = positive
+            .len()
+            .cmp(&negative.len())
+            .then_with(|| positive.cmp(&negative));
+        let (mut values, is_negative) = match ordering {
+            Ordering::Less => (long_sub(&negative, &positive, 10), true),
+            _ => (long_sub(&positive, &negative, 10), false),
+        };
+        strip_leading_zeros(&mut values);
+
+        let values: DigitVec<T> = values.into_iter().map(|d| T::from(d).unwrap()).collect();
+        Self::new_signed(values, 10, is_negative)
+    }
+}
+
+impl fmt::Display for BalancedTernary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let trits: String = self
+            .trits
+            .iter()
+            .map(|t| match t {
+                -1 => 'T',
+                0 => '0',
+                _ => '1',
+            })
+            .collect();
+        f.pad(&trits)
+    }
+}
+
+impl FromStr for BalancedTernary {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trits = s
+            .chars()
+            .enumerate()
+            .map(|(position, c)| match c {
+                'T' | 't' => Ok(-1),
+                '0' => Ok(0),
+                '1' => Ok(1),
+                _ => Err(ConversionError::InvalidChar { position, value: c }),
+            })
+            .collect::<Result<Vec<i8>, _>>()?;
+        Self::from_trits(trits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn to_balanced_ternary_works() {
+        let expected = [
+            (0i32, "0"),
+            (1, "1"),
+            (2, "1T"),
+            (3, "10"),
+            (8, "10T"),
+            (-1, "T"),
+            (-8, "T01"),
+            (35, "110T"),
+        ];
+        for (n, s) in expected {
+            let digits = Digits::try_from(n).unwrap();
+            assert_eq!(digits.to_balanced_ternary().to_string(), s);
+        }
+        assert_eq!(Digits::from(8u32).to_balanced_ternary().trits(), [1, 0, -1]);
+    }
+
+    #[test]
+    fn to_balanced_ternary_works_with_radix() {
+        let digits = Digits::with_radix(35u32, 2).unwrap();
+        assert_eq!(digits.to_balanced_ternary().to_string(), "110T");
+    }
+
+    #[test]
+    fn from_balanced_ternary_works() {
+        let ternary: BalancedTernary = "110T".parse().unwrap();
+        assert_eq!(Digits::from_balanced_ternary(&ternary).to_int(), Ok(35u32));
+        let ternary: BalancedTernary = "T01".parse().unwrap();
+        assert_eq!(Digits::from_balanced_ternary(&ternary).to_int(), Ok(-8i32));
+        let ternary = BalancedTernary::from_trits(vec![0, 0]).unwrap();
+        assert_eq!(
+            Digits::<u8>::from_balanced_ternary(&ternary).as_slice(),
+            [0]
+        );
+    }
+
+    #[test]
+    fn from_balanced_ternary_round_trips() {
+        for n in -2_000i32..2_000 {
+            let ternary = Digits::try_from(n).unwrap().to_balanced_ternary();
+            assert_eq!(Digits::from_balanced_ternary(&ternary).to_int(), Ok(n));
+        }
+        let factorial = crate::factorial_digits(40);
+        let ternary = factorial.to_balanced_ternary();
+        assert_eq!(
+            Digits::<u64>::from_balanced_ternary(&ternary).as_slice(),
+            factorial.as_slice()
+        );
+    }
+
+    #[test]
+    fn from_trits_throws_error_with_invalid_trit() {
+        assert_eq!(
+            BalancedTernary::from_trits(vec![1, 2]),
+            Err(ConversionError::InvalidDigit {
+                position: 1,
+                value: 2
+            })
+        );
+        assert!(BalancedTernary::from_trits(vec![]).is_err());
+    }
+
+    #[test]
+    fn from_str_throws_error_with_invalid_char() {
+        assert_eq!(
+            "1T2".parse::<BalancedTernary>(),
+            Err(ConversionError::InvalidChar {
+                position: 2,
+                value: '2'
+            })
+        );
+    }
+}
@@ -0,0 +1,1386 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Num, NumCast};
+
+use crate::{
+    long_add, long_sub, radix_constant, strip_leading_zeros, to_mixed_radix, ConversionError,
+    DigitVec, Digits, Sign,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeadingZeros {
+    Strip,
+    Keep,
+}
+
+impl<T> Digits<T>
+where
+    T: Num + Copy,
+{
+    /// Reverses the digits in place. Zeros that end up leading are dropped,
+    /// so 120 becomes 21, unless the digits have a fixed width.
+    pub fn reverse(&mut self) {
+        self.values.reverse();
+        if !self.fixed_width {
+            strip_leading_zeros(&mut self.values);
+        }
+    }
+
+    pub fn reversed(&self) -> Digits<T> {
+        let mut digits = self.clone();
+        digits.reverse();
+        digits
+    }
+
+    pub fn is_palindrome(&self) -> bool {
+        let half = self.values.len() / 2;
+        self.values[..half]
+            .iter()
+            .eq(self.values[self.values.len() - half..].iter().rev())
+    }
+
+    /// Rotates the digits left by `k`. Digits with a fixed width always keep
+    /// their leading zeros.
+    pub fn rotate_left(&self, k: usize, zeros: LeadingZeros) -> Digits<T> {
+        let mut digits = self.clone();
+        let k = k % digits.values.len();
+        digits.values.rotate_left(k);
+        if zeros == LeadingZeros::Strip && !self.fixed_width {
+            strip_leading_zeros(&mut digits.values);
+        }
+        digits
+    }
+
+    pub fn rotate_right(&self, k: usize, zeros: LeadingZeros) -> Digits<T> {
+        let k = k % self.values.len();
+        self.rotate_left(self.values.len() - k, zeros)
+    }
+
+    /// Appends the digits of `other`, including any leading zeros it carries.
+    /// The result takes the sign of `self`; `other` must not be negative.
+    pub fn concat(&self, other: &Digits<T>) -> Result<Digits<T>, ConversionError> {
+        if self.radix != other.radix {
+            return Err(ConversionError::RadixMismatch {
+                expected: self.radix,
+                found: other.radix,
+            });
+        }
+        if other.sign == Sign::Negative {
+            return Err(ConversionError::NegativeInput);
+        }
+        let mut values = self.values.clone();
+        values.extend_from_slice(&other.values);
+        Ok(Digits::new_signed(
+            values,
+            self.radix,
+            self.sign == Sign::Negative,
+        ))
+    }
+
+    /// Splits after the first `idx` digits. The right part keeps its leading
+    /// zeros so that concatenating the halves gives back the original digits,
+    /// and an empty half is returned as zero. Panics if `idx > len`.
+    pub fn split_at(&self, idx: usize) -> (Digits<T>, Digits<T>) {
+        let (left, right) = self.values.split_at(idx);
+        let part = |values: &[T]| {
+            if values.is_empty() {
+                vec![T::zero()]
+            } else {
+                values.to_vec()
+            }
+        };
+        (
+            Digits::new_signed(part(left), self.radix, self.sign == Sign::Negative),
+            Digits::new(part(right), self.radix),
+        )
+    }
+
+    /// All rotations of the number, starting with the number itself and
+    /// rotating left by one digit each step.
+    pub fn rotations(&self, zeros: LeadingZeros) -> Rotations<'_, T> {
+        Rotations {
+            digits: self,
+            zeros,
+            next: 0,
+        }
+    }
+
+    // The number formed by the digits in `range`, keeping the sign, and the
+    // leading zeros if the width is fixed.
+    fn truncated(&self, range: core::ops::Range<usize>) -> Digits<T> {
+        let mut values = self.values[range].to_vec();
+        if !self.fixed_width {
+            strip_leading_zeros(&mut values);
+        }
+        let mut digits = Digits::new_signed(values, self.radix, self.sign == Sign::Negative);
+        digits.fixed_width = self.fixed_width;
+        digits
+    }
+
+    /// The number without its most significant digit, e.g. 797 for 3797, or
+    /// `None` for a single digit. Zeros that end up leading are dropped.
+    pub fn truncate_left(&self) -> Option<Digits<T>> {
+        let len = self.values.len();
+        (len > 1).then(|| self.truncated(1..len))
+    }
+
+    /// The number without its least significant digit, e.g. 379 for 3797, or
+    /// `None` for a single digit.
+    pub fn truncate_right(&self) -> Option<Digits<T>> {
+        let len = self.values.len();
+        (len > 1).then(|| self.truncated(0..len - 1))
+    }
+
+    /// The number followed by each of its left truncations, down to the last
+    /// digit: 3797, 797, 97, 7. There is one per digit, so a zero digit gives
+    /// the same number twice, as in 307, 7, 7.
+    pub fn left_truncations(&self) -> Truncations<'_, T> {
+        Truncations {
+            digits: self,
+            from_left: true,
+            next: 0,
+        }
+    }
+
+    /// The number followed by each of its right truncations, down to the
+    /// first digit: 3797, 379, 37, 3.
+    pub fn right_truncations(&self) -> Truncations<'_, T> {
+        Truncations {
+            digits: self,
+            from_left: false,
+            next: 0,
+        }
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// The digits in ascending order. Zeros that end up leading are dropped,
+    /// unless the digits have a fixed width.
+    pub fn sorted_ascending(&self) -> Digits<T> {
+        let mut digits = self.clone();
+        sort(&mut digits.values);
+        if !self.fixed_width {
+            strip_leading_zeros(&mut digits.values);
+        }
+        digits
+    }
+
+    pub fn sorted_descending(&self) -> Digits<T> {
+        let mut digits = self.clone();
+        sort(&mut digits.values);
+        digits.values.reverse();
+        digits
+    }
+
+    /// Iterates Kaprekar's routine: each step subtracts the ascending
+    /// arrangement of the digits from the descending one, keeping the
+    /// original width, until the sequence reaches a fixed point (6174 for
+    /// four digits) or starts to cycle.
+    pub fn kaprekar_iter(&self) -> KaprekarIter<T> {
+        KaprekarIter {
+            current: self.values.to_vec(),
+            radix: self.radix,
+            seen: Vec::new(),
+        }
+    }
+
+    /// Checks whether both numbers use the same digits the same number of
+    /// times, by comparing digit counts rather than sorting.
+    pub fn is_anagram_of(&self, other: &Digits<T>) -> bool {
+        self.radix == other.radix
+            && self.values.len() == other.values.len()
+            && self.counts() == other.counts()
+    }
+
+    /// The digit multiset, for grouping numbers into anagram classes.
+    pub fn signature(&self) -> DigitSignature {
+        DigitSignature {
+            radix: self.radix,
+            counts: self.counts(),
+        }
+    }
+
+    /// The reflected binary Gray code of the number, as base 2 digits.
+    pub fn to_gray(&self) -> Result<Digits<T>, ConversionError> {
+        if self.sign == Sign::Negative {
+            return Err(ConversionError::NegativeInput);
+        }
+        let values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        let binary = to_mixed_radix(&values, self.radix as u64, |_| 2);
+        let gray: DigitVec<T> = (0..binary.len())
+            .map(|i| match i {
+                0 => binary[0],
+                _ => binary[i - 1] ^ binary[i],
+            })
+            .map(|b| T::from(b).unwrap())
+            .collect();
+        Ok(Digits::new(gray, 2))
+    }
+
+    /// Decodes base 2 Gray code digits back into plain binary digits.
+    pub fn from_gray(&self) -> Result<Digits<T>, ConversionError> {
+        if self.radix != 2 {
+            return Err(ConversionError::RadixMismatch {
+                expected: 2,
+                found: self.radix,
+            });
+        }
+        let mut bit = T::zero();
+        let mut binary: Vec<T> = self
+            .values
+            .iter()
+            .map(|&g| {
+                bit = if g == bit { T::zero() } else { T::one() };
+                bit
+            })
+            .collect();
+        strip_leading_zeros(&mut binary);
+        Ok(Digits::new(binary, 2))
+    }
+
+    /// The sequence n, n + reverse(n), ... without end. The additions are
+    /// done on the digit vectors, so the terms are never limited by `T`.
+    pub fn reverse_and_add_iter(&self) -> ReverseAndAdd<T> {
+        let mut current = self.clone();
+        strip_leading_zeros(&mut current.values);
+        ReverseAndAdd { current }
+    }
+
+    /// Checks whether no palindrome appears within `max_iterations` steps of
+    /// reverse-and-add, as for 196.
+    pub fn is_lychrel_candidate(&self, max_iterations: usize) -> bool {
+        !self
+            .reverse_and_add_iter()
+            .skip(1)
+            .take(max_iterations)
+            .any(|digits| digits.is_palindrome())
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + PartialOrd + Copy,
+{
+    /// Rearranges the digits into the next greater permutation and returns
+    /// true, or returns false and leaves them untouched if they are already
+    /// in descending order.
+    pub fn next_permutation(&mut self) -> bool {
+        next_permutation(&mut self.values)
+    }
+
+    /// Rearranges the digits into the next smaller permutation and returns
+    /// true, or returns false if they are already in ascending order. The
+    /// result may have leading zeros, so the width is preserved.
+    pub fn prev_permutation(&mut self) -> bool {
+        prev_permutation(&mut self.values)
+    }
+
+    /// Iterates the distinct permutations of the digits in ascending
+    /// lexicographic order. Every permutation keeps the original width, so
+    /// those starting with zero are included with their leading zeros.
+    pub fn permutations(&self) -> Permutations<T> {
+        let mut first = self.clone();
+        sort(&mut first.values);
+        Permutations { next: Some(first) }
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// The smallest palindrome greater than the number, built by mirroring
+    /// its first half rather than testing the numbers in between.
+    pub fn next_palindrome(&self) -> Digits<T> {
+        let radix = radix_constant(self.radix).expect("radix was checked on construction");
+        let magnitude = significant(&self.values);
+        match self.sign {
+            Sign::Negative => {
+                let values = prev_palindrome(magnitude, radix);
+                Digits::new_signed(values, self.radix, true)
+            }
+            _ => Digits::new(next_palindrome(magnitude, radix), self.radix),
+        }
+    }
+
+    /// The largest palindrome less than the number, or `None` below zero for
+    /// an unsigned digit type.
+    pub fn prev_palindrome(&self) -> Option<Digits<T>> {
+        let radix = radix_constant(self.radix).expect("radix was checked on construction");
+        let magnitude = significant(&self.values);
+        match self.sign {
+            Sign::Positive => Some(Digits::new(prev_palindrome(magnitude, radix), self.radix)),
+            _ => {
+                T::from(-1)?;
+                let values = next_palindrome(magnitude, radix);
+                Some(Digits::new_signed(values, self.radix, true))
+            }
+        }
+    }
+
+    /// Iterates the palindromes with exactly `len` digits in `radix` in
+    /// ascending order, mirroring each possible first half.
+    pub fn palindromes(len: usize, radix: u32) -> Result<Palindromes<T>, ConversionError> {
+        let radix_value = radix_constant(radix)?;
+        if len == 0 {
+            return Err(ConversionError::Empty);
+        }
+        let mut half = vec![T::zero(); len.div_ceil(2)];
+        if len > 1 {
+            half[0] = T::one();
+        }
+        Ok(Palindromes {
+            half: Some(half),
+            len,
+            radix,
+            radix_value,
+        })
+    }
+}
+
+fn significant<T>(values: &[T]) -> &[T]
+where
+    T: Num,
+{
+    let zeros = values.iter().take_while(|d| d.is_zero()).count();
+    &values[zeros.min(values.len().saturating_sub(1))..]
+}
+
+// The `len` digits whose first half is `half`.
+fn mirrored<T>(half: &[T], len: usize) -> Vec<T>
+where
+    T: Copy,
+{
+    let mut values = half.to_vec();
+    values.extend(half[..len / 2].iter().rev());
+    values
+}
+
+// Adds one to the digits in place, returning whether it carried out.
+fn increment<T>(values: &mut [T], radix: T) -> bool
+where
+    T: Num + Copy,
+{
+    for d in values.iter_mut().rev() {
+        *d = *d + T::one();
+        if *d != radix {
+            return false;
+        }
+        *d = T::zero();
+    }
+    true
+}
+
+fn next_palindrome<T>(values: &[T], radix: T) -> Vec<T>
+where
+    T: Num + PartialOrd + Copy,
+{
+    let len = values.len();
+    let mut half = values[..len.div_ceil(2)].to_vec();
+    let candidate = mirrored(&half, len);
+    if candidate[..] > values[..] {
+        return candidate;
+    }
+    if increment(&mut half, radix) {
+        // The half was all top digits, so the next one is 10...01.
+        let mut values = vec![T::zero(); len + 1];
+        values[0] = T::one();
+        values[len] = T::one();
+        return values;
+    }
+    mirrored(&half, len)
+}
+
+// Assumes the digits are not zero.
+fn prev_palindrome<T>(values: &[T], radix: T) -> Vec<T>
+where
+    T: Num + PartialOrd + Copy,
+{
+    let len = values.len();
+    let mut half = values[..len.div_ceil(2)].to_vec();
+    let candidate = mirrored(&half, len);
+    if candidate[..] < values[..] {
+        return candidate;
+    }
+    let i = half.iter().rposition(|d| !d.is_zero()).unwrap();
+    half[i] = half[i] - T::one();
+    for d in &mut half[i + 1..] {
+        *d = radix - T::one();
+    }
+    if len > 1 && half[0].is_zero() {
+        // The half was 10...0, so the previous one is all top digits.
+        return vec![radix - T::one(); len - 1];
+    }
+    mirrored(&half, len)
+}
+
+fn next_permutation<T>(values: &mut [T]) -> bool
+where
+    T: PartialOrd,
+{
+    let Some(i) = (1..values.len()).rev().find(|&i| values[i - 1] < values[i]) else {
+        return false;
+    };
+    let j = (i..values.len())
+        .rev()
+        .find(|&j| values[j] > values[i - 1])
+        .unwrap();
+    values.swap(i - 1, j);
+    values[i..].reverse();
+    true
+}
+
+fn prev_permutation<T>(values: &mut [T]) -> bool
+where
+    T: PartialOrd,
+{
+    let Some(i) = (1..values.len()).rev().find(|&i| values[i - 1] > values[i]) else {
+        return false;
+    };
+    let j = (i..values.len())
+        .rev()
+        .find(|&j| values[j] < values[i - 1])
+        .unwrap();
+    values.swap(i - 1, j);
+    values[i..].reverse();
+    true
+}
+
+#[derive(Clone)]
+pub struct Palindromes<T> {
+    half: Option<Vec<T>>,
+    len: usize,
+    radix: u32,
+    radix_value: T,
+}
+
+impl<T> Iterator for Palindromes<T>
+where
+    T: Num + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let half = self.half.as_mut()?;
+        let current = Digits::new(mirrored(half, self.len), self.radix);
+        if increment(half, self.radix_value) {
+            self.half = None;
+        }
+        Some(current)
+    }
+}
+
+impl<T> FusedIterator for Palindromes<T> where T: Num + Copy {}
+
+#[derive(Clone)]
+pub struct Permutations<T> {
+    next: Option<Digits<T>>,
+}
+
+impl<T> Permutations<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + CheckedAdd + CheckedMul + CheckedSub,
+{
+    /// Yields the permutations as integers instead of `Digits`.
+    pub fn ints(self) -> impl Iterator<Item = Result<T, ConversionError>> {
+        self.map(|digits| digits.to_int())
+    }
+}
+
+impl<T> Iterator for Permutations<T>
+where
+    T: Num + PartialOrd + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        if next.next_permutation() {
+            self.next = Some(next);
+        }
+        Some(current)
+    }
+}
+
+impl<T> FusedIterator for Permutations<T> where T: Num + PartialOrd + Copy {}
+
+fn sort<T>(values: &mut [T])
+where
+    T: PartialOrd,
+{
+    values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+}
+
+#[derive(Clone)]
+pub struct KaprekarIter<T> {
+    current: Vec<T>,
+    radix: u32,
+    seen: Vec<Vec<T>>,
+}
+
+impl<T> Iterator for KaprekarIter<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut ascending = self.current.clone();
+        sort(&mut ascending);
+        let descending: Vec<T> = ascending.iter().rev().copied().collect();
+
+        let next = long_sub(&descending, &ascending, T::from(self.radix).unwrap());
+        if self.seen.contains(&next) {
+            return None;
+        }
+        self.seen.push(next.clone());
+        self.current = next.clone();
+
+        let mut values = next;
+        strip_leading_zeros(&mut values);
+        Some(Digits::new(values, self.radix))
+    }
+}
+
+impl<T> FusedIterator for KaprekarIter<T> where T: Num + NumCast + PartialOrd + Copy {}
+
+/// Two numbers have equal signatures exactly when one is an anagram of the
+/// other.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DigitSignature {
+    radix: u32,
+    counts: Vec<usize>,
+}
+
+#[derive(Clone)]
+pub struct ReverseAndAdd<T> {
+    current: Digits<T>,
+}
+
+impl<T> Iterator for ReverseAndAdd<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reversed: Vec<T> = self.current.values.iter().rev().copied().collect();
+        let sum = long_add(
+            &self.current.values,
+            &reversed,
+            T::from(self.current.radix).unwrap(),
+        );
+        let next = Digits::new_signed(sum, self.current.radix, self.current.sign == Sign::Negative);
+        Some(core::mem::replace(&mut self.current, next))
+    }
+}
+
+impl<T> FusedIterator for ReverseAndAdd<T> where T: Num + NumCast + PartialOrd + Copy {}
+
+#[derive(Clone)]
+pub struct Rotations<'a, T> {
+    digits: &'a Digits<T>,
+    zeros: LeadingZeros,
+    next: usize,
+}
+
+impl<T> Iterator for Rotations<'_, T>
+where
+    T: Num + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.digits.values.len() {
+            return None;
+        }
+        self.next += 1;
+        Some(self.digits.rotate_left(self.next - 1, self.zeros))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.digits.values.len() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Rotations<'_, T> where T: Num + Copy {}
+
+impl<T> FusedIterator for Rotations<'_, T> where T: Num + Copy {}
+
+#[derive(Clone)]
+pub struct Truncations<'a, T> {
+    digits: &'a Digits<T>,
+    from_left: bool,
+    next: usize,
+}
+
+impl<T> Iterator for Truncations<'_, T>
+where
+    T: Num + Copy,
+{
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.digits.values.len();
+        if self.next >= len {
+            return None;
+        }
+        let k = self.next;
+        self.next += 1;
+        Some(match self.from_left {
+            true => self.digits.truncated(k..len),
+            false => self.digits.truncated(0..len - k),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.digits.values.len() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Truncations<'_, T> where T: Num + Copy {}
+
+impl<T> FusedIterator for Truncations<'_, T> where T: Num + Copy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reverse_works() {
+        let mut digits = Digits::from(123u32);
+        digits.reverse();
+        assert_eq!(digits.as_slice(), vec![3, 2, 1]);
+        assert_eq!(digits.to_int(), Ok(321));
+    }
+
+    #[test]
+    fn reverse_drops_leading_zeros() {
+        let mut digits = Digits::from(1200u32);
+        digits.reverse();
+        assert_eq!(digits.as_slice(), vec![2, 1]);
+
+        let mut digits = Digits::from(0u32);
+        digits.reverse();
+        assert_eq!(digits.as_slice(), vec![0]);
+    }
+
+    #[test]
+    fn reverse_keeps_sign_and_radix() {
+        let mut digits = Digits::try_from(-123).unwrap();
+        digits.reverse();
+        assert_eq!(digits.to_int(), Ok(-321));
+
+        let mut digits = Digits::with_radix(0b1101, 2).unwrap();
+        digits.reverse();
+        assert_eq!(digits.to_int(), Ok(0b1011));
+    }
+
+    #[test]
+    fn reversed_works() {
+        let digits = Digits::from(4_711u32);
+        assert_eq!(digits.reversed().as_slice(), vec![1, 1, 7, 4]);
+        assert_eq!(digits.as_slice(), vec![4, 7, 1, 1]);
+        assert_eq!(Digits::from(10u32).reversed().as_slice(), vec![1]);
+    }
+
+    #[test]
+    fn is_palindrome_works() {
+        assert!(Digits::from(0u32).is_palindrome());
+        assert!(Digits::from(7u32).is_palindrome());
+        assert!(Digits::from(11u32).is_palindrome());
+        assert!(Digits::from(121u32).is_palindrome());
+        assert!(Digits::from(9_009u32).is_palindrome());
+        assert!(Digits::try_from(-12_321).unwrap().is_palindrome());
+    }
+
+    #[test]
+    fn is_palindrome_rejects_non_palindromes() {
+        assert!(!Digits::from(10u32).is_palindrome());
+        assert!(!Digits::from(123u32).is_palindrome());
+        assert!(!Digits::from(1_231u32).is_palindrome());
+    }
+
+    #[test]
+    fn is_palindrome_works_with_radix() {
+        assert!(Digits::with_radix(0b1001, 2).unwrap().is_palindrome());
+        assert!(Digits::with_radix(0xabba, 16).unwrap().is_palindrome());
+        assert!(!Digits::with_radix(0b1101, 2).unwrap().is_palindrome());
+    }
+
+    #[test]
+    fn rotate_left_works() {
+        let digits = Digits::from(1234u32);
+        assert_eq!(
+            digits.rotate_left(1, LeadingZeros::Strip).as_slice(),
+            vec![2, 3, 4, 1]
+        );
+        assert_eq!(
+            digits.rotate_left(3, LeadingZeros::Strip).as_slice(),
+            vec![4, 1, 2, 3]
+        );
+        assert_eq!(
+            digits.rotate_left(0, LeadingZeros::Strip).as_slice(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            digits.rotate_left(5, LeadingZeros::Strip).as_slice(),
+            vec![2, 3, 4, 1]
+        );
+    }
+
+    #[test]
+    fn rotate_right_works() {
+        let digits = Digits::from(1234u32);
+        assert_eq!(
+            digits.rotate_right(1, LeadingZeros::Strip).as_slice(),
+            vec![4, 1, 2, 3]
+        );
+        assert_eq!(
+            digits.rotate_right(4, LeadingZeros::Strip).as_slice(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            digits.rotate_right(6, LeadingZeros::Strip).as_slice(),
+            vec![3, 4, 1, 2]
+        );
+    }
+
+    #[test]
+    fn rotate_works_with_leading_zeros() {
+        let digits = Digits::from(101u32);
+        assert_eq!(
+            digits.rotate_right(1, LeadingZeros::Strip).as_slice(),
+            vec![1, 1, 0]
+        );
+        assert_eq!(
+            digits.rotate_left(1, LeadingZeros::Strip).as_slice(),
+            vec![1, 1]
+        );
+        assert_eq!(
+            digits.rotate_left(1, LeadingZeros::Keep).as_slice(),
+            vec![0, 1, 1]
+        );
+        assert_eq!(digits.rotate_left(1, LeadingZeros::Keep).to_int(), Ok(11));
+    }
+
+    #[test]
+    fn rotate_keeps_sign_and_radix() {
+        let digits = Digits::try_from(-12).unwrap();
+        assert_eq!(digits.rotate_left(1, LeadingZeros::Strip).to_int(), Ok(-21));
+
+        let digits = Digits::with_radix(0b110, 2).unwrap();
+        assert_eq!(
+            digits.rotate_right(1, LeadingZeros::Strip).to_int(),
+            Ok(0b11)
+        );
+    }
+
+    #[test]
+    fn rotations_works() {
+        let rotations: Vec<_> = Digits::from(197u32)
+            .rotations(LeadingZeros::Strip)
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(rotations, vec![197, 971, 719]);
+        assert_eq!(Digits::from(7u32).rotations(LeadingZeros::Strip).count(), 1);
+    }
+
+    #[test]
+    fn truncate_left_works() {
+        let digits = Digits::from(3_797u32);
+        assert_eq!(digits.truncate_left().unwrap().to_int(), Ok(797));
+        assert_eq!(
+            Digits::from(3_007u32).truncate_left().map(|d| d.to_vec()),
+            Some(vec![7])
+        );
+        assert!(Digits::from(7u32).truncate_left().is_none());
+        let digits = Digits::try_from(-42).unwrap();
+        assert_eq!(digits.truncate_left().unwrap().to_int(), Ok(-2));
+        let digits = Digits::try_from(-40).unwrap().truncate_left().unwrap();
+        assert_eq!(digits.sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn truncate_right_works() {
+        let digits = Digits::from(3_797u32);
+        assert_eq!(digits.truncate_right().unwrap().to_int(), Ok(379));
+        assert!(Digits::from(0u32).truncate_right().is_none());
+        let digits = Digits::with_radix(0xabcu32, 16).unwrap();
+        assert_eq!(digits.truncate_right().unwrap().to_int(), Ok(0xab));
+    }
+
+    #[test]
+    fn left_truncations_works() {
+        let truncations: Vec<u32> = Digits::from(3_797u32)
+            .left_truncations()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(truncations, vec![3_797, 797, 97, 7]);
+        let truncations: Vec<u32> = Digits::from(307u32)
+            .left_truncations()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(truncations, vec![307, 7, 7]);
+        assert_eq!(Digits::from(123_456u32).left_truncations().len(), 6);
+    }
+
+    #[test]
+    fn right_truncations_works() {
+        let truncations: Vec<u32> = Digits::from(3_797u32)
+            .right_truncations()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(truncations, vec![3_797, 379, 37, 3]);
+    }
+
+    #[test]
+    fn truncations_find_two_sided_primes() {
+        let is_prime = |n: u32| {
+            n > 1
+                && (2..n)
+                    .take_while(|d| d * d <= n)
+                    .all(|d| !n.is_multiple_of(d))
+        };
+        let truncatable = |n: u32| {
+            let digits = Digits::from(n);
+            !digits.contains(&0)
+                && digits
+                    .left_truncations()
+                    .chain(digits.right_truncations())
+                    .all(|d| is_prime(d.to_int().unwrap()))
+        };
+        let found: Vec<u32> = (10..4_000).filter(|&n| truncatable(n)).collect();
+        assert_eq!(
+            found,
+            vec![23, 37, 53, 73, 313, 317, 373, 797, 3_137, 3_797]
+        );
+    }
+
+    #[test]
+    fn rotations_works_with_leading_zeros() {
+        let digits = Digits::from(1_009u32);
+        let rotations: Vec<_> = digits
+            .rotations(LeadingZeros::Strip)
+            .map(|d| d.len())
+            .collect();
+        assert_eq!(rotations, vec![4, 2, 3, 4]);
+
+        let rotations: Vec<_> = digits
+            .rotations(LeadingZeros::Keep)
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(rotations, vec![1_009, 91, 910, 9_100]);
+        assert!(digits.rotations(LeadingZeros::Keep).all(|d| d.len() == 4));
+    }
+
+    #[test]
+    fn rotations_has_exact_size() {
+        let digits = Digits::from(12_345u32);
+        let mut rotations = digits.rotations(LeadingZeros::Strip);
+        assert_eq!(rotations.len(), 5);
+        rotations.next();
+        assert_eq!(rotations.len(), 4);
+    }
+
+    #[test]
+    fn sorted_ascending_works() {
+        assert_eq!(
+            Digits::from(3_524u32).sorted_ascending().as_slice(),
+            vec![2, 3, 4, 5]
+        );
+        assert_eq!(
+            Digits::from(1_020u32).sorted_ascending().as_slice(),
+            vec![1, 2]
+        );
+        assert_eq!(Digits::from(0u32).sorted_ascending().as_slice(), vec![0]);
+    }
+
+    #[test]
+    fn sorted_descending_works() {
+        assert_eq!(
+            Digits::from(3_524u32).sorted_descending().as_slice(),
+            vec![5, 4, 3, 2]
+        );
+        assert_eq!(
+            Digits::from(1_020u32).sorted_descending().as_slice(),
+            vec![2, 1, 0, 0]
+        );
+        assert_eq!(
+            Digits::try_from(-1_020)
+                .unwrap()
+                .sorted_descending()
+                .to_int(),
+            Ok(-2_100)
+        );
+    }
+
+    #[test]
+    fn kaprekar_iter_works() {
+        let steps: Vec<_> = Digits::from(3_524u32)
+            .kaprekar_iter()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(steps, vec![3_087, 8_352, 6_174]);
+    }
+
+    #[test]
+    fn kaprekar_iter_keeps_width() {
+        let steps: Vec<_> = Digits::from(2_111u32)
+            .kaprekar_iter()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(steps, vec![999, 8_991, 8_082, 8_532, 6_174]);
+    }
+
+    #[test]
+    fn kaprekar_iter_reaches_fixed_point() {
+        let steps: Vec<_> = Digits::from(6_174u32)
+            .kaprekar_iter()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(steps, vec![6_174]);
+
+        let steps: Vec<_> = Digits::from(495u32)
+            .kaprekar_iter()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(steps, vec![495]);
+    }
+
+    #[test]
+    fn kaprekar_iter_works_with_repdigit() {
+        let steps: Vec<_> = Digits::from(1_111u32)
+            .kaprekar_iter()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(steps, vec![0]);
+    }
+
+    #[test]
+    fn kaprekar_iter_stops_on_cycle() {
+        // Two-digit numbers fall into the cycle 9 -> 81 -> 63 -> 27 -> 45.
+        let steps: Vec<_> = Digits::from(21u32)
+            .kaprekar_iter()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(steps, vec![9, 81, 63, 27, 45]);
+    }
+
+    #[test]
+    fn next_permutation_works() {
+        let mut digits = Digits::from(123u32);
+        assert!(digits.next_permutation());
+        assert_eq!(digits.as_slice(), vec![1, 3, 2]);
+        assert!(digits.next_permutation());
+        assert_eq!(digits.as_slice(), vec![2, 1, 3]);
+
+        let mut digits = Digits::from(1_002u32);
+        assert!(digits.next_permutation());
+        assert_eq!(digits.as_slice(), vec![1, 0, 2, 0]);
+    }
+
+    #[test]
+    fn next_permutation_stops_at_last_permutation() {
+        let mut digits = Digits::from(321u32);
+        assert!(!digits.next_permutation());
+        assert_eq!(digits.as_slice(), vec![3, 2, 1]);
+
+        let mut digits = Digits::from(7u32);
+        assert!(!digits.next_permutation());
+    }
+
+    #[test]
+    fn prev_permutation_works() {
+        let mut digits = Digits::from(132u32);
+        assert!(digits.prev_permutation());
+        assert_eq!(digits.as_slice(), vec![1, 2, 3]);
+        assert!(!digits.prev_permutation());
+        assert_eq!(digits.as_slice(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn prev_permutation_keeps_width() {
+        let mut digits = Digits::from(1_002u32);
+        assert!(digits.prev_permutation());
+        assert_eq!(digits.as_slice(), vec![0, 2, 1, 0]);
+        assert_eq!(digits.to_int(), Ok(210));
+    }
+
+    #[test]
+    fn next_and_prev_permutation_are_inverse() {
+        let mut digits = Digits::from(35_521u32);
+        while digits.prev_permutation() {}
+        let mut count = 1;
+        while digits.next_permutation() {
+            let mut back = digits.clone();
+            assert!(back.prev_permutation());
+            assert!(back.next_permutation());
+            assert_eq!(back.as_slice(), digits.as_slice());
+            count += 1;
+        }
+        assert_eq!(count, 60);
+    }
+
+    #[test]
+    fn permutations_works() {
+        let permutations: Vec<_> = Digits::from(312u32)
+            .permutations()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(permutations, vec![123, 132, 213, 231, 312, 321]);
+    }
+
+    #[test]
+    fn permutations_works_with_repeated_digits() {
+        let permutations: Vec<_> = Digits::from(1_121u32).permutations().ints().collect();
+        assert_eq!(
+            permutations,
+            vec![Ok(1_112), Ok(1_121), Ok(1_211), Ok(2_111)]
+        );
+        assert_eq!(Digits::from(1_111u32).permutations().count(), 1);
+        assert_eq!(Digits::from(112_233u32).permutations().count(), 90);
+    }
+
+    #[test]
+    fn permutations_keeps_leading_zeros() {
+        let permutations: Vec<_> = Digits::from(102u32).permutations().collect();
+        assert_eq!(permutations.len(), 6);
+        assert_eq!(permutations[0].as_slice(), vec![0, 1, 2]);
+        assert!(permutations.iter().all(|d| d.len() == 3));
+    }
+
+    #[test]
+    fn permutations_ints_reports_overflow() {
+        let permutations: Vec<_> = Digits::from(255u8).permutations().ints().collect();
+        assert_eq!(permutations.len(), 3);
+        assert_eq!(permutations[0], Ok(255));
+        assert!(permutations[1].is_err());
+        assert!(permutations[2].is_err());
+    }
+
+    #[test]
+    fn concat_works() {
+        let digits = Digits::from(12u32).concat(&Digits::from(345u32)).unwrap();
+        assert_eq!(digits.as_slice(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(digits.to_int(), Ok(12_345));
+
+        let digits = Digits::try_from(-12).unwrap();
+        let digits = digits.concat(&Digits::try_from(34).unwrap()).unwrap();
+        assert_eq!(digits.to_int(), Ok(-1_234));
+    }
+
+    #[test]
+    fn concat_works_with_leading_zeros() {
+        let right = Digits::<u32>::parse_with_leading_zeros("007").unwrap();
+        let digits = Digits::from(12u32).concat(&right).unwrap();
+        assert_eq!(digits.to_int(), Ok(12_007));
+    }
+
+    #[test]
+    fn concat_throws_error_with_mismatched_radix() {
+        let hex = Digits::with_radix(0xfu32, 16).unwrap();
+        assert_eq!(
+            Digits::from(1u32).concat(&hex).map(|d| d.to_int()),
+            Err(ConversionError::RadixMismatch {
+                expected: 10,
+                found: 16
+            })
+        );
+    }
+
+    #[test]
+    fn concat_throws_error_with_negative_right_hand_side() {
+        let negative = Digits::try_from(-1).unwrap();
+        assert_eq!(
+            Digits::try_from(1)
+                .unwrap()
+                .concat(&negative)
+                .map(|d| d.to_int()),
+            Err(ConversionError::NegativeInput)
+        );
+    }
+
+    #[test]
+    fn split_at_works() {
+        let (left, right) = Digits::from(12_345u32).split_at(2);
+        assert_eq!(left.as_slice(), vec![1, 2]);
+        assert_eq!(right.as_slice(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn split_at_keeps_leading_zeros_in_right_half() {
+        let digits = Digits::from(10_203u32);
+        let (left, right) = digits.split_at(2);
+        assert_eq!(right.as_slice(), vec![2, 0, 3]);
+        let (left, right) = left.concat(&right).unwrap().split_at(1);
+        assert_eq!(left.as_slice(), vec![1]);
+        assert_eq!(right.as_slice(), vec![0, 2, 0, 3]);
+        assert_eq!(right.to_int(), Ok(203));
+        assert_eq!(left.concat(&right).unwrap().as_slice(), digits.as_slice());
+    }
+
+    #[test]
+    fn split_at_works_at_the_ends() {
+        let digits = Digits::from(123u32);
+        let (left, right) = digits.split_at(0);
+        assert_eq!(left.to_int(), Ok(0));
+        assert_eq!(right.as_slice(), vec![1, 2, 3]);
+        let (left, right) = digits.split_at(3);
+        assert_eq!(left.as_slice(), vec![1, 2, 3]);
+        assert_eq!(right.to_int(), Ok(0));
+    }
+
+    #[test]
+    fn split_at_finds_kaprekar_numbers() {
+        let kaprekar: Vec<u64> = (1u64..1_000)
+            .filter(|&n| {
+                let square = Digits::from(n * n);
+                (1..square.len()).any(|i| {
+                    let (left, right) = square.split_at(i);
+                    !right.iter().all(|&d| d == 0)
+                        && left.to_int().unwrap() + right.to_int().unwrap() == n
+                })
+            })
+            .collect();
+        assert_eq!(kaprekar, vec![9, 45, 55, 99, 297, 703, 999]);
+    }
+
+    #[test]
+    fn reverse_and_add_iter_works() {
+        let terms: Vec<_> = Digits::from(89u32)
+            .reverse_and_add_iter()
+            .take(4)
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(terms, vec![89, 187, 968, 1_837]);
+
+        let terms: Vec<_> = Digits::from(10u32)
+            .reverse_and_add_iter()
+            .take(2)
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(terms, vec![10, 11]);
+    }
+
+    #[test]
+    fn reverse_and_add_iter_works_beyond_integer_range() {
+        // 89 takes 24 steps to reach a 13 digit palindrome, which would not
+        // fit the u8 digits it started from.
+        let palindrome = Digits::from(89u8).reverse_and_add_iter().nth(24).unwrap();
+        assert!(palindrome.is_palindrome());
+        assert_eq!(
+            palindrome.as_slice(),
+            vec![8, 8, 1, 3, 2, 0, 0, 0, 2, 3, 1, 8, 8]
+        );
+
+        let big = Digits::from(196u8)
+            .reverse_and_add_iter()
+            .nth(1_000)
+            .unwrap();
+        assert!(big.len() > 400);
+    }
+
+    #[test]
+    fn reverse_and_add_iter_works_with_radix() {
+        let terms: Vec<_> = Digits::with_radix(0b10u32, 2)
+            .unwrap()
+            .reverse_and_add_iter()
+            .take(3)
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        assert_eq!(terms, vec![0b10, 0b11, 0b110]);
+    }
+
+    #[test]
+    fn is_lychrel_candidate_works() {
+        assert!(Digits::from(196u32).is_lychrel_candidate(500));
+        assert!(Digits::from(879u32).is_lychrel_candidate(500));
+        assert!(!Digits::from(89u32).is_lychrel_candidate(24));
+        assert!(Digits::from(89u32).is_lychrel_candidate(23));
+        assert!(!Digits::from(56u32).is_lychrel_candidate(1));
+        assert!(Digits::from(56u32).is_lychrel_candidate(0));
+    }
+
+    #[test]
+    fn is_anagram_of_works() {
+        let digits = Digits::from(1_487u32);
+        assert!(digits.is_anagram_of(&Digits::from(4_817u32)));
+        assert!(digits.is_anagram_of(&Digits::from(8_147u32)));
+        assert!(digits.is_anagram_of(&digits));
+        assert!(!digits.is_anagram_of(&Digits::from(1_477u32)));
+        assert!(!digits.is_anagram_of(&Digits::from(14_870u32)));
+        assert!(!Digits::from(1_122u32).is_anagram_of(&Digits::from(1_222u32)));
+    }
+
+    #[test]
+    fn is_anagram_of_works_with_leading_zeros() {
+        let padded = Digits::<u32>::parse_with_leading_zeros("0123").unwrap();
+        assert!(padded.is_anagram_of(&Digits::from(1_230u32)));
+        assert!(!padded.is_anagram_of(&Digits::from(123u32)));
+    }
+
+    #[test]
+    fn is_anagram_of_rejects_mismatched_radix() {
+        let hex = Digits::with_radix(0x21u32, 16).unwrap();
+        assert!(!Digits::from(12u32).is_anagram_of(&hex));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn signature_groups_anagram_classes() {
+        use std::collections::HashMap;
+
+        let mut classes: HashMap<DigitSignature, Vec<u32>> = HashMap::new();
+        for n in [1_487u32, 4_817, 8_147, 1_234, 4_321, 1_000, 10] {
+            classes
+                .entry(Digits::from(n).signature())
+                .or_default()
+                .push(n);
+        }
+        assert_eq!(classes.len(), 4);
+        assert_eq!(
+            classes[&Digits::from(7_418u32).signature()],
+            vec![1_487, 4_817, 8_147]
+        );
+        assert_eq!(classes[&Digits::from(10u32).signature()], vec![10]);
+    }
+
+    #[test]
+    fn to_gray_works() {
+        let codes: Vec<u32> = (0u32..8)
+            .map(|n| Digits::from(n).to_gray().unwrap().to_int().unwrap())
+            .collect();
+        assert_eq!(
+            codes,
+            vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]
+        );
+
+        let gray = Digits::from(10u32).to_gray().unwrap();
+        assert_eq!(gray.radix(), 2);
+        assert_eq!(gray.as_slice(), vec![1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn to_gray_changes_one_bit_per_step() {
+        for n in 0u32..1_000 {
+            let a = Digits::from(n).to_gray().unwrap();
+            let b = Digits::from(n + 1).to_gray().unwrap();
+            assert_eq!(a.digit_hamming_distance(&b), 1);
+        }
+    }
+
+    #[test]
+    fn to_gray_throws_error_with_negative_number() {
+        assert_eq!(
+            Digits::try_from(-1).unwrap().to_gray().map(|d| d.to_int()),
+            Err(ConversionError::NegativeInput)
+        );
+    }
+
+    #[test]
+    fn from_gray_works() {
+        let gray = Digits::with_radix(0b1111u32, 2).unwrap();
+        assert_eq!(gray.from_gray().unwrap().to_int(), Ok(10));
+        for n in 0u32..1_000 {
+            let gray = Digits::from(n).to_gray().unwrap();
+            assert_eq!(gray.from_gray().unwrap().to_int(), Ok(n));
+        }
+    }
+
+    #[test]
+    fn from_gray_throws_error_with_non_binary_digits() {
+        assert_eq!(
+            Digits::from(10u32).from_gray().map(|d| d.to_int()),
+            Err(ConversionError::RadixMismatch {
+                expected: 2,
+                found: 10
+            })
+        );
+    }
+
+    #[test]
+    fn next_palindrome_works() {
+        let next = |n: u32| Digits::from(n).next_palindrome().to_int().unwrap();
+        for n in 0u32..2_000 {
+            let expected = (n + 1..).find(|&m| Digits::from(m).is_palindrome());
+            assert_eq!(Some(next(n)), expected);
+        }
+        assert_eq!(next(99_999), 100_001);
+        assert_eq!(next(12_345), 12_421);
+    }
+
+    #[test]
+    fn next_palindrome_works_with_signs_and_radix() {
+        let next = |n: i32| Digits::try_from(n).unwrap().next_palindrome().to_int();
+        assert_eq!(next(-1), Ok(0));
+        assert_eq!(next(-10), Ok(-9));
+        assert_eq!(next(-123), Ok(-121));
+        let digits = Digits::with_radix(0b1011, 2).unwrap().next_palindrome();
+        assert_eq!(digits.to_int(), Ok(0b1111));
+    }
+
+    #[test]
+    fn next_palindrome_works_beyond_integer_range() {
+        let digits = Digits::from_radix(vec![9u8; 40], 10).unwrap();
+        let next = digits.next_palindrome();
+        assert_eq!(next.len(), 41);
+        assert!(next.is_palindrome());
+        assert_eq!(next.iter().filter(|&&d| d == 1).count(), 2);
+    }
+
+    #[test]
+    fn next_palindrome_ignores_leading_zeros() {
+        let digits = Digits::<u8>::parse_with_leading_zeros("0012").unwrap();
+        assert_eq!(digits.next_palindrome().to_int(), Ok(22));
+    }
+
+    #[test]
+    fn prev_palindrome_works() {
+        let prev = |n: i32| Digits::try_from(n).unwrap().prev_palindrome().unwrap();
+        for n in -500..2_000 {
+            let expected = (i32::MIN..n)
+                .rev()
+                .find(|&m| Digits::try_from(m).unwrap().is_palindrome());
+            assert_eq!(prev(n).to_int().ok(), expected);
+        }
+        assert_eq!(prev(100_001).to_int(), Ok(99_999));
+        assert_eq!(prev(10).to_int(), Ok(9));
+    }
+
+    #[test]
+    fn prev_palindrome_works_with_unsigned_zero() {
+        assert!(Digits::from(0u32).prev_palindrome().is_none());
+        let prev = Digits::from(1u32).prev_palindrome().unwrap();
+        assert_eq!(prev.to_int(), Ok(0));
+    }
+
+    #[test]
+    fn palindromes_works() {
+        let palindromes: Vec<u32> = Digits::palindromes(3, 10)
+            .unwrap()
+            .map(|d| d.to_int().unwrap())
+            .collect();
+        let expected: Vec<u32> = (100..1_000)
+            .filter(|&n| Digits::from(n).is_palindrome())
+            .collect();
+        assert_eq!(palindromes, expected);
+        assert_eq!(Digits::<u8>::palindromes(1, 10).unwrap().count(), 10);
+        assert_eq!(Digits::<u8>::palindromes(4, 10).unwrap().count(), 90);
+        assert_eq!(Digits::<u8>::palindromes(5, 2).unwrap().count(), 4);
+    }
+
+    #[test]
+    fn palindromes_throws_error_with_invalid_arguments() {
+        assert!(Digits::<u8>::palindromes(0, 10).is_err());
+        assert!(Digits::<u8>::palindromes(3, 1).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! JavaScript bindings for the core operations.
+//!
+//! Numbers cross the boundary as decimal strings, since JavaScript numbers
+//! lose precision beyond 2^53 and the digits here are not bounded at all.
+//! Errors are thrown as JavaScript `Error`s.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{radix_constant, to_mixed_radix, ConversionError, DigitVec, Digits, Sign};
+
+impl From<ConversionError> for JsValue {
+    fn from(e: ConversionError) -> Self {
+        JsError::new(&e.to_string()).into()
+    }
+}
+
+fn parse(n: &str) -> Result<Digits<i8>, ConversionError> {
+    n.parse()
+}
+
+// Rewrites the digits in another radix, keeping the sign.
+fn convert(digits: &Digits<i8>, radix: u32) -> Result<Digits<i8>, ConversionError> {
+    radix_constant::<i8>(radix)?;
+    let values: Vec<u64> = digits.values.iter().map(|&d| d as u64).collect();
+    let values: DigitVec<i8> = to_mixed_radix(&values, digits.radix as u64, |_| radix as u64)
+        .into_iter()
+        .map(|d| d as i8)
+        .collect();
+    Ok(Digits::new_signed(
+        values,
+        radix,
+        digits.sign == Sign::Negative,
+    ))
+}
+
+/// The decimal digits of `n`, most significant first. The sign is dropped.
+#[wasm_bindgen(js_name = digitsOf)]
+pub fn digits_of(n: &str) -> Result<Vec<u8>, ConversionError> {
+    Ok(parse(n)?.values.iter().map(|&d| d as u8).collect())
+}
+
+/// The decimal number with the given digits in `radix`.
+#[wasm_bindgen(js_name = fromDigits)]
+pub fn from_digits(values: Vec<u8>, radix: u32) -> Result<String, ConversionError> {
+    let digits = Digits::from_radix(values, radix)?;
+    let digits = Digits::new(
+        digits
+            .values
+            .iter()
+            .map(|&d| d as i8)
+            .collect::<DigitVec<_>>(),
+        radix,
+    );
+    Ok(convert(&digits, 10)?.to_string())
+}
+
+#[wasm_bindgen(js_name = digitSum)]
+pub fn digit_sum(n: &str) -> Result<u32, ConversionError> {
+    Ok(parse(n)?.values.iter().map(|&d| d as u32).sum())
+}
+
+#[wasm_bindgen(js_name = isPalindrome)]
+pub fn is_palindrome(n: &str) -> Result<bool, ConversionError> {
+    Ok(parse(n)?.is_palindrome())
+}
+
+/// `n` written in `radix`, with letters for digits above 9.
+#[wasm_bindgen(js_name = toBase)]
+pub fn to_base(n: &str, radix: u32) -> Result<String, ConversionError> {
+    Ok(convert(&parse(n)?, radix)?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn digits_of_works() {
+        assert_eq!(digits_of("1234"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(digits_of("-42"), Ok(vec![4, 2]));
+        assert!(digits_of("12a").is_err());
+    }
+
+    #[test]
+    fn from_digits_works() {
+        assert_eq!(from_digits(vec![1, 0, 1], 2), Ok("5".to_string()));
+        assert_eq!(from_digits(vec![15, 15], 16), Ok("255".to_string()));
+        assert_eq!(
+            from_digits(vec![2], 2),
+            Err(ConversionError::InvalidDigit {
+                position: 0,
+                value: 2
+            })
+        );
+        assert!(from_digits(vec![255], 36).is_err());
+    }
+
+    #[test]
+    fn digit_sum_works() {
+        assert_eq!(digit_sum("12345"), Ok(15));
+        assert_eq!(digit_sum(&"9".repeat(100)), Ok(900));
+    }
+
+    #[test]
+    fn is_palindrome_works() {
+        assert_eq!(is_palindrome("12321"), Ok(true));
+        assert_eq!(is_palindrome("1232"), Ok(false));
+    }
+
+    #[test]
+    fn to_base_works() {
+        assert_eq!(to_base("255", 16), Ok("ff".to_string()));
+        assert_eq!(to_base("-5", 2), Ok("-101".to_string()));
+        assert_eq!(to_base("0", 36), Ok("0".to_string()));
+        assert_eq!(
+            to_base("340282366920938463463374607431768211456", 16),
+            Ok(alloc::format!("1{}", "0".repeat(32)))
+        );
+        assert_eq!(
+            to_base("10", 37),
+            Err(ConversionError::InvalidRadix { radix: 37 })
+        );
+    }
+}
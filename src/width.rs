@@ -0,0 +1,226 @@
+//! Fixed-width digits, for PINs, postal codes and padded counters where the
+//! leading zeros belong to the value.
+//!
+//! In fixed-width mode reversing, rotating, sorting and truncating keep the
+//! leading zeros, and equality takes the width into account, so `007` is
+//! neither `7` nor `07`. `to_int` still gives the plain number, 7 for `007`.
+//!
+//! Arithmetic keeps the width too, like an odometer: the result is padded
+//! with zeros, and digits that carry past the top are dropped, so `999 + 1`
+//! with a width of 3 is `000`.
+
+use num_traits::{Num, NumCast};
+
+use crate::{parse_decimal, signed_digits_from_int_radix, strip_leading_zeros};
+use crate::{ConversionError, Digits};
+
+impl<T> Digits<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// `n` in decimal, padded with zeros to exactly `width` digits, in
+    /// fixed-width mode.
+    pub fn with_width(n: T, width: usize) -> Result<Self, ConversionError> {
+        let digits = signed_digits_from_int_radix(n, T::from(10).unwrap());
+        let found = digits.len();
+        if found > width {
+            return Err(ConversionError::InvalidLength {
+                expected: width,
+                found,
+            });
+        }
+        let mut values = alloc::vec![T::zero(); width - found];
+        values.extend(digits);
+        Ok(Self::new_signed(values, 10, n < T::zero()).into_fixed_width())
+    }
+
+    /// Parses decimal digits in fixed-width mode, so `"007"` keeps its three
+    /// digits.
+    pub fn parse_fixed_width(s: &str) -> Result<Self, ConversionError> {
+        let (values, negative) = parse_decimal(s)?;
+        Ok(Self::new_signed(values, 10, negative).into_fixed_width())
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: Num + Copy,
+{
+    /// The number of digits, if the width is fixed.
+    pub fn fixed_width(&self) -> Option<usize> {
+        self.fixed_width.then_some(self.values.len())
+    }
+
+    /// Fixes the width at the current number of digits, leading zeros
+    /// included.
+    pub fn into_fixed_width(mut self) -> Self {
+        self.fixed_width = true;
+        self
+    }
+
+    /// Leaves fixed-width mode, dropping the leading zeros.
+    pub fn into_variable_width(mut self) -> Self {
+        self.fixed_width = false;
+        strip_leading_zeros(&mut self.values);
+        self
+    }
+
+    // Pads the magnitude with zeros to `width` digits, or drops the digits
+    // above them, and fixes the width there.
+    pub(crate) fn wrap_to_width(&mut self, width: usize) {
+        let len = self.values.len();
+        if len > width {
+            self.values.drain(..len - width);
+        } else {
+            self.values.resize(width, T::zero());
+            self.values.rotate_right(width - len);
+        }
+        self.fixed_width = true;
+        self.update_sign();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LeadingZeros, Sign};
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn with_width_works() {
+        let digits = Digits::with_width(7u32, 3).unwrap();
+        assert_eq!(digits.as_slice(), vec![0, 0, 7]);
+        assert_eq!(digits.fixed_width(), Some(3));
+        assert_eq!(digits.to_string(), "007");
+        assert_eq!(digits.to_int(), Ok(7));
+
+        let digits = Digits::with_width(0u32, 4).unwrap();
+        assert_eq!(digits.to_string(), "0000");
+        assert_eq!(digits.sign(), Sign::Zero);
+        assert_eq!(Digits::with_width(-42i32, 4).unwrap().to_string(), "-0042");
+        assert_eq!(Digits::with_width(123u32, 3).unwrap().to_string(), "123");
+        assert!(Digits::with_width(0u32, 0).is_err());
+    }
+
+    #[test]
+    fn with_width_throws_error_with_too_many_digits() {
+        assert_eq!(
+            Digits::with_width(1_234u32, 3).map(|d| d.to_int()),
+            Err(ConversionError::InvalidLength {
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn parse_fixed_width_works() {
+        let digits = Digits::<u8>::parse_fixed_width("007").unwrap();
+        assert_eq!(digits.fixed_width(), Some(3));
+        assert_eq!(digits.to_int(), Ok(7));
+        assert!(Digits::<u8>::parse_fixed_width("0a").is_err());
+    }
+
+    #[test]
+    fn fixed_width_is_none_by_default() {
+        assert_eq!(Digits::from(7u32).fixed_width(), None);
+        let digits = Digits::<u8>::parse_with_leading_zeros("007").unwrap();
+        assert_eq!(digits.fixed_width(), None);
+    }
+
+    #[test]
+    fn into_variable_width_works() {
+        let digits = Digits::<u8>::parse_fixed_width("0070").unwrap();
+        let digits = digits.into_variable_width();
+        assert_eq!(digits.as_slice(), vec![7, 0]);
+        assert_eq!(digits.fixed_width(), None);
+        let digits = Digits::with_width(0u8, 3).unwrap().into_variable_width();
+        assert_eq!(digits.as_slice(), vec![0]);
+    }
+
+    #[test]
+    fn fixed_width_equality_works() {
+        let pin = Digits::<u8>::parse_fixed_width("007").unwrap();
+        assert!(pin != Digits::from(7u8));
+        assert!(pin != Digits::<u8>::parse_fixed_width("07").unwrap());
+        assert!(pin == Digits::with_width(7u8, 3).unwrap());
+        assert!(pin.clone().into_variable_width() == Digits::from(7u8));
+        assert!(Digits::from(7u8) < pin);
+        assert!(pin < Digits::from(8u8));
+    }
+
+    #[test]
+    fn fixed_width_operations_keep_leading_zeros() {
+        let digits = Digits::<u8>::parse_fixed_width("0120").unwrap();
+        assert_eq!(digits.reversed().to_string(), "0210");
+        assert_eq!(
+            digits.rotate_left(1, LeadingZeros::Strip).to_string(),
+            "1200"
+        );
+        assert_eq!(
+            digits.rotate_right(1, LeadingZeros::Strip).to_string(),
+            "0012"
+        );
+        assert_eq!(digits.truncate_left().unwrap().to_string(), "120");
+        let truncations: alloc::vec::Vec<_> =
+            digits.left_truncations().map(|d| d.to_string()).collect();
+        assert_eq!(truncations, vec!["0120", "120", "20", "0"]);
+
+        let mut counter = Digits::with_width(99u32, 4).unwrap();
+        counter.increment();
+        assert_eq!(counter.to_string(), "0100");
+        assert_eq!(counter.fixed_width(), Some(4));
+
+        let digits = Digits::with_width(7u8, 3).unwrap();
+        assert_eq!(digits.sorted_ascending().to_string(), "007");
+        assert_eq!(digits.sorted_ascending().fixed_width(), Some(3));
+        assert_eq!(digits.sorted_descending().to_string(), "700");
+    }
+
+    #[test]
+    fn fixed_width_counters_wrap_around() {
+        let mut counter = Digits::with_width(999u32, 3).unwrap();
+        counter.increment();
+        assert_eq!(counter.to_string(), "000");
+        assert_eq!(counter.fixed_width(), Some(3));
+        assert_eq!(counter.sign(), Sign::Zero);
+
+        let mut counter = Digits::with_width(100u32, 3).unwrap();
+        counter.decrement();
+        assert_eq!(counter.to_string(), "099");
+        assert_eq!(counter.fixed_width(), Some(3));
+
+        let mut counter = Digits::with_width(-999i32, 3).unwrap();
+        counter.decrement();
+        assert_eq!(counter.to_string(), "000");
+        let mut counter = Digits::with_width(0i32, 3).unwrap();
+        counter.decrement();
+        assert_eq!(counter.to_string(), "-001");
+    }
+
+    #[test]
+    fn fixed_width_arithmetic_keeps_width() {
+        let a = Digits::with_width(7u32, 3).unwrap();
+        let b = Digits::from(5u32);
+        assert_eq!((&a + &b).to_string(), "012");
+        assert_eq!((&a + &b).fixed_width(), Some(3));
+        assert_eq!((&a - &b).to_string(), "002");
+        assert_eq!((&a * &b).to_string(), "035");
+
+        // Digits carried past the top are dropped.
+        let max = Digits::with_width(999u32, 3).unwrap();
+        assert_eq!((&max + &Digits::from(2u32)).to_string(), "001");
+        assert_eq!((&max * &max).to_string(), "001");
+        let zero = &max + &Digits::from(1u32);
+        assert_eq!(zero.to_string(), "000");
+        assert_eq!(zero.sign(), Sign::Zero);
+
+        // Only the width of the left operand counts.
+        assert_eq!((&b + &a).to_string(), "12");
+        assert_eq!((&b + &a).fixed_width(), None);
+        let mut total = Digits::with_width(0u32, 4).unwrap();
+        total += Digits::from(42u32);
+        assert_eq!(total.to_string(), "0042");
+    }
+}
@@ -0,0 +1,314 @@
+//! Spelling numbers out in words, e.g. 1234 as "one thousand two hundred
+//! thirty-four".
+//!
+//! English is the only language so far. Others can be added by implementing
+//! `Language` and passing it to `Digits::to_words_in`.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use num_traits::NumCast;
+
+use crate::{strip_leading_zeros, to_mixed_radix, ConversionError, Digits, Sign};
+
+/// A way of writing numbers in words. Both methods get the decimal digits of
+/// the magnitude, most significant first and without leading zeros.
+pub trait Language {
+    fn cardinal(&self, digits: &[u8], negative: bool) -> Result<String, ConversionError>;
+
+    fn ordinal(&self, digits: &[u8], negative: bool) -> Result<String, ConversionError>;
+}
+
+/// English in the short scale, without "and", so 10^9 is "one billion".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct English;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 14] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+    "duodecillion",
+];
+
+// The words for 1 to 999.
+fn hundreds(n: usize, words: &mut Vec<String>) {
+    if n >= 100 {
+        words.push(ONES[n / 100].to_string());
+        words.push("hundred".to_string());
+    }
+    match n % 100 {
+        0 => {}
+        n @ 1..=19 => words.push(ONES[n].to_string()),
+        n if n % 10 == 0 => words.push(TENS[n / 10].to_string()),
+        n => words.push([TENS[n / 10], ONES[n % 10]].join("-")),
+    }
+}
+
+impl Language for English {
+    fn cardinal(&self, digits: &[u8], negative: bool) -> Result<String, ConversionError> {
+        let groups: Vec<usize> = digits
+            .rchunks(3)
+            .map(|group| group.iter().fold(0, |n, &d| n * 10 + d as usize))
+            .collect();
+        if groups.len() > SCALES.len() {
+            return Err(ConversionError::OutOfRange);
+        }
+
+        let mut words = Vec::new();
+        if negative {
+            words.push("minus".to_string());
+        }
+        for (scale, &group) in groups.iter().enumerate().rev() {
+            if group > 0 {
+                hundreds(group, &mut words);
+                if scale > 0 {
+                    words.push(SCALES[scale].to_string());
+                }
+            }
+        }
+        if words.len() == negative as usize {
+            words.push(ONES[0].to_string());
+        }
+        Ok(words.join(" "))
+    }
+
+    fn ordinal(&self, digits: &[u8], negative: bool) -> Result<String, ConversionError> {
+        let mut words = self.cardinal(digits, negative)?;
+        // Only the last word changes, and after a hyphen only its last part.
+        let start = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+        let ordinal = match &words[start..] {
+            "one" => "first".to_string(),
+            "two" => "second".to_string(),
+            "three" => "third".to_string(),
+            "five" => "fifth".to_string(),
+            "eight" => "eighth".to_string(),
+            "nine" => "ninth".to_string(),
+            "twelve" => "twelfth".to_string(),
+            word => match word.strip_suffix('y') {
+                Some(stem) => stem.to_string() + "ieth",
+                None => word.to_string() + "th",
+            },
+        };
+        words.replace_range(start.., &ordinal);
+        Ok(words)
+    }
+}
+
+impl<T> Digits<T>
+where
+    T: NumCast + Copy,
+{
+    fn decimal_values(&self) -> Vec<u8> {
+        let values: Vec<u64> = self.values.iter().map(|d| d.to_u64().unwrap()).collect();
+        let mut values: Vec<u8> = if self.radix == 10 {
+            values.into_iter().map(|d| d as u8).collect()
+        } else {
+            to_mixed_radix(&values, self.radix as u64, |_| 10)
+                .into_iter()
+                .map(|d| d as u8)
+                .collect()
+        };
+        strip_leading_zeros(&mut values);
+        values
+    }
+
+    pub fn to_words(&self) -> Result<String, ConversionError> {
+        self.to_words_in(&English)
+    }
+
+    /// E.g. "one thousand two hundred thirty-fourth".
+    pub fn to_ordinal_words(&self) -> Result<String, ConversionError> {
+        self.to_ordinal_words_in(&English)
+    }
+
+    pub fn to_words_in<L: Language>(&self, language: &L) -> Result<String, ConversionError> {
+        language.cardinal(&self.decimal_values(), self.sign == Sign::Negative)
+    }
+
+    pub fn to_ordinal_words_in<L: Language>(
+        &self,
+        language: &L,
+    ) -> Result<String, ConversionError> {
+        language.ordinal(&self.decimal_values(), self.sign == Sign::Negative)
+    }
+
+    /// The English numeric ordinal in decimal with thousands separators,
+    /// e.g. "1,234th".
+    pub fn to_ordinal(&self) -> String {
+        let values = self.decimal_values();
+        let suffix = match values.as_slice() {
+            [.., 1, 1..=3] => "th",
+            [.., 1] => "st",
+            [.., 2] => "nd",
+            [.., 3] => "rd",
+            _ => "th",
+        };
+        let decimal = Digits::new_signed(values, 10, self.sign == Sign::Negative);
+        decimal.format().separator(',').to_string() + suffix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_words_works() {
+        let expected = [
+            (0u64, "zero"),
+            (7, "seven"),
+            (13, "thirteen"),
+            (40, "forty"),
+            (42, "forty-two"),
+            (100, "one hundred"),
+            (101, "one hundred one"),
+            (1_234, "one thousand two hundred thirty-four"),
+            (1_000_000, "one million"),
+            (1_000_001, "one million one"),
+            (
+                9_007_199_254_740_993,
+                "nine quadrillion seven trillion one hundred ninety-nine billion \
+                 two hundred fifty-four million seven hundred forty thousand \
+                 nine hundred ninety-three",
+            ),
+        ];
+        for (n, s) in expected {
+            assert_eq!(Digits::from(n).to_words(), Ok(s.to_string()));
+        }
+    }
+
+    #[test]
+    fn to_words_works_with_negative_number() {
+        let digits = Digits::try_from(-15).unwrap();
+        assert_eq!(digits.to_words(), Ok("minus fifteen".to_string()));
+    }
+
+    #[test]
+    fn to_words_works_with_radix() {
+        let digits = Digits::with_radix(1_234u32, 16).unwrap();
+        assert_eq!(
+            digits.to_words(),
+            Ok("one thousand two hundred thirty-four".to_string())
+        );
+    }
+
+    #[test]
+    fn to_words_works_with_wide_types() {
+        let words = Digits::from(u128::MAX).to_words().unwrap();
+        assert!(words.starts_with("three hundred forty undecillion"));
+        assert!(words.ends_with("two hundred eleven thousand four hundred fifty-five"));
+    }
+
+    #[test]
+    fn to_words_throws_error_with_large_number() {
+        let digits = crate::factorial_digits(50);
+        assert_eq!(digits.to_words(), Err(ConversionError::OutOfRange));
+    }
+
+    #[test]
+    fn to_ordinal_words_works() {
+        let expected = [
+            (0u32, "zeroth"),
+            (1, "first"),
+            (2, "second"),
+            (3, "third"),
+            (5, "fifth"),
+            (8, "eighth"),
+            (9, "ninth"),
+            (11, "eleventh"),
+            (12, "twelfth"),
+            (20, "twentieth"),
+            (21, "twenty-first"),
+            (100, "one hundredth"),
+            (1_234, "one thousand two hundred thirty-fourth"),
+            (1_000_000, "one millionth"),
+        ];
+        for (n, s) in expected {
+            assert_eq!(Digits::from(n).to_ordinal_words(), Ok(s.to_string()));
+        }
+    }
+
+    #[test]
+    fn to_ordinal_works() {
+        let expected = [
+            (0u32, "0th"),
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (112, "112th"),
+            (1_234, "1,234th"),
+            (1_000_002, "1,000,002nd"),
+        ];
+        for (n, s) in expected {
+            assert_eq!(Digits::from(n).to_ordinal(), s);
+        }
+    }
+
+    #[test]
+    fn to_words_in_works_with_custom_language() {
+        struct Digitwise;
+
+        impl Language for Digitwise {
+            fn cardinal(&self, digits: &[u8], _: bool) -> Result<String, ConversionError> {
+                let words: Vec<&str> = digits.iter().map(|&d| ONES[d as usize]).collect();
+                Ok(words.join(" "))
+            }
+
+            fn ordinal(&self, digits: &[u8], negative: bool) -> Result<String, ConversionError> {
+                Ok(self.cardinal(digits, negative)? + " (ordinal)")
+            }
+        }
+
+        let digits = Digits::from(305u32);
+        assert_eq!(
+            digits.to_words_in(&Digitwise),
+            Ok("three zero five".to_string())
+        );
+        assert_eq!(
+            digits.to_ordinal_words_in(&Digitwise),
+            Ok("three zero five (ordinal)".to_string())
+        );
+    }
+}
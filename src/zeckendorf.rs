@@ -0,0 +1,185 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use num_traits::{Num, NumCast};
+
+use crate::ConversionError;
+
+/// A number written as a sum of non-consecutive Fibonacci numbers. The bits
+/// are most significant first, with the last one standing for F(2) = 1, the
+/// one before it for F(3) = 2, then 3, 5, 8 and so on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Zeckendorf {
+    bits: Vec<u8>,
+}
+
+impl Zeckendorf {
+    pub fn from_int<T>(n: T) -> Result<Zeckendorf, ConversionError>
+    where
+        T: Num + NumCast + PartialOrd + Copy,
+    {
+        if n < T::zero() {
+            return Err(ConversionError::NegativeInput);
+        }
+        let mut n = n.to_u128().unwrap();
+
+        let mut fibs = vec![1u128, 2];
+        while let Some(next) = fibs[fibs.len() - 2].checked_add(fibs[fibs.len() - 1]) {
+            if next > n {
+                break;
+            }
+            fibs.push(next);
+        }
+
+        // Greedily taking the largest Fibonacci number that fits never picks
+        // two neighbours, since F(k) + F(k - 1) would have fit as F(k + 1).
+        let mut bits: Vec<u8> = fibs
+            .iter()
+            .rev()
+            .map(|&f| {
+                if f <= n {
+                    n -= f;
+                    1
+                } else {
+                    0
+                }
+            })
+            .collect();
+        let leading = bits.iter().take_while(|&&b| b == 0).count();
+        bits.drain(..leading.min(bits.len() - 1));
+        Ok(Zeckendorf { bits })
+    }
+
+    pub fn from_bits(bits: Vec<u8>) -> Result<Zeckendorf, ConversionError> {
+        if bits.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        if let Some(position) = bits.iter().position(|&b| b > 1) {
+            return Err(ConversionError::invalid_digit(position, bits[position]));
+        }
+        if bits.windows(2).any(|w| w == [1, 1]) {
+            return Err(ConversionError::Malformed);
+        }
+        Ok(Zeckendorf { bits })
+    }
+
+    pub fn bits(&self) -> &[u8] {
+        &self.bits
+    }
+
+    pub fn to_int<T>(&self) -> Result<T, ConversionError>
+    where
+        T: NumCast,
+    {
+        let overflow = || ConversionError::Overflow { needed_bits: None };
+        // The weight becomes None once it no longer fits a u128, which only
+        // matters if a bit at or beyond that position is set.
+        let (mut sum, mut fib, mut prev) = (0u128, Some(1u128), 1u128);
+        for &bit in self.bits.iter().rev() {
+            if bit == 1 {
+                sum = fib.and_then(|f| sum.checked_add(f)).ok_or_else(overflow)?;
+            }
+            if let Some(f) = fib {
+                (fib, prev) = (f.checked_add(prev), f);
+            }
+        }
+        T::from(sum).ok_or_else(overflow)
+    }
+}
+
+impl fmt::Display for Zeckendorf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bits: String = self.bits.iter().map(|&b| char::from(b'0' + b)).collect();
+        f.pad(&bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn from_int_works() {
+        assert_eq!(Zeckendorf::from_int(0u32).unwrap().bits(), [0]);
+        assert_eq!(Zeckendorf::from_int(1u32).unwrap().bits(), [1]);
+        assert_eq!(Zeckendorf::from_int(4u32).unwrap().bits(), [1, 0, 1]);
+        // 100 = 89 + 8 + 3
+        assert_eq!(
+            Zeckendorf::from_int(100u32).unwrap().to_string(),
+            "1000010100"
+        );
+    }
+
+    #[test]
+    fn from_int_works_with_wide_types() {
+        let zeckendorf = Zeckendorf::from_int(u128::MAX).unwrap();
+        assert!(!zeckendorf.bits().windows(2).any(|w| w == [1, 1]));
+        assert_eq!(zeckendorf.to_int::<u128>(), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn from_int_throws_error_with_negative_number() {
+        assert_eq!(
+            Zeckendorf::from_int(-1),
+            Err(ConversionError::NegativeInput)
+        );
+    }
+
+    #[test]
+    fn from_int_never_sets_adjacent_bits() {
+        for n in 0u32..5_000 {
+            let zeckendorf = Zeckendorf::from_int(n).unwrap();
+            assert_eq!(
+                Zeckendorf::from_bits(zeckendorf.bits().to_vec()),
+                Ok(zeckendorf)
+            );
+        }
+    }
+
+    #[test]
+    fn to_int_works() {
+        for n in 0u32..5_000 {
+            assert_eq!(Zeckendorf::from_int(n).unwrap().to_int(), Ok(n));
+        }
+        let zeckendorf = Zeckendorf::from_bits(vec![0, 0, 1, 0, 1]).unwrap();
+        assert_eq!(zeckendorf.to_int(), Ok(4u8));
+    }
+
+    #[test]
+    fn to_int_throws_error_with_overflow() {
+        let zeckendorf = Zeckendorf::from_int(300u32).unwrap();
+        assert_eq!(
+            zeckendorf.to_int::<u8>(),
+            Err(ConversionError::Overflow { needed_bits: None })
+        );
+        let mut bits = vec![0; 300];
+        bits[0] = 1;
+        assert!(Zeckendorf::from_bits(bits)
+            .unwrap()
+            .to_int::<u128>()
+            .is_err());
+    }
+
+    #[test]
+    fn from_bits_throws_error_with_adjacent_ones() {
+        assert_eq!(
+            Zeckendorf::from_bits(vec![1, 0, 1, 1]),
+            Err(ConversionError::Malformed)
+        );
+    }
+
+    #[test]
+    fn from_bits_throws_error_with_invalid_bit() {
+        assert_eq!(
+            Zeckendorf::from_bits(vec![1, 2]),
+            Err(ConversionError::InvalidDigit {
+                position: 1,
+                value: 2
+            })
+        );
+        assert!(Zeckendorf::from_bits(Vec::new()).is_err());
+    }
+}